@@ -0,0 +1,379 @@
+use crate::Win32::System::Diagnostics::Etw::*;
+use windows_core::{Result, GUID, HSTRING};
+
+/// A TraceLogging provider, which writes self-describing ETW events.
+///
+/// Events are written with the `trace_event!` macro, which does nothing unless a trace session has enabled the
+/// provider for the event's level and keywords:
+///
+/// ```rust,ignore
+/// let provider = TraceProvider::register("Contoso.Service", GUID::from("...")?)?;
+/// trace_event!(provider, "RequestCompleted", TraceLevel::Information, "path" = path, "status" = 200u32)?;
+/// ```
+///
+/// The provider is unregistered when it is dropped.
+pub struct TraceProvider {
+    handle: u64,
+    traits: Vec<u8>,
+    // Boxed so that its address remains stable for the enable callback.
+    callback: Option<Box<Callback>>,
+}
+
+type Callback = Box<dyn Fn(ProviderControl) + Send + Sync>;
+
+unsafe impl Send for TraceProvider {}
+unsafe impl Sync for TraceProvider {}
+
+impl TraceProvider {
+    /// Registers a provider with the given name and identifier.
+    pub fn register(name: &str, id: GUID) -> Result<Self> {
+        Self::register_provider(name, id, None)
+    }
+
+    /// Registers a provider with a callback that is called whenever a trace session enables or disables the provider.
+    ///
+    /// The callback may be called on any thread, including during registration if a session has already enabled
+    /// the provider.
+    pub fn register_with_callback<F: Fn(ProviderControl) + Send + Sync + 'static>(name: &str, id: GUID, callback: F) -> Result<Self> {
+        Self::register_provider(name, id, Some(Box::new(Box::new(callback))))
+    }
+
+    fn register_provider(name: &str, id: GUID, callback: Option<Box<Callback>>) -> Result<Self> {
+        // The provider traits consist of their total size followed by the provider name.
+        let mut traits = vec![0, 0];
+        traits.extend_from_slice(name.as_bytes());
+        traits.push(0);
+        let size = (traits.len() as u16).to_le_bytes();
+        traits[..2].copy_from_slice(&size);
+
+        let mut provider = Self { handle: 0, traits, callback };
+        let context = provider.callback.as_deref().map(|callback| callback as *const Callback as *const core::ffi::c_void);
+        let enable: PENABLECALLBACK = if context.is_some() { Some(enable_callback) } else { None };
+
+        unsafe {
+            let error = EventRegister(&id, enable, context, &mut provider.handle);
+
+            if error != 0 {
+                return Err(windows_core::HRESULT::from_win32(error).into());
+            }
+
+            // Older versions of Windows don't support provider traits, but decoders can still find them in each event.
+            _ = EventSetInformation(REGHANDLE(provider.handle as i64), EventProviderSetTraits, provider.traits.as_ptr() as _, provider.traits.len() as u32);
+        }
+
+        Ok(provider)
+    }
+
+    /// Returns `true` if any trace session has enabled the provider for events with the given level and keywords.
+    pub fn is_enabled(&self, level: TraceLevel, keywords: u64) -> bool {
+        unsafe { EventProviderEnabled(REGHANDLE(self.handle as i64), level as u8, keywords).as_bool() }
+    }
+
+    /// Returns `true` if any trace session has enabled the provider for the event's level and keywords.
+    pub fn is_event_enabled(&self, event: &TraceEvent<'_>) -> bool {
+        self.is_enabled(event.level, event.keywords)
+    }
+
+    /// Writes the event if any trace session has enabled the provider for the event's level and keywords.
+    pub fn write(&self, event: &TraceEvent<'_>) -> Result<()> {
+        if !self.is_event_enabled(event) {
+            return Ok(());
+        }
+
+        // The event metadata consists of its total size, the tags, the event name, and the name and type of each field.
+        let mut metadata = vec![0, 0, 0];
+        metadata.extend_from_slice(event.name.as_bytes());
+        metadata.push(0);
+        metadata.extend_from_slice(&event.fields);
+        let size = (metadata.len() as u16).to_le_bytes();
+        metadata[..2].copy_from_slice(&size);
+
+        let descriptors = [descriptor(&self.traits, EVENT_DATA_DESCRIPTOR_TYPE_PROVIDER_METADATA), descriptor(&metadata, EVENT_DATA_DESCRIPTOR_TYPE_EVENT_METADATA), descriptor(&event.data, EVENT_DATA_DESCRIPTOR_TYPE_NONE)];
+        let count = if event.data.is_empty() { 2 } else { 3 };
+
+        // TraceLogging events use channel 11 so that decoders recognize them.
+        let header = EVENT_DESCRIPTOR { Channel: 11, Level: event.level as u8, Opcode: event.opcode, Keyword: event.keywords, ..Default::default() };
+
+        let error = unsafe { EventWriteTransfer(REGHANDLE(self.handle as i64), &header, event.activity_id.as_ref().map(|id| id as *const _), event.related_activity_id.as_ref().map(|id| id as *const _), Some(&descriptors[..count])) };
+
+        if error == 0 {
+            Ok(())
+        } else {
+            Err(windows_core::HRESULT::from_win32(error).into())
+        }
+    }
+}
+
+impl Drop for TraceProvider {
+    fn drop(&mut self) {
+        // Unregistering waits for any running enable callback, so the callback may be released afterwards.
+        unsafe { EventUnregister(REGHANDLE(self.handle as i64)) };
+    }
+}
+
+impl core::fmt::Debug for TraceProvider {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TraceProvider").field("handle", &self.handle).finish()
+    }
+}
+
+unsafe extern "system" fn enable_callback(_source: *const GUID, state: ENABLECALLBACK_ENABLED_STATE, level: u8, any_keywords: u64, all_keywords: u64, _filter: *const EVENT_FILTER_DESCRIPTOR, context: *mut core::ffi::c_void) {
+    let callback = &*(context as *const Callback);
+
+    callback(match state {
+        EVENT_CONTROL_CODE_DISABLE_PROVIDER => ProviderControl::Disable,
+        EVENT_CONTROL_CODE_CAPTURE_STATE => ProviderControl::CaptureState,
+        _ => ProviderControl::Enable { level, any_keywords, all_keywords },
+    });
+}
+
+fn descriptor(data: &[u8], kind: u32) -> EVENT_DATA_DESCRIPTOR {
+    let mut descriptor = EVENT_DATA_DESCRIPTOR { Ptr: data.as_ptr() as u64, Size: data.len() as u32, ..Default::default() };
+    descriptor.Anonymous.Anonymous.Type = kind as u8;
+    descriptor
+}
+
+/// A change to the state of a provider, passed to the callback given to `TraceProvider::register_with_callback`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProviderControl {
+    /// A trace session enabled the provider, or changed the level or keywords that it is enabled for.
+    ///
+    /// A level of zero enables events of every level.
+    Enable { level: u8, any_keywords: u64, all_keywords: u64 },
+    /// The last trace session that enabled the provider has disabled it.
+    Disable,
+    /// A trace session requested that the provider write events describing its current state.
+    CaptureState,
+}
+
+/// The severity of an event, where lower levels are more severe.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TraceLevel {
+    /// Written whenever the provider is enabled, regardless of the session's level.
+    LogAlways = 0,
+    Critical = 1,
+    Error = 2,
+    Warning = 3,
+    Information = 4,
+    Verbose = 5,
+}
+
+/// A TraceLogging event under construction, written with `TraceProvider::write`.
+///
+/// This is usually created by the `trace_event!` macro.
+#[derive(Clone, Debug)]
+pub struct TraceEvent<'a> {
+    name: &'a str,
+    level: TraceLevel,
+    keywords: u64,
+    opcode: u8,
+    activity_id: Option<GUID>,
+    related_activity_id: Option<GUID>,
+    fields: Vec<u8>,
+    data: Vec<u8>,
+}
+
+impl<'a> TraceEvent<'a> {
+    /// Creates an event with the given name and level, which should not contain a nul character.
+    pub fn new(name: &'a str, level: TraceLevel) -> Self {
+        Self { name, level, keywords: 0, opcode: 0, activity_id: None, related_activity_id: None, fields: Vec::new(), data: Vec::new() }
+    }
+
+    /// Sets the keywords of the event, which trace sessions use to choose the events that they receive.
+    pub fn keywords(mut self, keywords: u64) -> Self {
+        self.keywords = keywords;
+        self
+    }
+
+    /// Sets the opcode of the event, such as `EVENT_TRACE_TYPE_START` for the start of an activity.
+    pub fn opcode(mut self, opcode: u8) -> Self {
+        self.opcode = opcode;
+        self
+    }
+
+    /// Sets the activity that the event belongs to, which otherwise is the current thread's activity.
+    pub fn activity_id(mut self, id: GUID) -> Self {
+        self.activity_id = Some(id);
+        self
+    }
+
+    /// Sets the parent of the event's activity, typically for the event that starts the activity.
+    pub fn related_activity_id(mut self, id: GUID) -> Self {
+        self.related_activity_id = Some(id);
+        self
+    }
+
+    /// Adds a field with the given name, which should not contain a nul character.
+    pub fn field<T: TraceField + ?Sized>(mut self, name: &str, value: &T) -> Self {
+        self.fields.extend_from_slice(name.as_bytes());
+        self.fields.push(0);
+
+        match T::OUT_TYPE {
+            Some(out_type) => self.fields.extend_from_slice(&[T::IN_TYPE | 0x80, out_type]),
+            None => self.fields.push(T::IN_TYPE),
+        }
+
+        value.append(&mut self.data);
+        self
+    }
+}
+
+/// Creates a new activity identifier, which is unique across the machine.
+pub fn new_activity_id() -> GUID {
+    let mut id = GUID::zeroed();
+    unsafe { EventActivityIdControl(EVENT_ACTIVITY_CTRL_CREATE_ID, &mut id) };
+    id
+}
+
+/// A value that can be written as a TraceLogging field.
+pub trait TraceField {
+    /// The TraceLogging input type of the field, which describes its encoding.
+    const IN_TYPE: u8;
+    /// The TraceLogging output type of the field, which describes how it is formatted, if it differs from the default.
+    const OUT_TYPE: Option<u8> = None;
+
+    /// Appends the encoded value to the event's data.
+    fn append(&self, data: &mut Vec<u8>);
+}
+
+macro_rules! trace_field {
+    ($($ty:ty => $in_type:literal,)*) => {
+        $(
+            impl TraceField for $ty {
+                const IN_TYPE: u8 = $in_type;
+
+                fn append(&self, data: &mut Vec<u8>) {
+                    data.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+trace_field! {
+    i8 => 3,
+    u8 => 4,
+    i16 => 5,
+    u16 => 6,
+    i32 => 7,
+    u32 => 8,
+    i64 => 9,
+    u64 => 10,
+    f32 => 11,
+    f64 => 12,
+}
+
+impl TraceField for bool {
+    const IN_TYPE: u8 = 13;
+
+    fn append(&self, data: &mut Vec<u8>) {
+        data.extend_from_slice(&(*self as i32).to_le_bytes());
+    }
+}
+
+impl TraceField for GUID {
+    const IN_TYPE: u8 = 15;
+
+    fn append(&self, data: &mut Vec<u8>) {
+        data.extend_from_slice(&self.data1.to_le_bytes());
+        data.extend_from_slice(&self.data2.to_le_bytes());
+        data.extend_from_slice(&self.data3.to_le_bytes());
+        data.extend_from_slice(&self.data4);
+    }
+}
+
+// Strings are counted, so they are truncated to the 65,535 bytes that a count can describe.
+fn counted(bytes: &[u8], data: &mut Vec<u8>) {
+    let len = bytes.len().min(u16::MAX as usize);
+    data.extend_from_slice(&(len as u16).to_le_bytes());
+    data.extend_from_slice(&bytes[..len]);
+}
+
+impl TraceField for str {
+    const IN_TYPE: u8 = 23;
+    const OUT_TYPE: Option<u8> = Some(35);
+
+    fn append(&self, data: &mut Vec<u8>) {
+        // Truncation must not split a character, so that the field remains valid UTF-8.
+        let mut len = self.len().min(u16::MAX as usize);
+
+        while !self.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        counted(&self.as_bytes()[..len], data);
+    }
+}
+
+impl TraceField for String {
+    const IN_TYPE: u8 = <str as TraceField>::IN_TYPE;
+    const OUT_TYPE: Option<u8> = <str as TraceField>::OUT_TYPE;
+
+    fn append(&self, data: &mut Vec<u8>) {
+        self.as_str().append(data)
+    }
+}
+
+impl TraceField for [u16] {
+    const IN_TYPE: u8 = 22;
+
+    fn append(&self, data: &mut Vec<u8>) {
+        let len = self.len().min(u16::MAX as usize / 2);
+        data.extend_from_slice(&((len * 2) as u16).to_le_bytes());
+        self[..len].iter().for_each(|c| data.extend_from_slice(&c.to_le_bytes()));
+    }
+}
+
+impl TraceField for HSTRING {
+    const IN_TYPE: u8 = <[u16] as TraceField>::IN_TYPE;
+
+    fn append(&self, data: &mut Vec<u8>) {
+        self.as_wide().append(data)
+    }
+}
+
+impl TraceField for [u8] {
+    const IN_TYPE: u8 = 14;
+
+    fn append(&self, data: &mut Vec<u8>) {
+        counted(self, data)
+    }
+}
+
+impl<T: TraceField + ?Sized> TraceField for &T {
+    const IN_TYPE: u8 = T::IN_TYPE;
+    const OUT_TYPE: Option<u8> = T::OUT_TYPE;
+
+    fn append(&self, data: &mut Vec<u8>) {
+        (**self).append(data)
+    }
+}
+
+/// Writes a TraceLogging event with the given name, level, and fields to a `TraceProvider`.
+///
+/// Options such as `keywords`, `opcode`, and `activity_id` may follow the level, separated from the fields by a
+/// semicolon. The fields are not evaluated unless a trace session has enabled the provider for the event's level
+/// and keywords. Returns the result of `TraceProvider::write`.
+///
+/// ```rust,ignore
+/// trace_event!(provider, "Started", TraceLevel::Information)?;
+/// trace_event!(provider, "Read", TraceLevel::Verbose, "path" = path, "bytes" = len)?;
+/// trace_event!(provider, "Failed", TraceLevel::Error, keywords = 0x2, activity_id = id; "code" = code.0)?;
+/// ```
+#[macro_export]
+macro_rules! trace_event {
+    ($provider:expr, $name:expr, $level:expr $(, $option:ident = $option_value:expr)* ; $($field:literal = $value:expr),* $(,)?) => {{
+        let provider: &$crate::Win32::System::Diagnostics::Etw::TraceProvider = &$provider;
+        let event = $crate::Win32::System::Diagnostics::Etw::TraceEvent::new($name, $level) $(.$option($option_value))*;
+
+        if provider.is_event_enabled(&event) {
+            provider.write(&event $(.field($field, &$value))*)
+        } else {
+            ::core::result::Result::Ok(())
+        }
+    }};
+    ($provider:expr, $name:expr, $level:expr $(, $field:literal = $value:expr)* $(,)?) => {
+        $crate::trace_event!($provider, $name, $level ; $($field = $value),*)
+    };
+}
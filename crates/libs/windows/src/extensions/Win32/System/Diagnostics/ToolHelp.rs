@@ -0,0 +1,384 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::Diagnostics::ToolHelp::*;
+use std::ffi::OsString;
+use std::os::windows::ffi::OsStringExt;
+use std::path::PathBuf;
+use windows_core::{Owned, Result};
+
+/// A snapshot of the processes, threads, modules, and heaps in the system, taken with `CreateToolhelp32Snapshot`.
+///
+/// ```rust,ignore
+/// let snapshot = Snapshot::new(TH32CS_SNAPPROCESS, 0)?;
+///
+/// for process in snapshot.processes() {
+///     println!("{} {}", process.id, process.name);
+/// }
+/// ```
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct Snapshot(Owned<HANDLE>);
+
+unsafe impl Send for Snapshot {}
+unsafe impl Sync for Snapshot {}
+
+impl Snapshot {
+    /// Takes a snapshot of what the flags include, such as `TH32CS_SNAPPROCESS`.
+    ///
+    /// Modules and heaps are only included for the given process, where zero refers to the current process.
+    pub fn new(flags: CREATE_TOOLHELP_SNAPSHOT_FLAGS, process_id: u32) -> Result<Self> {
+        loop {
+            match unsafe { CreateToolhelp32Snapshot(flags, process_id) } {
+                Ok(handle) => return Ok(Self(unsafe { Owned::new(handle) })),
+                // Module snapshots fail with `ERROR_BAD_LENGTH` while the process is loading or unloading modules.
+                Err(error) if error.code() == ERROR_BAD_LENGTH.to_hresult() => continue,
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Returns the snapshot handle.
+    pub fn handle(&self) -> HANDLE {
+        *self.0
+    }
+
+    /// Returns an iterator over the processes in the snapshot.
+    pub fn processes(&self) -> Processes<'_> {
+        Processes(Walk::new(self, Process32FirstW, Process32NextW))
+    }
+
+    /// Returns an iterator over the threads in the snapshot, which includes the threads of every process.
+    pub fn threads(&self) -> Threads<'_> {
+        Threads(Walk::new(self, Thread32First, Thread32Next))
+    }
+
+    /// Returns an iterator over the modules loaded by the process in the snapshot.
+    pub fn modules(&self) -> Modules<'_> {
+        Modules(Walk::new(self, Module32FirstW, Module32NextW))
+    }
+
+    /// Returns an iterator over the heaps of the process in the snapshot.
+    pub fn heaps(&self) -> Heaps<'_> {
+        Heaps(Walk::new(self, Heap32ListFirst, Heap32ListNext))
+    }
+}
+
+// Walks one of the lists in a snapshot, where each entry starts with its size.
+struct Walk<'a, T> {
+    snapshot: &'a Snapshot,
+    entry: T,
+    started: bool,
+    done: bool,
+    first: unsafe fn(HANDLE, *mut T) -> Result<()>,
+    next: unsafe fn(HANDLE, *mut T) -> Result<()>,
+}
+
+impl<'a, T: Default> Walk<'a, T> {
+    fn new(snapshot: &'a Snapshot, first: unsafe fn(HANDLE, *mut T) -> Result<()>, next: unsafe fn(HANDLE, *mut T) -> Result<()>) -> Self {
+        let mut entry = T::default();
+
+        // Every entry begins with a `dwSize` field, which is either a `u32` or a `usize`. Both are little endian,
+        // so writing the size as a `u32` is enough.
+        unsafe { *(&mut entry as *mut T as *mut u32) = core::mem::size_of::<T>() as u32 };
+
+        Self { snapshot, entry, started: false, done: false, first, next }
+    }
+
+    fn advance(&mut self) -> Option<&T> {
+        if self.done {
+            return None;
+        }
+
+        let walk = if self.started { self.next } else { self.first };
+        self.started = true;
+
+        // The walk ends with `ERROR_NO_MORE_FILES`, and there's nothing useful to do with any other error.
+        if unsafe { walk(self.snapshot.handle(), &mut self.entry) }.is_err() {
+            self.done = true;
+            return None;
+        }
+
+        Some(&self.entry)
+    }
+}
+
+fn from_wide(value: &[u16]) -> OsString {
+    let len = value.iter().position(|c| *c == 0).unwrap_or(value.len());
+    OsString::from_wide(&value[..len])
+}
+
+/// A process in a `Snapshot`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcessEntry {
+    /// The process identifier.
+    pub id: u32,
+    /// The identifier of the process that created this process, which may since have exited.
+    pub parent_id: u32,
+    /// The number of threads in the process.
+    pub threads: u32,
+    /// The base priority of threads created by the process.
+    pub base_priority: i32,
+    /// The file name of the process's executable, without its directory.
+    pub name: String,
+}
+
+/// An iterator over the processes in a `Snapshot`.
+pub struct Processes<'a>(Walk<'a, PROCESSENTRY32W>);
+
+impl Iterator for Processes<'_> {
+    type Item = ProcessEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.advance().map(|entry| ProcessEntry {
+            id: entry.th32ProcessID,
+            parent_id: entry.th32ParentProcessID,
+            threads: entry.cntThreads,
+            base_priority: entry.pcPriClassBase,
+            name: from_wide(&entry.szExeFile).to_string_lossy().into_owned(),
+        })
+    }
+}
+
+/// A thread in a `Snapshot`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThreadEntry {
+    /// The thread identifier.
+    pub id: u32,
+    /// The identifier of the process that owns the thread.
+    pub process_id: u32,
+    /// The base priority of the thread.
+    pub base_priority: i32,
+}
+
+/// An iterator over the threads in a `Snapshot`.
+pub struct Threads<'a>(Walk<'a, THREADENTRY32>);
+
+impl Iterator for Threads<'_> {
+    type Item = ThreadEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.advance().map(|entry| ThreadEntry { id: entry.th32ThreadID, process_id: entry.th32OwnerProcessID, base_priority: entry.tpBasePri })
+    }
+}
+
+/// A module in a `Snapshot`.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct ModuleEntry {
+    /// The identifier of the process that loaded the module.
+    pub process_id: u32,
+    /// The module handle, which is only valid within that process.
+    pub handle: HMODULE,
+    /// The address at which the module is loaded.
+    pub base_address: usize,
+    /// The size of the module in bytes.
+    pub size: u32,
+    /// The module name.
+    pub name: String,
+    /// The full path of the module.
+    pub path: PathBuf,
+}
+
+unsafe impl Send for ModuleEntry {}
+unsafe impl Sync for ModuleEntry {}
+
+/// An iterator over the modules in a `Snapshot`.
+pub struct Modules<'a>(Walk<'a, MODULEENTRY32W>);
+
+impl Iterator for Modules<'_> {
+    type Item = ModuleEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.advance().map(|entry| ModuleEntry {
+            process_id: entry.th32ProcessID,
+            handle: entry.hModule,
+            base_address: entry.modBaseAddr as usize,
+            size: entry.modBaseSize,
+            name: from_wide(&entry.szModule).to_string_lossy().into_owned(),
+            path: from_wide(&entry.szExePath).into(),
+        })
+    }
+}
+
+/// A heap in a `Snapshot`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeapEntry {
+    /// The identifier of the process that owns the heap.
+    pub process_id: u32,
+    /// The heap identifier.
+    pub id: usize,
+    /// Whether this is the default heap of the process.
+    pub default: bool,
+}
+
+impl HeapEntry {
+    /// Returns an iterator over the blocks allocated from the heap.
+    ///
+    /// This reads the memory of the process and is very slow for large heaps.
+    pub fn blocks(&self) -> HeapBlocks {
+        let mut entry = HEAPENTRY32 { dwSize: core::mem::size_of::<HEAPENTRY32>(), ..Default::default() };
+        let pending = unsafe { Heap32First(&mut entry, self.process_id, self.id) }.is_ok();
+        HeapBlocks { entry, pending, done: !pending }
+    }
+}
+
+/// An iterator over the heaps in a `Snapshot`.
+pub struct Heaps<'a>(Walk<'a, HEAPLIST32>);
+
+impl Iterator for Heaps<'_> {
+    type Item = HeapEntry;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.advance().map(|entry| HeapEntry { process_id: entry.th32ProcessID, id: entry.th32HeapID, default: entry.dwFlags & HF32_DEFAULT != 0 })
+    }
+}
+
+/// A block allocated from a heap.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct HeapBlock {
+    /// The address of the block in the process that owns the heap.
+    pub address: usize,
+    /// The size of the block in bytes.
+    pub size: usize,
+    /// Whether the block is `LF32_FIXED`, `LF32_FREE`, or `LF32_MOVEABLE`.
+    pub flags: HEAPENTRY32_FLAGS,
+}
+
+/// An iterator over the blocks of a heap, produced by `HeapEntry::blocks`.
+pub struct HeapBlocks {
+    entry: HEAPENTRY32,
+    // Whether the entry holds a block that hasn't been returned yet.
+    pending: bool,
+    done: bool,
+}
+
+impl Iterator for HeapBlocks {
+    type Item = HeapBlock;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if self.pending {
+            self.pending = false;
+        } else if unsafe { Heap32Next(&mut self.entry) }.is_err() {
+            self.done = true;
+            return None;
+        }
+
+        Some(HeapBlock { address: self.entry.dwAddress, size: self.entry.dwBlockSize, flags: self.entry.dwFlags })
+    }
+}
+
+/// Information about a running process, queried through a handle with limited access.
+///
+/// ```rust,ignore
+/// let info = ProcessInfo::open(process_id)?;
+/// println!("{} {:?}", info.image_name()?.display(), info.times()?.user);
+/// ```
+#[cfg(feature = "Win32_System_Threading")]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct ProcessInfo(Owned<HANDLE>);
+
+#[cfg(feature = "Win32_System_Threading")]
+unsafe impl Send for ProcessInfo {}
+#[cfg(feature = "Win32_System_Threading")]
+unsafe impl Sync for ProcessInfo {}
+
+/// The times of a process, as returned by `ProcessInfo::times`.
+#[cfg(feature = "Win32_System_Threading")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProcessTimes {
+    /// When the process was created.
+    pub creation: std::time::SystemTime,
+    /// When the process exited, or `None` if it is still running.
+    pub exit: Option<std::time::SystemTime>,
+    /// The time the process spent executing in kernel mode.
+    pub kernel: core::time::Duration,
+    /// The time the process spent executing in user mode.
+    pub user: core::time::Duration,
+}
+
+/// The memory usage of a process, as returned by `ProcessInfo::memory`.
+#[cfg(all(feature = "Win32_System_ProcessStatus", feature = "Win32_System_Threading"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryCounters {
+    /// The number of page faults.
+    pub page_faults: u32,
+    /// The current working set size in bytes.
+    pub working_set: usize,
+    /// The peak working set size in bytes.
+    pub peak_working_set: usize,
+    /// The current amount of committed private memory in bytes.
+    pub private_usage: usize,
+    /// The peak amount of committed private memory in bytes.
+    pub peak_private_usage: usize,
+}
+
+#[cfg(feature = "Win32_System_Threading")]
+impl ProcessInfo {
+    /// Opens the process with the given identifier for querying information.
+    pub fn open(process_id: u32) -> Result<Self> {
+        use crate::Win32::System::Threading::*;
+        unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, process_id).map(|handle| Self(Owned::new(handle))) }
+    }
+
+    /// Opens the current process.
+    pub fn current() -> Result<Self> {
+        Self::open(unsafe { crate::Win32::System::Threading::GetCurrentProcessId() })
+    }
+
+    /// Returns the process handle.
+    pub fn handle(&self) -> HANDLE {
+        *self.0
+    }
+
+    /// Returns the full path of the process's executable.
+    pub fn image_name(&self) -> Result<PathBuf> {
+        use crate::Win32::System::Threading::*;
+        let mut buffer = vec![0u16; MAX_PATH as usize];
+
+        loop {
+            let mut len = buffer.len() as u32;
+
+            match unsafe { QueryFullProcessImageNameW(self.handle(), PROCESS_NAME_WIN32, windows_core::PWSTR(buffer.as_mut_ptr()), &mut len) } {
+                Ok(()) => return Ok(OsString::from_wide(&buffer[..len as usize]).into()),
+                Err(error) if error.code() == ERROR_INSUFFICIENT_BUFFER.to_hresult() && buffer.len() < u16::MAX as usize => buffer.resize(buffer.len() * 2, 0),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Returns the creation and exit times of the process along with the time it spent executing.
+    pub fn times(&self) -> Result<ProcessTimes> {
+        let mut creation = FILETIME::default();
+        let mut exit = FILETIME::default();
+        let mut kernel = FILETIME::default();
+        let mut user = FILETIME::default();
+        unsafe { crate::Win32::System::Threading::GetProcessTimes(self.handle(), &mut creation, &mut exit, &mut kernel, &mut user)? };
+
+        let ticks = |time: FILETIME| (time.dwHighDateTime as u64) << 32 | time.dwLowDateTime as u64;
+        let duration = |time: FILETIME| core::time::Duration::from_nanos(ticks(time).saturating_mul(100));
+
+        // `FILETIME` counts 100-nanosecond intervals since 1601, which is 11,644,473,600 seconds before the Unix epoch.
+        let system_time = |time: FILETIME| std::time::UNIX_EPOCH + duration(time) - core::time::Duration::from_secs(11_644_473_600);
+
+        Ok(ProcessTimes { creation: system_time(creation), exit: (ticks(exit) != 0).then(|| system_time(exit)), kernel: duration(kernel), user: duration(user) })
+    }
+
+    /// Returns the memory usage of the process.
+    #[cfg(feature = "Win32_System_ProcessStatus")]
+    pub fn memory(&self) -> Result<MemoryCounters> {
+        use crate::Win32::System::ProcessStatus::*;
+        let mut counters = PROCESS_MEMORY_COUNTERS { cb: core::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32, ..Default::default() };
+        unsafe { GetProcessMemoryInfo(self.handle(), &mut counters, counters.cb)? };
+
+        Ok(MemoryCounters {
+            page_faults: counters.PageFaultCount,
+            working_set: counters.WorkingSetSize,
+            peak_working_set: counters.PeakWorkingSetSize,
+            private_usage: counters.PagefileUsage,
+            peak_private_usage: counters.PeakPagefileUsage,
+        })
+    }
+}
@@ -0,0 +1,420 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::EventLog::*;
+use core::ffi::c_void;
+use core::mem::ManuallyDrop;
+use std::sync::Mutex;
+use windows_core::{Owned, Result, GUID, HRESULT, HSTRING, PCWSTR};
+
+// The number of event handles requested from each call to `EvtNext`.
+const BATCH: usize = 16;
+
+/// A query over the events in an event log channel or exported log file.
+///
+/// The query either runs once, returning an iterator over the matching events, or subscribes to the channel so
+/// that matching events are delivered as they are logged:
+///
+/// ```rust,ignore
+/// for event in EventQuery::channel("System").filter("*[System[Level=2]]").reverse().run()?.take(10) {
+///     println!("{}", event?.xml()?);
+/// }
+/// ```
+#[derive(Clone)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct EventQuery {
+    path: HSTRING,
+    filter: Option<HSTRING>,
+    flags: EVT_QUERY_FLAGS,
+}
+
+impl EventQuery {
+    /// Queries the events in a channel, such as `Application` or `Microsoft-Windows-Sysmon/Operational`.
+    pub fn channel<T: Into<HSTRING>>(path: T) -> Self {
+        Self { path: path.into(), filter: None, flags: EvtQueryChannelPath }
+    }
+
+    /// Queries the events in an exported `.evtx` log file.
+    pub fn file<T: Into<HSTRING>>(path: T) -> Self {
+        Self { path: path.into(), filter: None, flags: EvtQueryFilePath }
+    }
+
+    /// Restricts the query to events that match an XPath filter, such as `*[System[EventID=4624]]`.
+    ///
+    /// By default, every event is returned.
+    pub fn filter<T: Into<HSTRING>>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    /// Returns the newest events first, rather than the oldest.
+    pub fn reverse(mut self) -> Self {
+        self.flags.0 |= EvtQueryReverseDirection.0;
+        self
+    }
+
+    /// Runs the query, returning an iterator over the matching events.
+    pub fn run(&self) -> Result<Events> {
+        let handle = unsafe { EvtQuery(EVT_HANDLE::default(), &self.path, self.filter_ptr(), self.flags.0)? };
+        Ok(Events { handle: unsafe { Owned::new(handle) }, pending: Vec::new().into_iter(), done: false })
+    }
+
+    /// Subscribes to the channel, calling `callback` with each matching event on a thread pool thread.
+    ///
+    /// Events stop being delivered once the returned `EventSubscription` is dropped. Errors reported by the
+    /// event log service, such as a dropped connection, are passed to the callback as well.
+    pub fn subscribe<F: FnMut(Result<&EventRecord>) + Send + 'static>(&self, start: SubscriptionStart, callback: F) -> Result<EventSubscription> {
+        let callback: Box<Callback> = Box::new(Mutex::new(Box::new(callback)));
+
+        let flags = match start {
+            SubscriptionStart::FutureEvents => EvtSubscribeToFutureEvents,
+            SubscriptionStart::OldestRecord => EvtSubscribeStartAtOldestRecord,
+        };
+
+        let handle = unsafe { EvtSubscribe(EVT_HANDLE::default(), HANDLE::default(), &self.path, self.filter_ptr(), EVT_HANDLE::default(), Some(&*callback as *const Callback as *const c_void), Some(subscription_callback), flags.0)? };
+        Ok(EventSubscription { handle: unsafe { Owned::new(handle) }, _callback: callback })
+    }
+
+    fn filter_ptr(&self) -> PCWSTR {
+        self.filter.as_ref().map_or(PCWSTR::null(), |filter| PCWSTR(filter.as_ptr()))
+    }
+}
+
+/// An iterator over the events returned by `EventQuery::run`.
+pub struct Events {
+    handle: Owned<EVT_HANDLE>,
+    pending: std::vec::IntoIter<EventRecord>,
+    done: bool,
+}
+
+impl Iterator for Events {
+    type Item = Result<EventRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(event) = self.pending.next() {
+            return Some(Ok(event));
+        }
+
+        if self.done {
+            return None;
+        }
+
+        let mut handles = [0; BATCH];
+        let mut returned = 0;
+
+        if let Err(error) = unsafe { EvtNext(*self.handle, &mut handles, u32::MAX, 0, &mut returned) } {
+            self.done = true;
+
+            if error.code() == ERROR_NO_MORE_ITEMS.to_hresult() {
+                return None;
+            }
+
+            return Some(Err(error));
+        }
+
+        self.pending = handles[..returned as usize].iter().map(|handle| EventRecord(unsafe { Owned::new(EVT_HANDLE(*handle)) })).collect::<Vec<_>>().into_iter();
+        self.pending.next().map(Ok)
+    }
+}
+
+/// Where a subscription starts delivering events from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubscriptionStart {
+    /// Only events logged after the subscription is created are delivered.
+    FutureEvents,
+    /// Every event already in the channel is delivered first, followed by events as they are logged.
+    OldestRecord,
+}
+
+type Callback = Mutex<Box<dyn FnMut(Result<&EventRecord>) + Send>>;
+
+/// A subscription created by `EventQuery::subscribe`, which delivers events until it is dropped.
+pub struct EventSubscription {
+    // Closing the subscription handle waits for any callback in progress, so it must be dropped first.
+    handle: Owned<EVT_HANDLE>,
+    _callback: Box<Callback>,
+}
+
+impl EventSubscription {
+    /// Returns the underlying subscription handle.
+    pub fn handle(&self) -> EVT_HANDLE {
+        *self.handle
+    }
+}
+
+unsafe extern "system" fn subscription_callback(action: EVT_SUBSCRIBE_NOTIFY_ACTION, context: *const c_void, event: EVT_HANDLE) -> u32 {
+    let Ok(mut callback) = (*(context as *const Callback)).lock() else {
+        return 0;
+    };
+
+    if action == EvtSubscribeActionDeliver {
+        // The event handle belongs to the subscription, which closes it once the callback returns.
+        let record = ManuallyDrop::new(EventRecord(Owned::new(event)));
+        callback(Ok(&record));
+    } else {
+        // Errors are reported by passing the error code in place of the event handle.
+        callback(Err(HRESULT::from_win32(event.0 as u32).into()));
+    }
+
+    0
+}
+
+/// An event returned by an `EventQuery`.
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct EventRecord(Owned<EVT_HANDLE>);
+
+impl EventRecord {
+    /// Returns the underlying event handle.
+    pub fn handle(&self) -> EVT_HANDLE {
+        *self.0
+    }
+
+    /// Renders the event as XML.
+    pub fn xml(&self) -> Result<String> {
+        let (buffer, used, _) = render(EVT_HANDLE::default(), *self.0, EvtRenderEventXml)?;
+        Ok(wide_to_string(unsafe { core::slice::from_raw_parts(buffer.as_ptr() as *const u16, used as usize / 2) }))
+    }
+
+    /// Returns the properties from the event's `System` element, which every event has.
+    pub fn system(&self) -> Result<EventSystem> {
+        let values = self.render_values(EvtRenderContextSystem.0, None)?;
+        let value = |id: EVT_SYSTEM_PROPERTY_ID| values.get(id.0 as usize).cloned().unwrap_or(EventValue::Null);
+
+        Ok(EventSystem {
+            provider_name: value(EvtSystemProviderName).as_str().unwrap_or_default().to_string(),
+            provider_guid: value(EvtSystemProviderGuid).as_guid(),
+            event_id: value(EvtSystemEventID).as_u64().unwrap_or_default() as u16,
+            qualifiers: value(EvtSystemQualifiers).as_u64().map(|value| value as u16),
+            level: value(EvtSystemLevel).as_u64().unwrap_or_default() as u8,
+            task: value(EvtSystemTask).as_u64().unwrap_or_default() as u16,
+            opcode: value(EvtSystemOpcode).as_u64().unwrap_or_default() as u8,
+            keywords: value(EvtSystemKeywords).as_u64().unwrap_or_default(),
+            time_created: value(EvtSystemTimeCreated).as_u64().unwrap_or_default(),
+            record_id: value(EvtSystemEventRecordId).as_u64().unwrap_or_default(),
+            activity_id: value(EvtSystemActivityID).as_guid(),
+            process_id: value(EvtSystemProcessID).as_u64().unwrap_or_default() as u32,
+            thread_id: value(EvtSystemThreadID).as_u64().unwrap_or_default() as u32,
+            channel: value(EvtSystemChannel).as_str().unwrap_or_default().to_string(),
+            computer: value(EvtSystemComputer).as_str().unwrap_or_default().to_string(),
+        })
+    }
+
+    /// Returns the values of the event's `EventData` or `UserData` element, in the order they appear.
+    pub fn user_values(&self) -> Result<Vec<EventValue>> {
+        self.render_values(EvtRenderContextUser.0, None)
+    }
+
+    /// Returns the values selected by each XPath expression, such as `Event/EventData/Data[@Name='TargetUserName']`.
+    ///
+    /// Expressions that don't match anything in the event return `EventValue::Null`.
+    pub fn values(&self, paths: &[&str]) -> Result<Vec<EventValue>> {
+        let paths: Vec<HSTRING> = paths.iter().map(|path| HSTRING::from(*path)).collect();
+        let paths: Vec<PCWSTR> = paths.iter().map(|path| PCWSTR(path.as_ptr())).collect();
+        self.render_values(EvtRenderContextValues.0, Some(&paths))
+    }
+
+    /// Returns the event's message, formatted with the message resources of the provider that logged it.
+    pub fn message(&self) -> Result<String> {
+        let provider = HSTRING::from(self.system()?.provider_name);
+
+        unsafe {
+            let metadata = Owned::new(EvtOpenPublisherMetadata(EVT_HANDLE::default(), &provider, PCWSTR::null(), 0, 0)?);
+            let mut used = 0;
+
+            match EvtFormatMessage(*metadata, *self.0, 0, None, EvtFormatMessageEvent.0, None, &mut used) {
+                Err(error) if error.code() != ERROR_INSUFFICIENT_BUFFER.to_hresult() => return Err(error),
+                _ => {}
+            }
+
+            let mut buffer = vec![0; used as usize];
+            EvtFormatMessage(*metadata, *self.0, 0, None, EvtFormatMessageEvent.0, Some(&mut buffer), &mut used)?;
+            Ok(wide_to_string(&buffer))
+        }
+    }
+
+    fn render_values(&self, flags: u32, paths: Option<&[PCWSTR]>) -> Result<Vec<EventValue>> {
+        unsafe {
+            let context = Owned::new(EvtCreateRenderContext(paths, flags)?);
+            let (buffer, _, count) = render(*context, *self.0, EvtRenderEventValues)?;
+            let variants = core::slice::from_raw_parts(buffer.as_ptr() as *const EVT_VARIANT, count as usize);
+            Ok(variants.iter().map(|variant| EventValue::from_variant(variant)).collect())
+        }
+    }
+}
+
+// Renders into a buffer that is suitably aligned for the `EVT_VARIANT` array that value rendering produces.
+fn render(context: EVT_HANDLE, fragment: EVT_HANDLE, flags: EVT_RENDER_FLAGS) -> Result<(Vec<u64>, u32, u32)> {
+    let mut used = 0;
+    let mut count = 0;
+
+    match unsafe { EvtRender(context, fragment, flags.0, 0, None, &mut used, &mut count) } {
+        Err(error) if error.code() != ERROR_INSUFFICIENT_BUFFER.to_hresult() => return Err(error),
+        _ => {}
+    }
+
+    let mut buffer = vec![0u64; (used as usize).div_ceil(core::mem::size_of::<u64>())];
+    unsafe { EvtRender(context, fragment, flags.0, used, Some(buffer.as_mut_ptr() as _), &mut used, &mut count)? };
+    Ok((buffer, used, count))
+}
+
+fn wide_to_string(wide: &[u16]) -> String {
+    let len = wide.iter().position(|c| *c == 0).unwrap_or(wide.len());
+    String::from_utf16_lossy(&wide[..len])
+}
+
+/// The properties from an event's `System` element.
+///
+/// Properties that the event doesn't include are zero or empty.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventSystem {
+    /// The name of the provider that logged the event.
+    pub provider_name: String,
+    /// The identifier of the provider that logged the event, which classic event sources don't have.
+    pub provider_guid: Option<GUID>,
+    /// The event identifier.
+    pub event_id: u16,
+    /// The high word of the event identifier logged by classic event sources.
+    pub qualifiers: Option<u16>,
+    /// The level, where lower values are more severe.
+    pub level: u8,
+    /// The task that the event belongs to.
+    pub task: u16,
+    /// The opcode of the event.
+    pub opcode: u8,
+    /// The keywords that categorize the event.
+    pub keywords: u64,
+    /// The time that the event was logged, as a `FILETIME` value.
+    pub time_created: u64,
+    /// The event's record number in the channel.
+    pub record_id: u64,
+    /// The activity that the event belongs to.
+    pub activity_id: Option<GUID>,
+    /// The process that logged the event.
+    pub process_id: u32,
+    /// The thread that logged the event.
+    pub thread_id: u32,
+    /// The channel that the event was logged to.
+    pub channel: String,
+    /// The name of the computer that logged the event.
+    pub computer: String,
+}
+
+/// A typed value rendered from an event.
+#[derive(Clone, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub enum EventValue {
+    Null,
+    String(String),
+    Bool(bool),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    F32(f32),
+    F64(f64),
+    /// A `FILETIME` value.
+    FileTime(u64),
+    SystemTime(SYSTEMTIME),
+    Guid(GUID),
+    Binary(Vec<u8>),
+    /// A security identifier in its binary form.
+    Sid(Vec<u8>),
+    Size(usize),
+    Array(Vec<EventValue>),
+    /// A value of a type that has no Rust representation, such as an event handle.
+    Unsupported(u32),
+}
+
+impl EventValue {
+    /// Returns the value if it is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it is an integer that fits in a `u64`, including `FILETIME` values.
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Self::I8(value) => value.try_into().ok(),
+            Self::I16(value) => value.try_into().ok(),
+            Self::I32(value) => value.try_into().ok(),
+            Self::I64(value) => value.try_into().ok(),
+            Self::U8(value) => Some(value.into()),
+            Self::U16(value) => Some(value.into()),
+            Self::U32(value) => Some(value.into()),
+            Self::U64(value) | Self::FileTime(value) => Some(value),
+            Self::Size(value) => Some(value as u64),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it is a GUID.
+    pub fn as_guid(&self) -> Option<GUID> {
+        match self {
+            Self::Guid(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    unsafe fn from_variant(variant: &EVT_VARIANT) -> Self {
+        let kind = EVT_VARIANT_TYPE((variant.Type & EVT_VARIANT_TYPE_MASK) as i32);
+        let value = &variant.Anonymous;
+
+        if variant.Type & EVT_VARIANT_TYPE_ARRAY != 0 {
+            let size = match kind {
+                EvtVarTypeSByte | EvtVarTypeByte => 1,
+                EvtVarTypeInt16 | EvtVarTypeUInt16 => 2,
+                EvtVarTypeBoolean | EvtVarTypeInt32 | EvtVarTypeUInt32 | EvtVarTypeHexInt32 | EvtVarTypeSingle => 4,
+                EvtVarTypeInt64 | EvtVarTypeUInt64 | EvtVarTypeHexInt64 | EvtVarTypeDouble | EvtVarTypeFileTime => 8,
+                EvtVarTypeSysTime => core::mem::size_of::<SYSTEMTIME>(),
+                EvtVarTypeGuid => core::mem::size_of::<GUID>(),
+                _ => core::mem::size_of::<usize>(),
+            };
+
+            let base = value.ByteArr as *const u8;
+            return Self::Array((0..variant.Count as usize).map(|index| Self::from_element(kind, base.add(index * size) as _, 0)).collect());
+        }
+
+        match kind {
+            // These scalars are stored behind a pointer, whereas their array elements are stored inline.
+            EvtVarTypeSysTime => Self::from_element(kind, value.SysTimeVal as _, 0),
+            EvtVarTypeGuid => Self::from_element(kind, value.GuidVal as _, 0),
+            EvtVarTypeBinary => Self::from_element(kind, value.BinaryVal as _, variant.Count as usize),
+            _ => Self::from_element(kind, value as *const EVT_VARIANT_0 as _, 0),
+        }
+    }
+
+    // Reads a value stored the way an array element of that type is stored.
+    unsafe fn from_element(kind: EVT_VARIANT_TYPE, element: *const c_void, len: usize) -> Self {
+        match kind {
+            EvtVarTypeNull => Self::Null,
+            EvtVarTypeString | EvtVarTypeEvtXml => Self::String((*(element as *const PCWSTR)).to_string().unwrap_or_default()),
+            EvtVarTypeAnsiString => Self::String((*(element as *const windows_core::PCSTR)).to_string().unwrap_or_default()),
+            EvtVarTypeBoolean => Self::Bool((*(element as *const BOOL)).as_bool()),
+            EvtVarTypeSByte => Self::I8(*(element as *const i8)),
+            EvtVarTypeInt16 => Self::I16(*(element as *const i16)),
+            EvtVarTypeInt32 => Self::I32(*(element as *const i32)),
+            EvtVarTypeInt64 => Self::I64(*(element as *const i64)),
+            EvtVarTypeByte => Self::U8(*(element as *const u8)),
+            EvtVarTypeUInt16 => Self::U16(*(element as *const u16)),
+            EvtVarTypeUInt32 | EvtVarTypeHexInt32 => Self::U32(*(element as *const u32)),
+            EvtVarTypeUInt64 | EvtVarTypeHexInt64 => Self::U64(*(element as *const u64)),
+            EvtVarTypeSingle => Self::F32(*(element as *const f32)),
+            EvtVarTypeDouble => Self::F64(*(element as *const f64)),
+            EvtVarTypeFileTime => Self::FileTime(core::ptr::read_unaligned(element as *const u64)),
+            EvtVarTypeSysTime => Self::SystemTime(*(element as *const SYSTEMTIME)),
+            EvtVarTypeGuid => Self::Guid(*(element as *const GUID)),
+            EvtVarTypeBinary => Self::Binary(core::slice::from_raw_parts(element as *const u8, len).to_vec()),
+            EvtVarTypeSid => {
+                let sid = *(element as *const crate::Win32::Security::PSID);
+                Self::Sid(core::slice::from_raw_parts(sid.0 as *const u8, crate::Win32::Security::GetLengthSid(sid) as usize).to_vec())
+            }
+            EvtVarTypeSizeT => Self::Size(*(element as *const usize)),
+            _ => Self::Unsupported(kind.0 as u32),
+        }
+    }
+}
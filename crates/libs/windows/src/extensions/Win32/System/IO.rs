@@ -0,0 +1,405 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::IO::*;
+use core::cell::UnsafeCell;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::{Arc, Mutex};
+use windows_core::{Borrowed, Owned, Result, HRESULT};
+
+// The completion key used to ask the dispatch thread to close the port and exit.
+const SHUTDOWN: usize = usize::MAX;
+
+/// An I/O completion port that completes `IoFuture`s.
+///
+/// Handles associated with the port via `CompletionPort::associate` support awaitable overlapped I/O.
+///
+/// A port created with `CompletionPort::new` has a dedicated thread that dispatches its completions and is
+/// closed once it and all of its `AsyncHandle`s have been dropped. Alternatively, `CompletionPort::external`
+/// shares a port owned by an external reactor, which dequeues the completion packets itself and hands those
+/// with the port's key back to `CompletionPort::complete`.
+#[derive(Clone)]
+pub struct CompletionPort(Arc<Port>);
+
+struct Port {
+    handle: HANDLE,
+    key: usize,
+    // Set if the port has a dispatch thread that must be asked to exit.
+    dispatcher: bool,
+}
+
+unsafe impl Send for Port {}
+unsafe impl Sync for Port {}
+
+impl Drop for Port {
+    fn drop(&mut self) {
+        if self.dispatcher {
+            unsafe {
+                _ = PostQueuedCompletionStatus(self.handle, 0, SHUTDOWN, None);
+            }
+        }
+    }
+}
+
+impl CompletionPort {
+    /// Creates a new completion port along with the thread that dispatches its completions.
+    pub fn new() -> Result<Self> {
+        unsafe {
+            let port = CreateIoCompletionPort(INVALID_HANDLE_VALUE, None, 0, 1)?;
+            let owned = Dispatcher(Owned::new(port));
+
+            std::thread::Builder::new().name("CompletionPort".to_string()).spawn(move || owned.run())?;
+
+            Ok(Self(Arc::new(Port { handle: port, key: 0, dispatcher: true })))
+        }
+    }
+
+    /// Shares a completion port that is owned and drained by an external reactor.
+    ///
+    /// Handles associated through the returned `CompletionPort` post their completion packets with the given
+    /// `key`. The reactor must pass each packet it dequeues with that key to `CompletionPort::complete`, which
+    /// completes the corresponding `IoFuture`. Packets with other keys belong to the reactor.
+    ///
+    /// # Safety
+    ///
+    /// The `port` must remain open until every `AsyncHandle` and `IoFuture` using it has been dropped and every
+    /// packet with the given `key` has been dequeued and completed.
+    pub unsafe fn external(port: HANDLE, key: usize) -> Self {
+        Self(Arc::new(Port { handle: port, key, dispatcher: false }))
+    }
+
+    /// Returns the raw completion port handle.
+    pub fn handle(&self) -> HANDLE {
+        self.0.handle
+    }
+
+    /// Returns the completion key that this port's handles post their completion packets with.
+    pub fn key(&self) -> usize {
+        self.0.key
+    }
+
+    /// Associates a handle opened for overlapped I/O with the completion port.
+    ///
+    /// The returned `AsyncHandle` takes ownership of the handle and closes it when dropped.
+    pub fn associate(&self, handle: Owned<HANDLE>) -> Result<AsyncHandle> {
+        unsafe {
+            CreateIoCompletionPort(*handle, self.handle(), self.key(), 0)?;
+        }
+
+        Ok(AsyncHandle { handle, port: self.clone() })
+    }
+
+    /// Completes the `IoFuture` that started the overlapped operation described by `packet`.
+    ///
+    /// # Safety
+    ///
+    /// The `packet` must have been dequeued from this port with this port's key, and may only be completed once.
+    pub unsafe fn complete(&self, packet: CompletionPacket) {
+        debug_assert_eq!(packet.key, self.key());
+
+        if !packet.overlapped.is_null() {
+            // Reclaims the reference that was handed to the kernel when the operation was started.
+            let operation = Arc::from_raw(packet.overlapped as *const Operation);
+            operation.complete(packet.result.map(|_| packet.bytes));
+        }
+    }
+}
+
+/// Associates a handle opened for overlapped I/O with a completion port, so that its completion packets are
+/// posted with the given `key`.
+///
+/// The caller remains responsible for the handle, which stays associated with the port until it is closed.
+pub fn associate_handle(port: HANDLE, handle: HANDLE, key: usize) -> Result<()> {
+    unsafe { CreateIoCompletionPort(handle, port, key, 0).map(|_| ()) }
+}
+
+/// Associates a socket opened for overlapped I/O with a completion port, so that its completion packets are
+/// posted with the given `key`.
+///
+/// The caller remains responsible for the socket, which stays associated with the port until it is closed.
+#[cfg(feature = "Win32_Networking_WinSock")]
+pub fn associate_socket(port: HANDLE, socket: crate::Win32::Networking::WinSock::SOCKET, key: usize) -> Result<()> {
+    associate_handle(port, HANDLE(socket.0 as _), key)
+}
+
+/// A completion packet dequeued from an I/O completion port.
+#[derive(Debug)]
+pub struct CompletionPacket {
+    /// The completion key of the handle that completed the operation, or the key passed to
+    /// `PostQueuedCompletionStatus`.
+    pub key: usize,
+
+    /// The number of bytes transferred.
+    pub bytes: u32,
+
+    /// The `OVERLAPPED` structure passed to the operation, which may be null for posted packets.
+    pub overlapped: *mut OVERLAPPED,
+
+    /// The result of the operation.
+    pub result: Result<()>,
+}
+
+unsafe impl Send for CompletionPacket {}
+
+impl CompletionPacket {
+    /// Dequeues a single packet, waiting up to `timeout` milliseconds. Returns `None` if the wait timed out.
+    ///
+    /// A packet for an operation that failed is returned with the operation's error rather than as an `Err`,
+    /// which is reserved for failures of the port itself.
+    pub fn dequeue(port: HANDLE, timeout: u32) -> Result<Option<Self>> {
+        let mut bytes = 0;
+        let mut key = 0;
+        let mut overlapped = core::ptr::null_mut();
+        let result = unsafe { GetQueuedCompletionStatus(port, &mut bytes, &mut key, &mut overlapped, timeout) };
+
+        if overlapped.is_null() {
+            return match result {
+                Ok(()) => Ok(Some(Self { key, bytes, overlapped, result: Ok(()) })),
+                Err(error) if error.code() == HRESULT::from_win32(WAIT_TIMEOUT.0) => Ok(None),
+                Err(error) => Err(error),
+            };
+        }
+
+        Ok(Some(Self { key, bytes, overlapped, result }))
+    }
+
+    /// Dequeues up to `entries.len()` packets, waiting up to `timeout` milliseconds. Returns an empty iterator if
+    /// the wait timed out.
+    pub fn dequeue_many(port: HANDLE, entries: &mut [OVERLAPPED_ENTRY], timeout: u32) -> Result<impl Iterator<Item = Self> + '_> {
+        let mut count = 0;
+
+        if let Err(error) = unsafe { GetQueuedCompletionStatusEx(port, entries, &mut count, timeout, false) } {
+            if error.code() != HRESULT::from_win32(WAIT_TIMEOUT.0) {
+                return Err(error);
+            }
+        }
+
+        Ok(entries[..count as usize].iter().map(Self::from))
+    }
+}
+
+impl From<&OVERLAPPED_ENTRY> for CompletionPacket {
+    fn from(entry: &OVERLAPPED_ENTRY) -> Self {
+        // The `Internal` field holds the operation's `NTSTATUS`, which is translated to the same Win32 error code
+        // that `GetQueuedCompletionStatus` reports.
+        let status = NTSTATUS(entry.Internal as i32);
+
+        let result = if status.is_ok() { Ok(()) } else { Err(HRESULT::from_win32(unsafe { RtlNtStatusToDosError(status) }).into()) };
+
+        Self { key: entry.lpCompletionKey, bytes: entry.dwNumberOfBytesTransferred, overlapped: entry.lpOverlapped, result }
+    }
+}
+
+// Owns the port handle, which is closed once the dispatch thread exits.
+struct Dispatcher(Owned<HANDLE>);
+
+unsafe impl Send for Dispatcher {}
+
+impl Dispatcher {
+    fn run(self) {
+        while let Ok(Some(packet)) = CompletionPacket::dequeue(*self.0, u32::MAX) {
+            if packet.overlapped.is_null() {
+                if packet.key == SHUTDOWN {
+                    break;
+                }
+
+                continue;
+            }
+
+            // Reclaims the reference that was handed to the kernel when the operation was started.
+            let operation = unsafe { Arc::from_raw(packet.overlapped as *const Operation) };
+            operation.complete(packet.result.map(|_| packet.bytes));
+        }
+    }
+}
+
+/// A handle associated with a `CompletionPort`, supporting awaitable overlapped I/O.
+///
+/// Buffers are moved into each operation and handed back on completion, so they remain valid even if
+/// the future is dropped or leaked before the kernel is done with them. Dropping an incomplete future
+/// cancels the operation.
+pub struct AsyncHandle {
+    handle: Owned<HANDLE>,
+    port: CompletionPort,
+}
+
+unsafe impl Send for AsyncHandle {}
+unsafe impl Sync for AsyncHandle {}
+
+impl AsyncHandle {
+    /// Returns the underlying handle.
+    pub fn handle(&self) -> HANDLE {
+        *self.handle
+    }
+
+    /// Returns the completion port that this handle is associated with.
+    pub fn port(&self) -> &CompletionPort {
+        &self.port
+    }
+
+    /// Reads into `buffer` starting at `offset`, returning the buffer truncated to the number of bytes read.
+    ///
+    /// The offset is ignored by handles that don't support seeking, such as pipes.
+    #[cfg(feature = "Win32_Storage_FileSystem")]
+    pub fn read(&self, offset: u64, buffer: Vec<u8>) -> IoFuture<'_, Vec<u8>> {
+        self.start(
+            offset,
+            buffer,
+            Vec::new(),
+            |handle, operation| unsafe { crate::Win32::Storage::FileSystem::ReadFile(handle, Some((*operation.buffers.get()).0.as_mut_slice()), None, Some(operation.overlapped.get())) },
+            |bytes, (mut buffer, _)| {
+                buffer.truncate(bytes as usize);
+                buffer
+            },
+        )
+    }
+
+    /// Writes `buffer` starting at `offset`, returning the number of bytes written.
+    ///
+    /// The offset is ignored by handles that don't support seeking, such as pipes.
+    #[cfg(feature = "Win32_Storage_FileSystem")]
+    pub fn write(&self, offset: u64, buffer: Vec<u8>) -> IoFuture<'_, usize> {
+        self.start(offset, buffer, Vec::new(), |handle, operation| unsafe { crate::Win32::Storage::FileSystem::WriteFile(handle, Some((*operation.buffers.get()).0.as_slice()), None, Some(operation.overlapped.get())) }, |bytes, _| bytes as usize)
+    }
+
+    /// Sends a control code to a device driver, returning the output buffer truncated to the number of bytes returned.
+    pub fn device_io_control(&self, code: u32, input: Vec<u8>, output_len: usize) -> IoFuture<'_, Vec<u8>> {
+        self.start(
+            0,
+            input,
+            vec![0; output_len],
+            |handle, operation| unsafe {
+                let (input, output) = &mut *operation.buffers.get();
+                DeviceIoControl(handle, code, Some(input.as_ptr() as _), input.len() as u32, Some(output.as_mut_ptr() as _), output.len() as u32, None, Some(operation.overlapped.get()))
+            },
+            |bytes, (_, mut output)| {
+                output.truncate(bytes as usize);
+                output
+            },
+        )
+    }
+
+    /// Waits for a client to connect to a named pipe server handle.
+    ///
+    /// A client that connected before this was called is treated as a successful connection.
+    #[cfg(feature = "Win32_System_Pipes")]
+    pub fn connect_named_pipe(&self) -> IoFuture<'_, ()> {
+        let mut future = self.start(0, Vec::new(), Vec::new(), |handle, operation| unsafe { crate::Win32::System::Pipes::ConnectNamedPipe(handle, Some(operation.overlapped.get())) }, |_, _| ());
+
+        if let Some(Err(error)) = &future.completed {
+            if error.code() == ERROR_PIPE_CONNECTED.to_hresult() {
+                future.completed = Some(Ok(0));
+            }
+        }
+
+        future
+    }
+
+    fn start<T, S: FnOnce(HANDLE, &Operation) -> Result<()>>(&self, offset: u64, first: Vec<u8>, second: Vec<u8>, start: S, finish: fn(u32, (Vec<u8>, Vec<u8>)) -> T) -> IoFuture<'_, T> {
+        let mut overlapped = OVERLAPPED::default();
+        overlapped.Anonymous.Anonymous.Offset = offset as u32;
+        overlapped.Anonymous.Anonymous.OffsetHigh = (offset >> 32) as u32;
+
+        let operation = Arc::new(Operation { overlapped: UnsafeCell::new(overlapped), buffers: UnsafeCell::new((first, second)), state: Mutex::new(State::default()) });
+
+        // The kernel holds a reference to the operation until its completion packet is dequeued.
+        let raw = Arc::into_raw(operation.clone());
+
+        match start(self.handle(), &operation) {
+            Err(error) if error.code() != ERROR_IO_PENDING.to_hresult() => {
+                // No completion packet is queued for operations that fail immediately.
+                unsafe { drop(Arc::from_raw(raw)) };
+                IoFuture { operation, handle: self.handle.borrow(), _port: self.port.clone(), finish, completed: Some(Err(error)), pending: false }
+            }
+            _ => IoFuture { operation, handle: self.handle.borrow(), _port: self.port.clone(), finish, completed: None, pending: true },
+        }
+    }
+}
+
+#[repr(C)]
+struct Operation {
+    overlapped: UnsafeCell<OVERLAPPED>,
+    buffers: UnsafeCell<(Vec<u8>, Vec<u8>)>,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    result: Option<Result<u32>>,
+    waker: Option<Waker>,
+}
+
+unsafe impl Send for Operation {}
+unsafe impl Sync for Operation {}
+
+impl Operation {
+    fn complete(&self, result: Result<u32>) {
+        let mut state = self.state.lock().unwrap();
+        state.result = Some(result);
+
+        if let Some(waker) = state.waker.take() {
+            drop(state);
+            waker.wake();
+        }
+    }
+}
+
+/// A future representing an overlapped I/O operation on an `AsyncHandle`.
+///
+/// The future borrows the handle that started it, so the handle can't be closed while the operation is pending.
+/// Dropping the future before it completes cancels the operation.
+pub struct IoFuture<'a, T> {
+    operation: Arc<Operation>,
+    handle: Borrowed<'a, HANDLE>,
+    // Keeps the port's dispatch thread alive until the operation has completed.
+    _port: CompletionPort,
+    finish: fn(u32, (Vec<u8>, Vec<u8>)) -> T,
+    // Set if the operation completed without queuing a completion packet.
+    completed: Option<Result<u32>>,
+    // Set while the kernel may still be using the operation's buffers.
+    pending: bool,
+}
+
+unsafe impl<T> Send for IoFuture<'_, T> {}
+impl<T> Unpin for IoFuture<'_, T> {}
+
+impl<T> Future for IoFuture<'_, T> {
+    type Output = Result<T>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Some(result) = self.completed.take() {
+            return Poll::Ready(result.map(|bytes| (self.finish)(bytes, Default::default())));
+        }
+
+        let mut state = self.operation.state.lock().unwrap();
+
+        match state.result.take() {
+            Some(result) => {
+                drop(state);
+                // The kernel is done with the buffers once the completion packet has been dequeued.
+                let buffers = unsafe { core::mem::take(&mut *self.operation.buffers.get()) };
+                self.pending = false;
+                Poll::Ready(result.map(|bytes| (self.finish)(bytes, buffers)))
+            }
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> Drop for IoFuture<'_, T> {
+    fn drop(&mut self) {
+        if self.pending && self.operation.state.lock().unwrap().result.is_none() {
+            // Waits for the operation to finish, whether cancelled or not, so the kernel no longer writes to the
+            // `OVERLAPPED` or the buffers. They stay alive until the completion packet is dequeued regardless.
+            unsafe {
+                if CancelIoEx(*self.handle, Some(self.operation.overlapped.get())).is_ok() {
+                    let mut bytes = 0;
+                    _ = GetOverlappedResult(*self.handle, self.operation.overlapped.get(), &mut bytes, true);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,299 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::JobObjects::*;
+use core::time::Duration;
+use windows_core::{Owned, Result, HSTRING};
+
+// winnt.h defines this as a combination of standard and job-specific access rights, so the metadata omits it.
+const JOB_OBJECT_ALL_ACCESS: u32 = 0x1F003F;
+
+/// A job object, which manages a group of processes as a unit.
+///
+/// Processes added to the job with `assign_process`, along with any processes they create, are subject to the
+/// job's limits:
+///
+/// ```rust,ignore
+/// let job = Job::new()?;
+/// job.set_limits(&JobLimits::new().kill_on_close().process_memory(512 * 1024 * 1024))?;
+/// job.assign_process(child)?;
+/// ```
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct Job(Owned<HANDLE>);
+
+unsafe impl Send for Job {}
+unsafe impl Sync for Job {}
+
+impl Job {
+    /// Creates an unnamed job object.
+    pub fn new() -> Result<Self> {
+        unsafe { Ok(Self(Owned::new(CreateJobObjectW(None, None)?))) }
+    }
+
+    /// Creates a named job object, or opens the job object if one with that name already exists.
+    pub fn create<T: Into<HSTRING>>(name: T) -> Result<Self> {
+        unsafe { Ok(Self(Owned::new(CreateJobObjectW(None, &name.into())?))) }
+    }
+
+    /// Opens an existing named job object with full access.
+    pub fn open<T: Into<HSTRING>>(name: T) -> Result<Self> {
+        unsafe { Ok(Self(Owned::new(OpenJobObjectW(JOB_OBJECT_ALL_ACCESS, false, &name.into())?))) }
+    }
+
+    /// Returns the underlying handle.
+    pub fn handle(&self) -> HANDLE {
+        *self.0
+    }
+
+    /// Adds a process to the job, which requires the `PROCESS_SET_QUOTA` and `PROCESS_TERMINATE` access rights.
+    ///
+    /// Processes that are created suspended may be assigned before they run any code, so they cannot escape the job.
+    pub fn assign_process(&self, process: HANDLE) -> Result<()> {
+        unsafe { AssignProcessToJobObject(*self.0, process) }
+    }
+
+    /// Returns `true` if the process belongs to the job.
+    pub fn contains_process(&self, process: HANDLE) -> Result<bool> {
+        let mut result = BOOL::default();
+        unsafe { IsProcessInJob(process, *self.0, &mut result)? };
+        Ok(result.as_bool())
+    }
+
+    /// Returns the identifiers of the processes that belong to the job.
+    pub fn process_ids(&self) -> Result<Vec<usize>> {
+        let header = core::mem::size_of::<JOBOBJECT_BASIC_PROCESS_ID_LIST>() - core::mem::size_of::<usize>();
+        let mut capacity = 16;
+
+        // The list may grow between calls, so the buffer is enlarged until every process fits.
+        loop {
+            let mut buffer = vec![0usize; header / core::mem::size_of::<usize>() + capacity];
+            let result = unsafe { QueryInformationJobObject(*self.0, JobObjectBasicProcessIdList, buffer.as_mut_ptr() as _, (buffer.len() * core::mem::size_of::<usize>()) as u32, None) };
+
+            match result {
+                Err(error) if error.code() != ERROR_MORE_DATA.to_hresult() => return Err(error),
+                _ => {}
+            }
+
+            let list = unsafe { &*(buffer.as_ptr() as *const JOBOBJECT_BASIC_PROCESS_ID_LIST) };
+
+            if list.NumberOfAssignedProcesses as usize <= capacity {
+                let ids = unsafe { core::slice::from_raw_parts(list.ProcessIdList.as_ptr(), list.NumberOfProcessIdsInList as usize) };
+                return Ok(ids.to_vec());
+            }
+
+            capacity = list.NumberOfAssignedProcesses as usize + 16;
+        }
+    }
+
+    /// Terminates every process in the job with the given exit code.
+    pub fn terminate(&self, exit_code: u32) -> Result<()> {
+        unsafe { TerminateJobObject(*self.0, exit_code) }
+    }
+
+    /// Replaces the job's limits.
+    pub fn set_limits(&self, limits: &JobLimits) -> Result<()> {
+        self.set(JobObjectExtendedLimitInformation, &limits.0)
+    }
+
+    /// Prevents processes in the job from using the given user interface features, such as
+    /// `JOB_OBJECT_UILIMIT_READCLIPBOARD | JOB_OBJECT_UILIMIT_WRITECLIPBOARD`.
+    pub fn set_ui_restrictions(&self, restrictions: JOB_OBJECT_UILIMIT) -> Result<()> {
+        self.set(JobObjectBasicUIRestrictions, &JOBOBJECT_BASIC_UI_RESTRICTIONS { UIRestrictionsClass: restrictions })
+    }
+
+    /// Limits the processor time of the job to the given rate, in hundredths of a percent from 1 to 10,000.
+    ///
+    /// The limit is a hard cap across all processors, so a rate of 2,500 allows the job a quarter of the machine.
+    /// A rate of `None` removes the limit.
+    pub fn set_cpu_rate(&self, rate: Option<u32>) -> Result<()> {
+        let mut info = JOBOBJECT_CPU_RATE_CONTROL_INFORMATION::default();
+
+        if let Some(rate) = rate {
+            info.ControlFlags = JOB_OBJECT_CPU_RATE_CONTROL_ENABLE | JOB_OBJECT_CPU_RATE_CONTROL_HARD_CAP;
+            info.Anonymous.CpuRate = rate;
+        }
+
+        self.set(JobObjectCpuRateControlInformation, &info)
+    }
+
+    /// Creates a queue of notifications about the processes in the job and its limits.
+    ///
+    /// A job may have only one notification queue, and notifications raised before it was created are not reported.
+    #[cfg(all(feature = "Win32_System_IO", feature = "Win32_System_SystemServices"))]
+    pub fn notifications(&self) -> Result<JobNotifications> {
+        use crate::Win32::System::IO::CreateIoCompletionPort;
+
+        unsafe {
+            let port = Owned::new(CreateIoCompletionPort(INVALID_HANDLE_VALUE, None, 0, 1)?);
+            self.set(JobObjectAssociateCompletionPortInformation, &JOBOBJECT_ASSOCIATE_COMPLETION_PORT { CompletionKey: core::ptr::null_mut(), CompletionPort: *port })?;
+            Ok(JobNotifications(port))
+        }
+    }
+
+    fn set<T>(&self, class: JOBOBJECTINFOCLASS, info: &T) -> Result<()> {
+        unsafe { SetInformationJobObject(*self.0, class, info as *const T as _, core::mem::size_of::<T>() as u32) }
+    }
+}
+
+/// The limits of a job, applied with `Job::set_limits`.
+///
+/// Each limit is off unless enabled.
+#[derive(Clone, Copy, Default)]
+pub struct JobLimits(JOBOBJECT_EXTENDED_LIMIT_INFORMATION);
+
+impl JobLimits {
+    /// Creates a set of limits with every limit off.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn flag(mut self, flag: JOB_OBJECT_LIMIT) -> Self {
+        self.0.BasicLimitInformation.LimitFlags |= flag;
+        self
+    }
+
+    /// Terminates every process in the job when the last handle to the job is closed.
+    pub fn kill_on_close(self) -> Self {
+        self.flag(JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE)
+    }
+
+    /// Terminates processes in the job that have an unhandled exception rather than showing an error dialog.
+    pub fn die_on_unhandled_exception(self) -> Self {
+        self.flag(JOB_OBJECT_LIMIT_DIE_ON_UNHANDLED_EXCEPTION)
+    }
+
+    /// Allows processes in the job to create processes outside the job with `CREATE_BREAKAWAY_FROM_JOB`.
+    pub fn breakaway_ok(self) -> Self {
+        self.flag(JOB_OBJECT_LIMIT_BREAKAWAY_OK)
+    }
+
+    /// Creates processes outside the job by default.
+    pub fn silent_breakaway_ok(self) -> Self {
+        self.flag(JOB_OBJECT_LIMIT_SILENT_BREAKAWAY_OK)
+    }
+
+    /// Limits the number of processes that may be active in the job at the same time.
+    pub fn active_processes(mut self, count: u32) -> Self {
+        self.0.BasicLimitInformation.ActiveProcessLimit = count;
+        self.flag(JOB_OBJECT_LIMIT_ACTIVE_PROCESS)
+    }
+
+    /// Limits the memory that each process in the job may commit, in bytes.
+    pub fn process_memory(mut self, bytes: usize) -> Self {
+        self.0.ProcessMemoryLimit = bytes;
+        self.flag(JOB_OBJECT_LIMIT_PROCESS_MEMORY)
+    }
+
+    /// Limits the memory that all processes in the job may commit together, in bytes.
+    pub fn job_memory(mut self, bytes: usize) -> Self {
+        self.0.JobMemoryLimit = bytes;
+        self.flag(JOB_OBJECT_LIMIT_JOB_MEMORY)
+    }
+
+    /// Limits the user-mode processor time of each process in the job, terminating processes that exceed it.
+    pub fn process_time(mut self, time: Duration) -> Self {
+        self.0.BasicLimitInformation.PerProcessUserTimeLimit = ticks(time);
+        self.flag(JOB_OBJECT_LIMIT_PROCESS_TIME)
+    }
+
+    /// Limits the user-mode processor time of all processes in the job together, terminating the job's processes
+    /// once it is exceeded.
+    pub fn job_time(mut self, time: Duration) -> Self {
+        self.0.BasicLimitInformation.PerJobUserTimeLimit = ticks(time);
+        self.flag(JOB_OBJECT_LIMIT_JOB_TIME)
+    }
+
+    /// Sets the processor affinity of every process in the job.
+    pub fn affinity(mut self, mask: usize) -> Self {
+        self.0.BasicLimitInformation.Affinity = mask;
+        self.flag(JOB_OBJECT_LIMIT_AFFINITY)
+    }
+}
+
+#[cfg(not(windows_no_debug))]
+impl core::fmt::Debug for JobLimits {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("JobLimits").field("flags", &self.0.BasicLimitInformation.LimitFlags).finish_non_exhaustive()
+    }
+}
+
+// Processor time limits are measured in 100-nanosecond intervals.
+fn ticks(time: Duration) -> i64 {
+    (time.as_nanos() / 100).min(i64::MAX as u128) as i64
+}
+
+/// A queue of notifications for a job, created with `Job::notifications`.
+#[cfg(all(feature = "Win32_System_IO", feature = "Win32_System_SystemServices"))]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct JobNotifications(Owned<HANDLE>);
+
+#[cfg(all(feature = "Win32_System_IO", feature = "Win32_System_SystemServices"))]
+unsafe impl Send for JobNotifications {}
+#[cfg(all(feature = "Win32_System_IO", feature = "Win32_System_SystemServices"))]
+unsafe impl Sync for JobNotifications {}
+
+#[cfg(all(feature = "Win32_System_IO", feature = "Win32_System_SystemServices"))]
+impl JobNotifications {
+    /// Waits for the next notification, returning `None` if no notification arrives before the timeout.
+    ///
+    /// A timeout of `None` waits indefinitely.
+    pub fn next(&self, timeout: Option<Duration>) -> Result<Option<JobNotification>> {
+        use crate::Win32::System::IO::{GetQueuedCompletionStatus, OVERLAPPED};
+
+        let mut message = 0;
+        let mut key = 0;
+        let mut overlapped: *mut OVERLAPPED = core::ptr::null_mut();
+        let timeout = timeout.map_or(u32::MAX, |timeout| timeout.as_millis().min(u32::MAX as u128 - 1) as u32);
+
+        match unsafe { GetQueuedCompletionStatus(*self.0, &mut message, &mut key, &mut overlapped, timeout) } {
+            Ok(()) => Ok(Some(JobNotification::from_message(message, overlapped as usize))),
+            Err(error) if error.code() == windows_core::HRESULT::from_win32(WAIT_TIMEOUT.0) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// A notification about a job, returned by `JobNotifications::next`.
+///
+/// Notifications about a process include its identifier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JobNotification {
+    /// The job's processor time limit was exceeded.
+    EndOfJobTime,
+    /// A process's processor time limit was exceeded.
+    EndOfProcessTime(usize),
+    /// The active process limit was exceeded.
+    ActiveProcessLimit,
+    /// The last active process in the job exited.
+    ActiveProcessZero,
+    /// A process was added to the job.
+    NewProcess(usize),
+    /// A process in the job exited.
+    ExitProcess(usize),
+    /// A process in the job exited abnormally, such as with an unhandled exception.
+    AbnormalExitProcess(usize),
+    /// A process exceeded its memory limit.
+    ProcessMemoryLimit(usize),
+    /// A process caused the job to exceed its memory limit.
+    JobMemoryLimit(usize),
+    /// Any other notification, with its message identifier and value.
+    Other(u32, usize),
+}
+
+impl JobNotification {
+    #[cfg(all(feature = "Win32_System_IO", feature = "Win32_System_SystemServices"))]
+    fn from_message(message: u32, value: usize) -> Self {
+        use crate::Win32::System::SystemServices::*;
+
+        match message {
+            JOB_OBJECT_MSG_END_OF_JOB_TIME => Self::EndOfJobTime,
+            JOB_OBJECT_MSG_END_OF_PROCESS_TIME => Self::EndOfProcessTime(value),
+            JOB_OBJECT_MSG_ACTIVE_PROCESS_LIMIT => Self::ActiveProcessLimit,
+            JOB_OBJECT_MSG_ACTIVE_PROCESS_ZERO => Self::ActiveProcessZero,
+            JOB_OBJECT_MSG_NEW_PROCESS => Self::NewProcess(value),
+            JOB_OBJECT_MSG_EXIT_PROCESS => Self::ExitProcess(value),
+            JOB_OBJECT_MSG_ABNORMAL_EXIT_PROCESS => Self::AbnormalExitProcess(value),
+            JOB_OBJECT_MSG_PROCESS_MEMORY_LIMIT => Self::ProcessMemoryLimit(value),
+            JOB_OBJECT_MSG_JOB_MEMORY_LIMIT => Self::JobMemoryLimit(value),
+            _ => Self::Other(message, value),
+        }
+    }
+}
@@ -0,0 +1,277 @@
+pub mod VirtualMemory;
+
+#[cfg(feature = "Win32_Security")]
+use crate::Win32::Foundation::*;
+#[cfg(feature = "Win32_Security")]
+use crate::Win32::System::Memory::*;
+#[cfg(feature = "Win32_Security")]
+use core::marker::PhantomData;
+#[cfg(feature = "Win32_Security")]
+use windows_core::{Owned, Result, HSTRING};
+
+/// A file mapping object, which is a section of memory that may be shared between processes or backed by a file.
+///
+/// Views of the mapping are created with `map` or `map_read` and unmapped when dropped:
+///
+/// ```rust,ignore
+/// #[repr(C)]
+/// struct Shared {
+///     counter: AtomicU32,
+/// }
+///
+/// let mapping = FileMapping::create("Local\\example", size_of::<Shared>() as u64)?;
+/// let view = mapping.map(0, size_of::<Shared>())?.cast::<Shared>()?;
+/// unsafe { view.get() }.counter.fetch_add(1, Ordering::SeqCst);
+/// ```
+///
+/// The mapping's handle is closed when it is dropped, while existing views remain valid until they are dropped.
+#[cfg(feature = "Win32_Security")]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct FileMapping(Owned<HANDLE>);
+
+#[cfg(feature = "Win32_Security")]
+unsafe impl Send for FileMapping {}
+#[cfg(feature = "Win32_Security")]
+unsafe impl Sync for FileMapping {}
+
+#[cfg(feature = "Win32_Security")]
+impl FileMapping {
+    /// Creates an unnamed read-write mapping of the given size backed by the system paging file.
+    pub fn new(size: u64) -> Result<Self> {
+        Self::create_mapping(INVALID_HANDLE_VALUE, PAGE_READWRITE, size, None)
+    }
+
+    /// Creates a named read-write mapping of the given size backed by the system paging file, or opens the
+    /// mapping if one with that name already exists.
+    ///
+    /// Names may be prefixed with `Global\` or `Local\` to choose the session namespace. An existing mapping
+    /// retains its original size.
+    pub fn create<T: Into<HSTRING>>(name: T, size: u64) -> Result<Self> {
+        Self::create_mapping(INVALID_HANDLE_VALUE, PAGE_READWRITE, size, Some(&name.into()))
+    }
+
+    /// Opens an existing named mapping, for reading and writing if `writable` is `true`.
+    pub fn open<T: Into<HSTRING>>(name: T, writable: bool) -> Result<Self> {
+        let access = if writable { FILE_MAP_READ | FILE_MAP_WRITE } else { FILE_MAP_READ };
+        unsafe { Ok(Self(Owned::new(OpenFileMappingW(access.0, false, &name.into())?))) }
+    }
+
+    /// Creates an unnamed mapping of an open file, for reading and writing if `writable` is `true`.
+    ///
+    /// A size of zero maps the current size of the file, which must not be empty. A larger size extends the file.
+    ///
+    /// # Safety
+    ///
+    /// The file must have been opened with access matching `writable` and remain open while the mapping is
+    /// created. Views remain valid after the file is closed.
+    pub unsafe fn from_file(file: HANDLE, size: u64, writable: bool) -> Result<Self> {
+        Self::create_mapping(file, if writable { PAGE_READWRITE } else { PAGE_READONLY }, size, None)
+    }
+
+    fn create_mapping(file: HANDLE, protect: PAGE_PROTECTION_FLAGS, size: u64, name: Option<&HSTRING>) -> Result<Self> {
+        unsafe {
+            let name = name.map_or(windows_core::PCWSTR::null(), |name| windows_core::PCWSTR(name.as_ptr()));
+            Ok(Self(Owned::new(CreateFileMappingW(file, None, protect, (size >> 32) as u32, size as u32, name)?)))
+        }
+    }
+
+    /// Returns the underlying handle.
+    pub fn handle(&self) -> HANDLE {
+        *self.0
+    }
+
+    /// Maps a read-write view of `len` bytes starting at `offset`.
+    ///
+    /// The offset must be a multiple of the system's allocation granularity, which is typically 64 KiB. A length of
+    /// zero maps from the offset to the end of the mapping.
+    pub fn map(&self, offset: u64, len: usize) -> Result<MappedView> {
+        self.map_view(FILE_MAP_READ | FILE_MAP_WRITE, offset, len)
+    }
+
+    /// Maps a read-only view of `len` bytes starting at `offset`.
+    ///
+    /// The offset and length are interpreted as they are by `map`.
+    pub fn map_read(&self, offset: u64, len: usize) -> Result<MappedView> {
+        self.map_view(FILE_MAP_READ, offset, len)
+    }
+
+    fn map_view(&self, access: FILE_MAP, offset: u64, len: usize) -> Result<MappedView> {
+        unsafe {
+            let address = MapViewOfFile(*self.0, access, (offset >> 32) as u32, offset as u32, len);
+
+            if address.Value.is_null() {
+                return Err(windows_core::Error::from_win32());
+            }
+
+            let view = MappedView { address, len, writable: access.0 & FILE_MAP_WRITE.0 != 0 };
+
+            if len != 0 {
+                return Ok(view);
+            }
+
+            // The view is rounded up to a whole number of pages, which is the most that can be known about its length.
+            let mut info = MEMORY_BASIC_INFORMATION::default();
+
+            if VirtualQuery(Some(address.Value), &mut info, core::mem::size_of::<MEMORY_BASIC_INFORMATION>()) == 0 {
+                return Err(windows_core::Error::from_win32());
+            }
+
+            Ok(MappedView { len: info.RegionSize, ..view })
+        }
+    }
+}
+
+/// A view of a `FileMapping`, which is unmapped when dropped.
+///
+/// The memory may be changed by other processes at any time, so the view does not hand out references to its
+/// contents without the caller's assurance that this is safe.
+#[cfg(feature = "Win32_Security")]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct MappedView {
+    address: MEMORY_MAPPED_VIEW_ADDRESS,
+    len: usize,
+    writable: bool,
+}
+
+#[cfg(feature = "Win32_Security")]
+unsafe impl Send for MappedView {}
+#[cfg(feature = "Win32_Security")]
+unsafe impl Sync for MappedView {}
+
+#[cfg(feature = "Win32_Security")]
+impl MappedView {
+    /// Returns a pointer to the start of the view.
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.address.Value as _
+    }
+
+    /// Returns the length of the view in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view has a length of zero.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the view may be written to.
+    pub fn is_writable(&self) -> bool {
+        self.writable
+    }
+
+    /// Returns the contents of the view.
+    ///
+    /// # Safety
+    ///
+    /// The contents must not be changed, by this or any other process, while the slice is alive.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        core::slice::from_raw_parts(self.as_ptr(), self.len)
+    }
+
+    /// Returns the contents of a writable view.
+    ///
+    /// # Safety
+    ///
+    /// The view must be writable and its contents must not be accessed, by this or any other process, while the
+    /// slice is alive.
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+        debug_assert!(self.writable);
+        core::slice::from_raw_parts_mut(self.as_ptr(), self.len)
+    }
+
+    /// Writes any changes to the view back to the file that backs the mapping.
+    pub fn flush(&self) -> Result<()> {
+        unsafe { FlushViewOfFile(self.address.Value, self.len) }
+    }
+
+    /// Views the start of the mapping as a value of type `T`.
+    ///
+    /// Fails with `E_INVALIDARG` if the view is too small or not sufficiently aligned for `T`.
+    pub fn cast<T>(self) -> Result<TypedView<T>> {
+        if self.len < core::mem::size_of::<T>() || self.address.Value as usize % core::mem::align_of::<T>() != 0 {
+            return Err(windows_core::Error::from(windows_core::imp::E_INVALIDARG));
+        }
+
+        Ok(TypedView { view: self, _type: PhantomData })
+    }
+}
+
+#[cfg(feature = "Win32_Security")]
+impl Drop for MappedView {
+    fn drop(&mut self) {
+        unsafe {
+            _ = UnmapViewOfFile(self.address);
+        }
+    }
+}
+
+/// A view of a `FileMapping` whose contents are a value of type `T`, typically a `#[repr(C)]` struct.
+///
+/// Values are read and written with volatile operations, since other processes may use them concurrently. Types
+/// built from atomics may instead be shared by reference with `get`.
+#[cfg(feature = "Win32_Security")]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct TypedView<T> {
+    view: MappedView,
+    _type: PhantomData<T>,
+}
+
+#[cfg(feature = "Win32_Security")]
+impl<T> TypedView<T> {
+    /// Returns a pointer to the value.
+    pub fn as_ptr(&self) -> *mut T {
+        self.view.as_ptr() as _
+    }
+
+    /// Returns the underlying view.
+    pub fn into_view(self) -> MappedView {
+        self.view
+    }
+
+    /// Returns a reference to the value.
+    ///
+    /// # Safety
+    ///
+    /// The bytes of the view must be a valid `T` and any concurrent changes, by this or any other process, must be
+    /// made through the interior mutability of `T`, such as atomics.
+    pub unsafe fn get(&self) -> &T {
+        &*self.as_ptr()
+    }
+
+    /// Returns a mutable reference to the value.
+    ///
+    /// # Safety
+    ///
+    /// The view must be writable, its bytes must be a valid `T`, and the value must not be accessed, by this or any
+    /// other process, while the reference is alive.
+    pub unsafe fn get_mut(&mut self) -> &mut T {
+        debug_assert!(self.view.writable);
+        &mut *self.as_ptr()
+    }
+}
+
+#[cfg(feature = "Win32_Security")]
+impl<T: Copy> TypedView<T> {
+    /// Reads a copy of the value.
+    ///
+    /// # Safety
+    ///
+    /// The bytes of the view must be a valid `T`. As the read is not atomic, the caller must synchronize with any
+    /// other process writing the value.
+    pub unsafe fn read(&self) -> T {
+        self.as_ptr().read_volatile()
+    }
+
+    /// Writes the value, failing with `E_ACCESSDENIED` if the view is read-only.
+    ///
+    /// As the write is not atomic, the caller must synchronize with any other process reading or writing the value.
+    pub fn write(&self, value: T) -> Result<()> {
+        if !self.view.writable {
+            return Err(windows_core::Error::from(E_ACCESSDENIED));
+        }
+
+        unsafe { self.as_ptr().write_volatile(value) };
+        Ok(())
+    }
+}
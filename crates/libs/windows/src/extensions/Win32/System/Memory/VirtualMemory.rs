@@ -0,0 +1,220 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::Memory::*;
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use windows_core::{Error, Result};
+
+/// A region of virtual memory allocated with `VirtualAlloc`, which is released when dropped.
+///
+/// A region may be reserved up front and committed piece by piece, as a JIT or arena allocator does:
+///
+/// ```rust,ignore
+/// let mut region = VirtualRegion::reserve(1 << 30)?;
+/// region.commit(0, 4096, PAGE_READWRITE)?;
+/// unsafe { region.as_mut_slice()[..CODE.len()].copy_from_slice(CODE) };
+///
+/// // Make the code executable and no longer writable.
+/// region.protect(0, 4096, PAGE_EXECUTE_READ)?;
+/// ```
+#[derive(Debug)]
+pub struct VirtualRegion {
+    address: *mut c_void,
+    len: usize,
+}
+
+unsafe impl Send for VirtualRegion {}
+unsafe impl Sync for VirtualRegion {}
+
+impl VirtualRegion {
+    /// Reserves `len` bytes of address space without committing any memory to it.
+    ///
+    /// Pages must be committed with `commit` before they are accessed.
+    pub fn reserve(len: usize) -> Result<Self> {
+        Self::alloc(len, MEM_RESERVE, PAGE_NOACCESS)
+    }
+
+    /// Reserves and commits `len` bytes of zeroed memory with the given protection, such as `PAGE_READWRITE`.
+    pub fn new(len: usize, protect: PAGE_PROTECTION_FLAGS) -> Result<Self> {
+        Self::alloc(len, MEM_RESERVE | MEM_COMMIT, protect)
+    }
+
+    /// Reserves and commits memory backed by large pages, which reduces TLB misses for large working sets.
+    ///
+    /// The length is rounded up to a multiple of `large_page_size`. Large pages are never paged out, and
+    /// allocating them requires the `SeLockMemoryPrivilege` privilege to be held and enabled, for example with
+    /// `Token::enable_privilege(SE_LOCK_MEMORY_NAME)`. Fails with `ERROR_NOT_SUPPORTED` if the system doesn't
+    /// support large pages.
+    pub fn large_pages(len: usize, protect: PAGE_PROTECTION_FLAGS) -> Result<Self> {
+        let minimum = large_page_size();
+
+        if minimum == 0 {
+            return Err(ERROR_NOT_SUPPORTED.into());
+        }
+
+        let len = len.checked_add(minimum - 1).ok_or_else(|| Error::from(E_INVALIDARG))? & !(minimum - 1);
+        Self::alloc(len, MEM_RESERVE | MEM_COMMIT | MEM_LARGE_PAGES, protect)
+    }
+
+    fn alloc(len: usize, kind: VIRTUAL_ALLOCATION_TYPE, protect: PAGE_PROTECTION_FLAGS) -> Result<Self> {
+        let address = unsafe { VirtualAlloc(None, len, kind, protect) };
+
+        if address.is_null() {
+            Err(Error::from_win32())
+        } else {
+            Ok(Self { address, len })
+        }
+    }
+
+    /// Returns a pointer to the start of the region.
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.address as _
+    }
+
+    /// Returns the length of the region in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the region has a length of zero.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the contents of the region.
+    ///
+    /// # Safety
+    ///
+    /// The whole region must be committed and readable, and must not be changed while the slice is alive.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        core::slice::from_raw_parts(self.as_ptr(), self.len)
+    }
+
+    /// Returns the contents of the region.
+    ///
+    /// # Safety
+    ///
+    /// The whole region must be committed and writable, and must not be accessed in any other way while the
+    /// slice is alive.
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [u8] {
+        core::slice::from_raw_parts_mut(self.as_ptr(), self.len)
+    }
+
+    /// Commits the pages spanning `len` bytes at `offset`, which are zeroed when first committed.
+    ///
+    /// Pages that are already committed keep their contents but are given the new protection.
+    pub fn commit(&self, offset: usize, len: usize, protect: PAGE_PROTECTION_FLAGS) -> Result<()> {
+        let address = self.range(offset, len)?;
+
+        if unsafe { VirtualAlloc(Some(address), len, MEM_COMMIT, protect) }.is_null() {
+            Err(Error::from_win32())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Decommits the pages spanning `len` bytes at `offset`, returning their memory to the system while keeping
+    /// the address space reserved.
+    ///
+    /// # Safety
+    ///
+    /// The pages must not be accessed until they are committed again.
+    pub unsafe fn decommit(&self, offset: usize, len: usize) -> Result<()> {
+        VirtualFree(self.range(offset, len)?, len, MEM_DECOMMIT)
+    }
+
+    /// Changes the protection of the committed pages spanning `len` bytes at `offset`, returning the previous
+    /// protection of the first page.
+    pub fn protect(&self, offset: usize, len: usize, protect: PAGE_PROTECTION_FLAGS) -> Result<PAGE_PROTECTION_FLAGS> {
+        let mut previous = PAGE_PROTECTION_FLAGS::default();
+        unsafe { VirtualProtect(self.range(offset, len)?, len, protect, &mut previous)? };
+        Ok(previous)
+    }
+
+    /// Changes the protection of the committed pages spanning `len` bytes at `offset` until the returned guard is
+    /// dropped, which restores the previous protection.
+    pub fn protect_scoped(&self, offset: usize, len: usize, protect: PAGE_PROTECTION_FLAGS) -> Result<ProtectionGuard<'_>> {
+        unsafe { ProtectionGuard::new(self.range(offset, len)?, len, protect) }
+    }
+
+    /// Returns information about the run of pages with the same state and protection that starts at the page
+    /// containing `offset`.
+    pub fn query(&self, offset: usize) -> Result<MEMORY_BASIC_INFORMATION> {
+        let address = self.range(offset, 0)?;
+        let mut info = MEMORY_BASIC_INFORMATION::default();
+
+        if unsafe { VirtualQuery(Some(address), &mut info, core::mem::size_of::<MEMORY_BASIC_INFORMATION>()) } == 0 {
+            Err(Error::from_win32())
+        } else {
+            Ok(info)
+        }
+    }
+
+    fn range(&self, offset: usize, len: usize) -> Result<*mut c_void> {
+        match offset.checked_add(len) {
+            Some(end) if end <= self.len => Ok(unsafe { self.as_ptr().add(offset) } as _),
+            _ => Err(E_INVALIDARG.into()),
+        }
+    }
+}
+
+impl Drop for VirtualRegion {
+    fn drop(&mut self) {
+        unsafe {
+            _ = VirtualFree(self.address, 0, MEM_RELEASE);
+        }
+    }
+}
+
+/// Restores the previous protection of a range of pages when dropped.
+///
+/// This is produced by `VirtualRegion::protect_scoped`, or by `ProtectionGuard::new` for memory that isn't owned
+/// by a `VirtualRegion`, such as when patching the code of a loaded module:
+///
+/// ```rust,ignore
+/// let _writable = unsafe { ProtectionGuard::new(address, len, PAGE_EXECUTE_READWRITE)? };
+/// unsafe { core::ptr::copy_nonoverlapping(patch.as_ptr(), address as *mut u8, len) };
+/// ```
+///
+/// The previous protection of the first page is restored to the whole range, so the range should not span pages
+/// with different protections.
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct ProtectionGuard<'a> {
+    address: *const c_void,
+    len: usize,
+    previous: PAGE_PROTECTION_FLAGS,
+    _region: PhantomData<&'a VirtualRegion>,
+}
+
+impl ProtectionGuard<'_> {
+    /// Changes the protection of the committed pages spanning `len` bytes at `address`.
+    ///
+    /// # Safety
+    ///
+    /// The pages must remain allocated until the guard is dropped, and any code relying on their current
+    /// protection must not run until it is restored.
+    pub unsafe fn new(address: *const c_void, len: usize, protect: PAGE_PROTECTION_FLAGS) -> Result<Self> {
+        let mut previous = PAGE_PROTECTION_FLAGS::default();
+        VirtualProtect(address, len, protect, &mut previous)?;
+        Ok(Self { address, len, previous, _region: PhantomData })
+    }
+
+    /// Returns the protection that is restored when the guard is dropped.
+    pub fn previous(&self) -> PAGE_PROTECTION_FLAGS {
+        self.previous
+    }
+}
+
+impl Drop for ProtectionGuard<'_> {
+    fn drop(&mut self) {
+        let mut protect = PAGE_PROTECTION_FLAGS::default();
+
+        unsafe {
+            _ = VirtualProtect(self.address, self.len, self.previous, &mut protect);
+        }
+    }
+}
+
+/// Returns the size of a large page, or zero if the system doesn't support large pages.
+pub fn large_page_size() -> usize {
+    unsafe { GetLargePageMinimum() }
+}
@@ -0,0 +1,318 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::Com::*;
+use crate::Win32::System::Ole::*;
+use crate::Win32::System::Variant::*;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use windows_core::{Result, BSTR, VARIANT};
+
+/// A type that may be stored in a `SafeArray`.
+///
+/// # Safety
+///
+/// The type must have the same layout as the elements of a `SAFEARRAY` of the `VT` type, and dropping it
+/// must release the element in the same way as `SafeArrayDestroy`.
+pub unsafe trait SafeArrayElement: Sized {
+    /// The type of the elements, such as `VT_I4`.
+    const VT: VARENUM;
+}
+
+macro_rules! element {
+    ($ty:ty, $vt:ident) => {
+        unsafe impl SafeArrayElement for $ty {
+            const VT: VARENUM = $vt;
+        }
+    };
+}
+
+element!(i8, VT_I1);
+element!(u8, VT_UI1);
+element!(i16, VT_I2);
+element!(u16, VT_UI2);
+element!(i32, VT_I4);
+element!(u32, VT_UI4);
+element!(i64, VT_I8);
+element!(u64, VT_UI8);
+element!(f32, VT_R4);
+element!(f64, VT_R8);
+element!(VARIANT_BOOL, VT_BOOL);
+element!(BSTR, VT_BSTR);
+element!(VARIANT, VT_VARIANT);
+element!(Option<windows_core::IUnknown>, VT_UNKNOWN);
+element!(Option<IDispatch>, VT_DISPATCH);
+
+/// An owned `SAFEARRAY` of elements of type `T`, which is destroyed along with its elements when dropped.
+///
+/// ```rust,ignore
+/// let array = SafeArray::from_slice(&[BSTR::from("one"), BSTR::from("two")])?;
+/// let variant = VARIANT::from(array);
+///
+/// let array = SafeArray::<BSTR>::try_from(&variant)?;
+/// assert_eq!(array.to_vec()?, ["one", "two"]);
+/// ```
+pub struct SafeArray<T: SafeArrayElement> {
+    array: *mut SAFEARRAY,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: SafeArrayElement + Send> Send for SafeArray<T> {}
+unsafe impl<T: SafeArrayElement + Sync> Sync for SafeArray<T> {}
+
+impl<T: SafeArrayElement> SafeArray<T> {
+    /// Creates a one-dimensional array of `len` default elements, indexed from zero.
+    pub fn new(len: usize) -> Result<Self> {
+        let array = unsafe { SafeArrayCreateVector(T::VT, 0, len as u32) };
+        Self::created(array)
+    }
+
+    /// Creates an array with the given bounds for each dimension, from left to right.
+    pub fn with_bounds(bounds: &[SAFEARRAYBOUND]) -> Result<Self> {
+        if bounds.is_empty() {
+            return Err(windows_core::Error::from(E_INVALIDARG));
+        }
+
+        let array = unsafe { SafeArrayCreate(T::VT, bounds.len() as u32, bounds.as_ptr()) };
+        Self::created(array)
+    }
+
+    /// Creates a one-dimensional array that holds copies of the values.
+    pub fn from_slice(values: &[T]) -> Result<Self>
+    where
+        T: Clone,
+    {
+        let mut array = Self::new(values.len())?;
+        array.lock_mut()?.clone_from_slice(values);
+        Ok(array)
+    }
+
+    /// Takes ownership of a `SAFEARRAY`, such as one returned by a COM method.
+    ///
+    /// # Safety
+    ///
+    /// The array must be valid and owned by the caller.
+    pub unsafe fn from_raw(array: *mut SAFEARRAY) -> Result<Self> {
+        if array.is_null() {
+            return Err(windows_core::Error::from(E_POINTER));
+        }
+
+        if SafeArrayGetVartype(array)? != T::VT {
+            return Err(windows_core::Error::from(DISP_E_TYPEMISMATCH));
+        }
+
+        Ok(Self { array, _marker: PhantomData })
+    }
+
+    /// Returns the underlying `SAFEARRAY`, which remains owned by the `SafeArray`.
+    pub fn as_raw(&self) -> *mut SAFEARRAY {
+        self.array
+    }
+
+    /// Releases ownership of the underlying `SAFEARRAY`, which must then be destroyed by the caller.
+    pub fn into_raw(self) -> *mut SAFEARRAY {
+        let array = self.array;
+        core::mem::forget(self);
+        array
+    }
+
+    /// Returns the number of dimensions.
+    pub fn dims(&self) -> u32 {
+        unsafe { SafeArrayGetDim(self.array) }
+    }
+
+    /// Returns the lower and upper bounds, inclusive, of a dimension, starting with `1` for the leftmost dimension.
+    pub fn bounds(&self, dim: u32) -> Result<(i32, i32)> {
+        unsafe { Ok((SafeArrayGetLBound(self.array, dim)?, SafeArrayGetUBound(self.array, dim)?)) }
+    }
+
+    /// Returns the total number of elements across all dimensions.
+    pub fn len(&self) -> usize {
+        unsafe {
+            let bounds = core::slice::from_raw_parts((*self.array).rgsabound.as_ptr(), (*self.array).cDims as usize);
+            bounds.iter().map(|bound| bound.cElements as usize).product()
+        }
+    }
+
+    /// Returns true if the array doesn't have any elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a copy of the element at the given indices, with one index for each dimension, from left to right.
+    pub fn get(&self, indices: &[i32]) -> Result<T> {
+        if indices.len() != self.dims() as usize {
+            return Err(windows_core::Error::from(DISP_E_BADINDEX));
+        }
+
+        let mut value = core::mem::MaybeUninit::<T>::zeroed();
+        unsafe {
+            SafeArrayGetElement(self.array, indices.as_ptr(), value.as_mut_ptr() as *mut _)?;
+            Ok(value.assume_init())
+        }
+    }
+
+    /// Locks the array, providing access to its elements as a slice until the lock is dropped.
+    ///
+    /// The elements of a multi-dimensional array are in column-major order, so the leftmost index changes fastest.
+    pub fn lock(&self) -> Result<SafeArrayLock<'_, T>> {
+        unsafe { SafeArrayLock(self.array)? };
+        Ok(SafeArrayLock { array: self })
+    }
+
+    /// Locks the array, providing mutable access to its elements as a slice until the lock is dropped.
+    pub fn lock_mut(&mut self) -> Result<SafeArrayLockMut<'_, T>> {
+        unsafe { SafeArrayLock(self.array)? };
+        Ok(SafeArrayLockMut { array: self })
+    }
+
+    /// Returns copies of the elements, in column-major order for a multi-dimensional array.
+    pub fn to_vec(&self) -> Result<Vec<T>>
+    where
+        T: Clone,
+    {
+        Ok(self.lock()?.to_vec())
+    }
+
+    fn created(array: *mut SAFEARRAY) -> Result<Self> {
+        if array.is_null() {
+            Err(windows_core::Error::from(E_OUTOFMEMORY))
+        } else {
+            Ok(Self { array, _marker: PhantomData })
+        }
+    }
+
+    // Only valid while the array is locked.
+    unsafe fn data(&self) -> *mut T {
+        (*self.array).pvData as *mut T
+    }
+}
+
+impl<T: SafeArrayElement> Clone for SafeArray<T> {
+    fn clone(&self) -> Self {
+        let array = unsafe { SafeArrayCopy(self.array) }.unwrap();
+        Self { array, _marker: PhantomData }
+    }
+}
+
+impl<T: SafeArrayElement> Drop for SafeArray<T> {
+    fn drop(&mut self) {
+        unsafe { _ = SafeArrayDestroy(self.array) };
+    }
+}
+
+impl<T: SafeArrayElement + core::fmt::Debug> core::fmt::Debug for SafeArray<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.lock() {
+            Ok(lock) => f.debug_list().entries(lock.iter()).finish(),
+            Err(error) => f.debug_tuple("SafeArray").field(&error).finish(),
+        }
+    }
+}
+
+impl<T: SafeArrayElement + Clone> TryFrom<&[T]> for SafeArray<T> {
+    type Error = windows_core::Error;
+    fn try_from(values: &[T]) -> Result<Self> {
+        Self::from_slice(values)
+    }
+}
+
+impl<T: SafeArrayElement + Clone> TryFrom<&SafeArray<T>> for Vec<T> {
+    type Error = windows_core::Error;
+    fn try_from(array: &SafeArray<T>) -> Result<Self> {
+        array.to_vec()
+    }
+}
+
+impl<T: SafeArrayElement> From<SafeArray<T>> for VARIANT {
+    fn from(value: SafeArray<T>) -> Self {
+        unsafe {
+            Self::from_raw(windows_core::imp::VARIANT {
+                Anonymous: windows_core::imp::VARIANT_0 {
+                    Anonymous: windows_core::imp::VARIANT_0_0 {
+                        vt: VT_ARRAY.0 | T::VT.0,
+                        wReserved1: 0,
+                        wReserved2: 0,
+                        wReserved3: 0,
+                        Anonymous: windows_core::imp::VARIANT_0_0_0 { parray: value.into_raw() as *mut _ },
+                    },
+                },
+            })
+        }
+    }
+}
+
+impl<T: SafeArrayElement> TryFrom<&VARIANT> for SafeArray<T> {
+    type Error = windows_core::Error;
+    fn try_from(from: &VARIANT) -> Result<Self> {
+        let from = from.as_raw();
+        unsafe {
+            if from.Anonymous.Anonymous.vt == VT_ARRAY.0 | T::VT.0 {
+                Self::from_raw(SafeArrayCopy(from.Anonymous.Anonymous.Anonymous.parray as *const _)?)
+            } else {
+                Err(windows_core::Error::from(DISP_E_TYPEMISMATCH))
+            }
+        }
+    }
+}
+
+/// A lock on a `SafeArray`, which provides access to its elements as a slice.
+pub struct SafeArrayLock<'a, T: SafeArrayElement> {
+    array: &'a SafeArray<T>,
+}
+
+impl<T: SafeArrayElement> Deref for SafeArrayLock<'_, T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        unsafe { slice(self.array) }
+    }
+}
+
+impl<T: SafeArrayElement> Drop for SafeArrayLock<'_, T> {
+    fn drop(&mut self) {
+        unsafe { _ = SafeArrayUnlock(self.array.array) };
+    }
+}
+
+/// A lock on a `SafeArray`, which provides mutable access to its elements as a slice.
+///
+/// Assigning an element drops the element that it replaces.
+pub struct SafeArrayLockMut<'a, T: SafeArrayElement> {
+    array: &'a mut SafeArray<T>,
+}
+
+impl<T: SafeArrayElement> Deref for SafeArrayLockMut<'_, T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] {
+        unsafe { slice(self.array) }
+    }
+}
+
+impl<T: SafeArrayElement> DerefMut for SafeArrayLockMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe {
+            let len = self.array.len();
+
+            if len == 0 {
+                &mut []
+            } else {
+                core::slice::from_raw_parts_mut(self.array.data(), len)
+            }
+        }
+    }
+}
+
+impl<T: SafeArrayElement> Drop for SafeArrayLockMut<'_, T> {
+    fn drop(&mut self) {
+        unsafe { _ = SafeArrayUnlock(self.array.array) };
+    }
+}
+
+// Only valid while the array is locked.
+unsafe fn slice<T: SafeArrayElement>(array: &SafeArray<T>) -> &[T] {
+    let len = array.len();
+
+    if len == 0 {
+        &[]
+    } else {
+        core::slice::from_raw_parts(array.data(), len)
+    }
+}
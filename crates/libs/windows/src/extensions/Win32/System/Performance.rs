@@ -0,0 +1,259 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::Performance::*;
+use std::sync::{Arc, Mutex};
+use windows_core::{Result, HRESULT, HSTRING, PCWSTR, PWSTR};
+
+// PDH functions return their own status codes, which are already in the form of an `HRESULT`.
+fn check(status: u32) -> Result<()> {
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(HRESULT(status as i32).into())
+    }
+}
+
+// Splits a list of strings separated by nulls and terminated by an empty string.
+fn split_multi(buffer: &[u16]) -> Vec<String> {
+    buffer.split(|c| *c == 0).take_while(|value| !value.is_empty()).map(String::from_utf16_lossy).collect()
+}
+
+// Calls a PDH function that fills in a list of strings, first to get the required length and then to fill it in.
+fn read_multi<F: FnMut(PWSTR, &mut u32) -> u32>(mut fill: F) -> Result<Vec<String>> {
+    let mut len = 0;
+    let status = fill(PWSTR::null(), &mut len);
+
+    if status != PDH_MORE_DATA {
+        check(status)?;
+        return Ok(Vec::new());
+    }
+
+    let mut buffer = vec![0u16; len as usize];
+    check(fill(PWSTR(buffer.as_mut_ptr()), &mut len))?;
+    Ok(split_multi(&buffer))
+}
+
+struct QueryHandle {
+    handle: isize,
+    // The event signaled by `CounterQuery::collect_every`, which must outlive the query's collection thread.
+    event: Mutex<Option<isize>>,
+}
+
+impl Drop for QueryHandle {
+    fn drop(&mut self) {
+        unsafe { _ = PdhCloseQuery(self.handle) };
+
+        if let Some(event) = self.event.get_mut().unwrap().take() {
+            unsafe { _ = CloseHandle(HANDLE(event as _)) };
+        }
+    }
+}
+
+/// A query that collects samples of one or more performance counters.
+///
+/// Rate counters, such as `% Processor Time`, need two samples before they have a value:
+///
+/// ```rust,ignore
+/// let query = CounterQuery::new()?;
+/// let cpu = query.add_english(r"\Processor(_Total)\% Processor Time")?;
+///
+/// query.collect()?;
+/// std::thread::sleep(Duration::from_secs(1));
+/// query.collect()?;
+///
+/// println!("{:.1}%", cpu.value()?);
+/// ```
+#[derive(Clone)]
+pub struct CounterQuery(Arc<QueryHandle>);
+
+impl CounterQuery {
+    /// Opens a query over the real-time counters of the local computer.
+    pub fn new() -> Result<Self> {
+        let mut handle = 0;
+        check(unsafe { PdhOpenQueryW(PCWSTR::null(), 0, &mut handle) })?;
+        Ok(Self(Arc::new(QueryHandle { handle, event: Mutex::new(None) })))
+    }
+
+    /// Returns the query handle.
+    pub fn handle(&self) -> isize {
+        self.0.handle
+    }
+
+    /// Adds a counter by its localized path, such as `\Processor(_Total)\% Processor Time` on an English system.
+    ///
+    /// A path that includes a wildcard, such as `\Processor(*)\% Processor Time`, adds a counter whose
+    /// values are read with `Counter::values`.
+    pub fn add<T: Into<HSTRING>>(&self, path: T) -> Result<Counter> {
+        let mut handle = 0;
+        check(unsafe { PdhAddCounterW(self.handle(), &path.into(), 0, &mut handle) })?;
+        Ok(Counter { _query: self.0.clone(), handle })
+    }
+
+    /// Adds a counter by its English path, regardless of the language of the system.
+    pub fn add_english<T: Into<HSTRING>>(&self, path: T) -> Result<Counter> {
+        let mut handle = 0;
+        check(unsafe { PdhAddEnglishCounterW(self.handle(), &path.into(), 0, &mut handle) })?;
+        Ok(Counter { _query: self.0.clone(), handle })
+    }
+
+    /// Collects a sample of each counter in the query.
+    pub fn collect(&self) -> Result<()> {
+        check(unsafe { PdhCollectQueryData(self.handle()) })
+    }
+
+    /// Starts collecting a sample on a background thread each interval, until the query is closed.
+    ///
+    /// The returned event is signaled after each sample is collected, and may be waited on with `CollectTimer::wait`
+    /// or any of the wait functions.
+    #[cfg(all(feature = "Win32_Security", feature = "Win32_System_Threading"))]
+    pub fn collect_every(&self, interval: core::time::Duration) -> Result<CollectTimer> {
+        let mut event = self.0.event.lock().unwrap();
+
+        let handle = match *event {
+            Some(handle) => HANDLE(handle as _),
+            None => {
+                let handle = unsafe { crate::Win32::System::Threading::CreateEventW(None, false, false, None)? };
+                *event = Some(handle.0 as isize);
+                handle
+            }
+        };
+
+        let seconds = interval.as_secs().clamp(1, u32::MAX as u64) as u32;
+        check(unsafe { PdhCollectQueryDataEx(self.handle(), seconds, handle) })?;
+        Ok(CollectTimer { query: self.clone(), event: handle.0 as isize })
+    }
+}
+
+/// Signals when a `CounterQuery` collects a sample, produced by `CounterQuery::collect_every`.
+#[cfg(all(feature = "Win32_Security", feature = "Win32_System_Threading"))]
+#[derive(Clone)]
+pub struct CollectTimer {
+    query: CounterQuery,
+    event: isize,
+}
+
+#[cfg(all(feature = "Win32_Security", feature = "Win32_System_Threading"))]
+impl CollectTimer {
+    /// Returns the query that is being collected.
+    pub fn query(&self) -> &CounterQuery {
+        &self.query
+    }
+
+    /// Returns the auto-reset event that is signaled after each sample is collected.
+    pub fn event(&self) -> HANDLE {
+        HANDLE(self.event as _)
+    }
+
+    /// Waits for the next sample to be collected.
+    pub fn wait(&self) -> Result<()> {
+        use crate::Win32::System::Threading::*;
+
+        if unsafe { WaitForSingleObject(self.event(), INFINITE) } == WAIT_OBJECT_0 {
+            Ok(())
+        } else {
+            Err(windows_core::Error::from_win32())
+        }
+    }
+}
+
+/// A counter in a `CounterQuery`, whose value is read after the query collects a sample.
+///
+/// The counter keeps the query open.
+pub struct Counter {
+    _query: Arc<QueryHandle>,
+    handle: isize,
+}
+
+impl Counter {
+    /// Returns the counter handle.
+    pub fn handle(&self) -> isize {
+        self.handle
+    }
+
+    /// Returns the value of the counter from the most recent sample, scaled as a floating point number.
+    pub fn value(&self) -> Result<f64> {
+        unsafe { Ok(self.formatted(PDH_FMT_DOUBLE)?.doubleValue) }
+    }
+
+    /// Returns the value of the counter from the most recent sample, as an integer.
+    pub fn large_value(&self) -> Result<i64> {
+        unsafe { Ok(self.formatted(PDH_FMT_LARGE)?.largeValue) }
+    }
+
+    /// Returns the name and value of each instance of a counter added with a wildcard, such as `\Processor(*)\% Processor Time`.
+    pub fn values(&self) -> Result<Vec<(String, f64)>> {
+        let mut size = 0;
+        let mut count = 0;
+        let status = unsafe { PdhGetFormattedCounterArrayW(self.handle, PDH_FMT_DOUBLE, &mut size, &mut count, None) };
+
+        if status != PDH_MORE_DATA {
+            check(status)?;
+            return Ok(Vec::new());
+        }
+
+        // The buffer holds the items followed by the strings that they point to.
+        let item_size = core::mem::size_of::<PDH_FMT_COUNTERVALUE_ITEM_W>();
+        let mut buffer = vec![PDH_FMT_COUNTERVALUE_ITEM_W::default(); (size as usize).div_ceil(item_size)];
+        check(unsafe { PdhGetFormattedCounterArrayW(self.handle, PDH_FMT_DOUBLE, &mut size, &mut count, Some(buffer.as_mut_ptr())) })?;
+
+        buffer[..count as usize]
+            .iter()
+            .map(|item| {
+                check(item.FmtValue.CStatus)?;
+                Ok((unsafe { item.szName.to_string() }.unwrap_or_default(), unsafe { item.FmtValue.Anonymous.doubleValue }))
+            })
+            .collect()
+    }
+
+    fn formatted(&self, format: PDH_FMT) -> Result<PDH_FMT_COUNTERVALUE_0> {
+        let mut value = PDH_FMT_COUNTERVALUE::default();
+        check(unsafe { PdhGetFormattedCounterValue(self.handle, format, None, &mut value) })?;
+
+        if value.CStatus == PDH_CSTATUS_VALID_DATA || value.CStatus == PDH_CSTATUS_NEW_DATA {
+            Ok(value.Anonymous)
+        } else {
+            Err(HRESULT(value.CStatus as i32).into())
+        }
+    }
+}
+
+impl Drop for Counter {
+    fn drop(&mut self) {
+        unsafe { _ = PdhRemoveCounter(self.handle) };
+    }
+}
+
+/// Expands a counter path that includes wildcards, such as `\Processor(*)\*`, into the matching counter paths.
+pub fn expand_counter_path<T: Into<HSTRING>>(path: T) -> Result<Vec<String>> {
+    let path = path.into();
+    read_multi(|buffer, len| unsafe { PdhExpandWildCardPathW(PCWSTR::null(), &path, buffer, len, 0) })
+}
+
+/// Returns the names of the performance objects on the local computer, such as `Processor` and `PhysicalDisk`.
+pub fn counter_objects() -> Result<Vec<String>> {
+    // Refreshing the list the first time also makes sure that objects that were recently installed are included.
+    read_multi(|buffer, len| unsafe { PdhEnumObjectsW(PCWSTR::null(), PCWSTR::null(), buffer, len, PERF_DETAIL_WIZARD, true) })
+}
+
+/// Returns the names of the counters and instances of a performance object, such as `Processor`.
+///
+/// Objects that have a single instance return an empty list of instances.
+pub fn counter_object_items<T: Into<HSTRING>>(object: T) -> Result<(Vec<String>, Vec<String>)> {
+    let object = object.into();
+    let mut counters_len = 0;
+    let mut instances_len = 0;
+    let status = unsafe { PdhEnumObjectItemsW(PCWSTR::null(), PCWSTR::null(), &object, PWSTR::null(), &mut counters_len, PWSTR::null(), &mut instances_len, PERF_DETAIL_WIZARD, 0) };
+
+    if status != PDH_MORE_DATA {
+        check(status)?;
+        return Ok((Vec::new(), Vec::new()));
+    }
+
+    let mut counters = vec![0u16; counters_len as usize];
+    let mut instances = vec![0u16; instances_len as usize];
+
+    let counters_ptr = if counters.is_empty() { PWSTR::null() } else { PWSTR(counters.as_mut_ptr()) };
+    let instances_ptr = if instances.is_empty() { PWSTR::null() } else { PWSTR(instances.as_mut_ptr()) };
+
+    check(unsafe { PdhEnumObjectItemsW(PCWSTR::null(), PCWSTR::null(), &object, counters_ptr, &mut counters_len, instances_ptr, &mut instances_len, PERF_DETAIL_WIZARD, 0) })?;
+    Ok((split_multi(&counters), split_multi(&instances)))
+}
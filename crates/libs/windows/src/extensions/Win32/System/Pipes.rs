@@ -0,0 +1,324 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::Security::SECURITY_ATTRIBUTES;
+use crate::Win32::Storage::FileSystem::*;
+use crate::Win32::System::Pipes::*;
+use core::time::Duration;
+use windows_core::{Owned, Result, HSTRING};
+
+/// A builder for the server end of a named pipe, created with `CreateNamedPipeW`.
+///
+/// Pipes are duplex, byte-mode, blocking, and allow unlimited instances unless configured otherwise:
+///
+/// ```rust,ignore
+/// let server = NamedPipeServer::new(r"\\.\pipe\example").message_mode().max_instances(4).create()?;
+/// server.connect()?;
+/// let request = server.read_message()?;
+/// ```
+pub struct NamedPipeServer<'a> {
+    name: HSTRING,
+    access: FILE_FLAGS_AND_ATTRIBUTES,
+    mode: NAMED_PIPE_MODE,
+    instances: u32,
+    out_buffer: u32,
+    in_buffer: u32,
+    timeout: u32,
+    security: Option<&'a SECURITY_ATTRIBUTES>,
+}
+
+impl<'a> NamedPipeServer<'a> {
+    /// Creates a builder for a pipe with the given name, which must have the form `\\.\pipe\name`.
+    pub fn new<T: Into<HSTRING>>(name: T) -> Self {
+        Self {
+            name: name.into(),
+            access: PIPE_ACCESS_DUPLEX,
+            mode: PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT,
+            instances: PIPE_UNLIMITED_INSTANCES,
+            out_buffer: 4096,
+            in_buffer: 4096,
+            timeout: 0,
+            security: None,
+        }
+    }
+
+    /// Only allows the server to read from the pipe.
+    pub fn inbound(mut self) -> Self {
+        self.access = (self.access & !PIPE_ACCESS_DUPLEX) | PIPE_ACCESS_INBOUND;
+        self
+    }
+
+    /// Only allows the server to write to the pipe.
+    pub fn outbound(mut self) -> Self {
+        self.access = (self.access & !PIPE_ACCESS_DUPLEX) | PIPE_ACCESS_OUTBOUND;
+        self
+    }
+
+    /// Writes and reads data as messages rather than as a stream of bytes.
+    pub fn message_mode(mut self) -> Self {
+        self.mode |= PIPE_TYPE_MESSAGE | PIPE_READMODE_MESSAGE;
+        self
+    }
+
+    /// Limits the number of instances of the pipe that may be created, from 1 to `PIPE_UNLIMITED_INSTANCES`.
+    pub fn max_instances(mut self, instances: u32) -> Self {
+        self.instances = instances;
+        self
+    }
+
+    /// Sets the number of bytes to reserve for the output and input buffers.
+    pub fn buffer_sizes(mut self, out_buffer: u32, in_buffer: u32) -> Self {
+        self.out_buffer = out_buffer;
+        self.in_buffer = in_buffer;
+        self
+    }
+
+    /// Sets the time that clients calling `WaitNamedPipeW` with `NMPWAIT_USE_DEFAULT_WAIT` wait for an instance.
+    pub fn default_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout.as_millis().clamp(1, u32::MAX as u128 - 1) as u32;
+        self
+    }
+
+    /// Fails with `ERROR_ACCESS_DENIED` if an instance of the pipe already exists.
+    pub fn first_instance(mut self) -> Self {
+        self.access |= FILE_FLAG_FIRST_PIPE_INSTANCE;
+        self
+    }
+
+    /// Rejects clients connecting from remote computers.
+    pub fn reject_remote_clients(mut self) -> Self {
+        self.mode |= PIPE_REJECT_REMOTE_CLIENTS;
+        self
+    }
+
+    /// Opens the pipe for overlapped I/O.
+    ///
+    /// An overlapped pipe must not be used with the blocking methods of `NamedPipe`. Instead, pass the
+    /// pipe's handle to `CompletionPort::associate` to await `connect_named_pipe`, `read`, and `write`.
+    pub fn overlapped(mut self) -> Self {
+        self.access |= FILE_FLAG_OVERLAPPED;
+        self
+    }
+
+    /// Sets the security descriptor of the pipe and whether its handle is inherited by child processes.
+    pub fn security_attributes(mut self, security: &'a SECURITY_ATTRIBUTES) -> Self {
+        self.security = Some(security);
+        self
+    }
+
+    /// Creates a new instance of the pipe.
+    pub fn create(&self) -> Result<NamedPipe> {
+        unsafe {
+            let handle = CreateNamedPipeW(&self.name, self.access, self.mode, self.instances, self.out_buffer, self.in_buffer, self.timeout, self.security.map(|security| security as *const _));
+
+            if handle.is_invalid() {
+                Err(windows_core::Error::from_win32())
+            } else {
+                Ok(NamedPipe(Owned::new(handle)))
+            }
+        }
+    }
+}
+
+/// A builder for the client end of a named pipe, opened with `CreateFileW`.
+///
+/// Pipes are opened for reading and writing in byte mode unless configured otherwise.
+pub struct NamedPipeClient<'a> {
+    name: HSTRING,
+    access: u32,
+    flags: FILE_FLAGS_AND_ATTRIBUTES,
+    message_mode: bool,
+    wait: Option<u32>,
+    security: Option<&'a SECURITY_ATTRIBUTES>,
+}
+
+impl<'a> NamedPipeClient<'a> {
+    /// Creates a builder for a pipe with the given name, which has the form `\\server\pipe\name`.
+    pub fn new<T: Into<HSTRING>>(name: T) -> Self {
+        Self {
+            name: name.into(),
+            access: (FILE_GENERIC_READ | FILE_GENERIC_WRITE).0,
+            flags: FILE_FLAGS_AND_ATTRIBUTES(0),
+            message_mode: false,
+            wait: None,
+            security: None,
+        }
+    }
+
+    /// Only allows the client to read from the pipe.
+    pub fn read_only(mut self) -> Self {
+        self.access = FILE_GENERIC_READ.0;
+        self
+    }
+
+    /// Only allows the client to write to the pipe, as required for pipes that the server created with `inbound`.
+    ///
+    /// A write-only client of a message-mode pipe cannot switch to reading messages, so its read mode is not changed.
+    pub fn write_only(mut self) -> Self {
+        self.access = FILE_GENERIC_WRITE.0 | FILE_READ_ATTRIBUTES.0;
+        self
+    }
+
+    /// Reads data from the pipe as messages, which requires the server to have created a message-mode pipe.
+    pub fn message_mode(mut self) -> Self {
+        self.message_mode = true;
+        self
+    }
+
+    /// Waits up to the given duration for an instance of the pipe to become available if all instances are busy.
+    ///
+    /// Without a wait, opening a busy pipe fails with `ERROR_PIPE_BUSY`.
+    pub fn wait(mut self, timeout: Duration) -> Self {
+        self.wait = Some(timeout.as_millis().min(NMPWAIT_WAIT_FOREVER as u128 - 1) as u32);
+        self
+    }
+
+    /// Opens the pipe for overlapped I/O.
+    ///
+    /// As with `NamedPipeServer::overlapped`, pass the pipe's handle to `CompletionPort::associate` to perform I/O.
+    pub fn overlapped(mut self) -> Self {
+        self.flags |= FILE_FLAG_OVERLAPPED;
+        self
+    }
+
+    /// Sets whether the pipe's handle is inherited by child processes.
+    pub fn security_attributes(mut self, security: &'a SECURITY_ATTRIBUTES) -> Self {
+        self.security = Some(security);
+        self
+    }
+
+    /// Connects to an instance of the pipe.
+    ///
+    /// If a wait was requested, the connection is retried until an instance is available or the wait times out
+    /// with `ERROR_SEM_TIMEOUT`. Another client may claim an instance between it becoming available and the
+    /// connection being retried, in which case the remaining time is spent waiting for the next instance.
+    pub fn open(&self) -> Result<NamedPipe> {
+        let start = std::time::Instant::now();
+
+        let handle = loop {
+            let result = unsafe { CreateFileW(&self.name, self.access, FILE_SHARE_NONE, self.security.map(|security| security as *const _), OPEN_EXISTING, self.flags, None) };
+
+            match (result, self.wait) {
+                (Ok(handle), _) => break unsafe { Owned::new(handle) },
+                (Err(error), Some(wait)) if error.code() == ERROR_PIPE_BUSY.to_hresult() => {
+                    let wait = wait.saturating_sub(start.elapsed().as_millis().min(u32::MAX as u128) as u32);
+
+                    // A zero timeout would use the server's default, so an elapsed wait is reported directly.
+                    if wait == 0 || !unsafe { WaitNamedPipeW(&self.name, wait) }.as_bool() {
+                        return Err(windows_core::Error::from(ERROR_SEM_TIMEOUT.to_hresult()));
+                    }
+                }
+                (Err(error), _) => return Err(error),
+            }
+        };
+
+        if self.message_mode && self.access & FILE_READ_DATA.0 != 0 {
+            unsafe { SetNamedPipeHandleState(*handle, Some(&PIPE_READMODE_MESSAGE), None, None)? };
+        }
+
+        Ok(NamedPipe(handle))
+    }
+}
+
+/// Either end of a named pipe, supporting blocking I/O.
+///
+/// The pipe's handle is closed when it is dropped.
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct NamedPipe(Owned<HANDLE>);
+
+unsafe impl Send for NamedPipe {}
+unsafe impl Sync for NamedPipe {}
+
+impl NamedPipe {
+    /// Returns the underlying handle.
+    pub fn handle(&self) -> HANDLE {
+        *self.0
+    }
+
+    /// Returns the underlying handle, passing ownership to the caller.
+    ///
+    /// This is useful for associating an overlapped pipe with a `CompletionPort`.
+    pub fn into_handle(self) -> Owned<HANDLE> {
+        self.0
+    }
+
+    /// Waits for a client to connect to this server instance of the pipe.
+    ///
+    /// A client that connected before this was called is treated as a successful connection.
+    pub fn connect(&self) -> Result<()> {
+        match unsafe { ConnectNamedPipe(*self.0, None) } {
+            Err(error) if error.code() == ERROR_PIPE_CONNECTED.to_hresult() => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Disconnects the client from this server instance so that the instance can accept another client.
+    ///
+    /// Any data that the client has not yet read is discarded, so call `flush` first to ensure it is delivered.
+    pub fn disconnect(&self) -> Result<()> {
+        unsafe { DisconnectNamedPipe(*self.0) }
+    }
+
+    /// Reads data from the pipe, returning the number of bytes read.
+    ///
+    /// Returns `Ok(0)` once the other end of the pipe has closed it. In message mode, a message larger than the
+    /// buffer fails with `ERROR_MORE_DATA` after filling the buffer, and the rest of the message is returned by
+    /// subsequent reads. Use `read_message` to read whole messages.
+    pub fn read(&self, buffer: &mut [u8]) -> Result<usize> {
+        let mut bytes = 0;
+
+        match unsafe { ReadFile(*self.0, Some(buffer), Some(&mut bytes), None) } {
+            Err(error) if error.code() == ERROR_BROKEN_PIPE.to_hresult() => Ok(0),
+            result => result.map(|_| bytes as usize),
+        }
+    }
+
+    /// Reads the next message from a message-mode pipe.
+    ///
+    /// Returns an empty message once the other end of the pipe has closed it.
+    pub fn read_message(&self) -> Result<Vec<u8>> {
+        let mut message = Vec::new();
+        let mut chunk = [0; 4096];
+
+        loop {
+            let mut bytes = 0;
+            let result = unsafe { ReadFile(*self.0, Some(&mut chunk), Some(&mut bytes), None) };
+            message.extend_from_slice(&chunk[..bytes as usize]);
+
+            match result {
+                Ok(()) => return Ok(message),
+                Err(error) if error.code() == ERROR_MORE_DATA.to_hresult() => continue,
+                Err(error) if error.code() == ERROR_BROKEN_PIPE.to_hresult() => return Ok(message),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    /// Writes data to the pipe, returning the number of bytes written.
+    ///
+    /// In message mode, each write is a separate message.
+    pub fn write(&self, buffer: &[u8]) -> Result<usize> {
+        let mut bytes = 0;
+        unsafe { WriteFile(*self.0, Some(buffer), Some(&mut bytes), None)? };
+        Ok(bytes as usize)
+    }
+
+    /// Waits until the other end of the pipe has read all of the data written to it.
+    pub fn flush(&self) -> Result<()> {
+        unsafe { FlushFileBuffers(*self.0) }
+    }
+}
+
+impl std::io::Read for NamedPipe {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        Ok(NamedPipe::read(self, buffer)?)
+    }
+}
+
+impl std::io::Write for NamedPipe {
+    fn write(&mut self, buffer: &[u8]) -> std::io::Result<usize> {
+        Ok(NamedPipe::write(self, buffer)?)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(NamedPipe::flush(self)?)
+    }
+}
@@ -0,0 +1,297 @@
+use crate::extensions::Win32::UI::WindowsAndMessaging::{MessageLoop, Window, WindowBuilder};
+use crate::Win32::Foundation::*;
+use crate::Win32::System::Power::*;
+use crate::Win32::UI::WindowsAndMessaging::*;
+use core::task::{Context, Poll, Waker};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use windows_core::{Owned, Result, GUID};
+
+/// A power or session change reported by `PowerNotifications`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PowerEvent {
+    /// The system is about to suspend.
+    Suspend,
+    /// The system resumed from suspend, whether or not a user is present.
+    ResumeAutomatic,
+    /// The system resumed from suspend because of user activity. This follows `ResumeAutomatic`.
+    Resume,
+    /// The power status changed, such as switching between AC and battery power.
+    StatusChange,
+    /// A power setting changed, along with its new value. Most settings, such as
+    /// `GUID_LIDSWITCH_STATE_CHANGE`, have a 4-byte value.
+    Setting(GUID, Vec<u8>),
+    /// A session changed state for a reason such as `WTS_SESSION_LOCK` or `WTS_SESSION_UNLOCK`.
+    Session {
+        /// The `WTS_*` reason for the change.
+        reason: u32,
+        /// The identifier of the session that changed.
+        session_id: u32,
+    },
+}
+
+impl PowerEvent {
+    // Translates the parameters of `WM_POWERBROADCAST`, or of a `SERVICE_CONTROL_POWEREVENT` control code.
+    unsafe fn from_power_broadcast(event: u32, data: *const core::ffi::c_void) -> Option<Self> {
+        match event {
+            PBT_APMSUSPEND => Some(Self::Suspend),
+            PBT_APMRESUMEAUTOMATIC => Some(Self::ResumeAutomatic),
+            PBT_APMRESUMESUSPEND => Some(Self::Resume),
+            PBT_APMPOWERSTATUSCHANGE => Some(Self::StatusChange),
+            PBT_POWERSETTINGCHANGE if !data.is_null() => {
+                let setting = &*(data as *const POWERBROADCAST_SETTING);
+                let value = core::slice::from_raw_parts(setting.Data.as_ptr(), setting.DataLength as usize);
+                Some(Self::Setting(setting.PowerSetting, value.to_vec()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Translates the `SERVICE_CONTROL_POWEREVENT` and `SERVICE_CONTROL_SESSIONCHANGE` control codes
+    /// received by a service's control handler, returning `None` for other control codes.
+    ///
+    /// # Safety
+    ///
+    /// The event data must be the pointer passed to the control handler along with the control code.
+    #[cfg(all(feature = "Win32_System_RemoteDesktop", feature = "Win32_System_Services"))]
+    pub unsafe fn from_service_control(control: u32, event_type: u32, event_data: *const core::ffi::c_void) -> Option<Self> {
+        use crate::Win32::System::{RemoteDesktop::WTSSESSION_NOTIFICATION, Services::*};
+
+        match control {
+            SERVICE_CONTROL_POWEREVENT => Self::from_power_broadcast(event_type, event_data),
+            SERVICE_CONTROL_SESSIONCHANGE if !event_data.is_null() => Some(Self::Session { reason: event_type, session_id: (*(event_data as *const WTSSESSION_NOTIFICATION)).dwSessionId }),
+            _ => None,
+        }
+    }
+}
+
+/// Registers for power and session notifications, which are reported to a callback or a stream.
+///
+/// Suspend, resume, and power status changes are always reported. Power settings and session changes
+/// are reported once registered with `setting` and `sessions` or `all_sessions`.
+///
+/// ```rust,ignore
+/// let mut events = PowerNotifications::new()
+///     .setting(GUID_LIDSWITCH_STATE_CHANGE)
+///     .sessions()
+///     .stream()?;
+///
+/// while let Some(event) = events.next().await {
+///     match event {
+///         PowerEvent::Suspend => flush()?,
+///         PowerEvent::Session { reason: WTS_SESSION_LOCK, .. } => pause(),
+///         _ => {}
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PowerNotifications {
+    settings: Vec<GUID>,
+    sessions: Option<u32>,
+}
+
+impl PowerNotifications {
+    /// Creates a registration for suspend, resume, and power status changes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers for changes to a power setting, such as `GUID_ACDC_POWER_SOURCE` or `GUID_CONSOLE_DISPLAY_STATE`.
+    ///
+    /// The current value of the setting is reported immediately after registering.
+    pub fn setting(mut self, setting: GUID) -> Self {
+        self.settings.push(setting);
+        self
+    }
+
+    /// Registers for session changes of the current session, such as lock, unlock, logon, and logoff.
+    #[cfg(feature = "Win32_System_RemoteDesktop")]
+    pub fn sessions(mut self) -> Self {
+        self.sessions = Some(crate::Win32::System::RemoteDesktop::NOTIFY_FOR_THIS_SESSION);
+        self
+    }
+
+    /// Registers for session changes of all sessions on the machine, such as remote connections and
+    /// disconnections, which is useful for services and other processes that manage sessions.
+    #[cfg(feature = "Win32_System_RemoteDesktop")]
+    pub fn all_sessions(mut self) -> Self {
+        self.sessions = Some(crate::Win32::System::RemoteDesktop::NOTIFY_FOR_ALL_SESSIONS);
+        self
+    }
+
+    /// Starts reporting events to a callback, which is called on a dedicated thread until the listener is dropped.
+    pub fn listen<F: FnMut(PowerEvent) + Send + 'static>(&self, callback: F) -> Result<PowerListener> {
+        PowerListener::new(self.clone(), callback)
+    }
+
+    /// Starts reporting events to a stream, which ends when dropped.
+    pub fn stream(&self) -> Result<PowerEventStream> {
+        let shared = Arc::new(Mutex::new(StreamState::default()));
+        let sender = shared.clone();
+
+        let listener = self.listen(move |event| {
+            let mut state = sender.lock().unwrap();
+            state.events.push_back(event);
+
+            if let Some(waker) = state.waker.take() {
+                drop(state);
+                waker.wake();
+            }
+        })?;
+
+        Ok(PowerEventStream { shared, _listener: listener })
+    }
+}
+
+/// Reports power and session notifications to a callback until dropped.
+///
+/// This is produced by `PowerNotifications::listen`. The notifications are received by a hidden window
+/// on a dedicated thread, since suspend and resume are only broadcast to top-level windows.
+pub struct PowerListener {
+    // The window handle, stored as an integer since `HWND` can't be sent between threads.
+    window: isize,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl PowerListener {
+    fn new<F: FnMut(PowerEvent) + Send + 'static>(notifications: PowerNotifications, callback: F) -> Result<Self> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let thread = std::thread::Builder::new().name("PowerListener".to_string()).spawn(move || match listener::create(&notifications, Box::new(callback)) {
+            Ok(registration) => {
+                _ = sender.send(Ok(registration.window.handle().0 as isize));
+                _ = MessageLoop::new().run();
+            }
+            Err(error) => _ = sender.send(Err(error)),
+        })?;
+
+        match receiver.recv() {
+            Ok(Ok(window)) => Ok(Self { window, thread: Some(thread) }),
+            Ok(Err(error)) => {
+                _ = thread.join();
+                Err(error)
+            }
+            Err(_) => Err(E_UNEXPECTED.into()),
+        }
+    }
+}
+
+impl Drop for PowerListener {
+    fn drop(&mut self) {
+        unsafe {
+            _ = PostMessageW(HWND(self.window as _), WM_CLOSE, WPARAM(0), LPARAM(0));
+        }
+
+        if let Some(thread) = self.thread.take() {
+            _ = thread.join();
+        }
+    }
+}
+
+mod listener {
+    use super::*;
+
+    // The window and its registrations, which are removed before the window is destroyed.
+    pub struct Registration {
+        settings: Vec<Owned<HPOWERNOTIFY>>,
+        sessions: bool,
+        pub window: Window,
+    }
+
+    impl Drop for Registration {
+        fn drop(&mut self) {
+            self.settings.clear();
+
+            #[cfg(feature = "Win32_System_RemoteDesktop")]
+            if self.sessions {
+                unsafe { _ = crate::Win32::System::RemoteDesktop::WTSUnRegisterSessionNotification(self.window.handle()) };
+            }
+        }
+    }
+
+    pub fn create(notifications: &PowerNotifications, callback: Box<dyn FnMut(PowerEvent)>) -> Result<Registration> {
+        let callback = RefCell::new(callback);
+
+        let report = move |event| {
+            // Events raised while the callback is running, such as by a nested message loop, are dropped.
+            if let Ok(mut callback) = callback.try_borrow_mut() {
+                callback(event);
+            }
+        };
+
+        let window = WindowBuilder::new("windows-rs-power-listener").create(move |_, message, wparam, lparam| match message {
+            WM_POWERBROADCAST => {
+                if let Some(event) = unsafe { PowerEvent::from_power_broadcast(wparam.0 as u32, lparam.0 as _) } {
+                    report(event);
+                }
+
+                Some(LRESULT(1))
+            }
+            WM_WTSSESSION_CHANGE => {
+                report(PowerEvent::Session { reason: wparam.0 as u32, session_id: lparam.0 as u32 });
+                Some(LRESULT(0))
+            }
+            WM_DESTROY => {
+                unsafe { PostQuitMessage(0) };
+                Some(LRESULT(0))
+            }
+            _ => None,
+        })?;
+
+        let mut registration = Registration { settings: Vec::new(), sessions: false, window };
+        let recipient = HANDLE(registration.window.handle().0);
+
+        for setting in &notifications.settings {
+            registration.settings.push(unsafe { Owned::new(RegisterPowerSettingNotification(recipient, setting, DEVICE_NOTIFY_WINDOW_HANDLE)?) });
+        }
+
+        #[cfg(feature = "Win32_System_RemoteDesktop")]
+        if let Some(flags) = notifications.sessions {
+            unsafe { crate::Win32::System::RemoteDesktop::WTSRegisterSessionNotification(registration.window.handle(), flags)? };
+            registration.sessions = true;
+        }
+
+        Ok(registration)
+    }
+}
+
+#[derive(Default)]
+struct StreamState {
+    events: VecDeque<PowerEvent>,
+    waker: Option<Waker>,
+}
+
+/// A stream of power and session notifications, produced by `PowerNotifications::stream`.
+///
+/// The stream never ends. Notifications stop once it is dropped.
+pub struct PowerEventStream {
+    shared: Arc<Mutex<StreamState>>,
+    _listener: PowerListener,
+}
+
+impl PowerEventStream {
+    /// Attempts to pull out the next event, registering the current task for wakeup if none is available.
+    pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<PowerEvent>> {
+        let mut state = self.shared.lock().unwrap();
+
+        match state.events.pop_front() {
+            Some(event) => Poll::Ready(Some(event)),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Unpin for PowerEventStream {}
+
+#[cfg(feature = "futures")]
+impl futures_core::Stream for PowerEventStream {
+    type Item = PowerEvent;
+
+    fn poll_next(self: core::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_next(cx)
+    }
+}
@@ -0,0 +1,172 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::RemoteDesktop::*;
+use windows_core::{Result, PWSTR};
+
+/// A Terminal Services session on the local machine, such as the physical console or a Remote Desktop connection.
+///
+/// ```rust,ignore
+/// for info in Session::enumerate()? {
+///     if info.state == WTSActive {
+///         println!("{} is logged on to {} over {:?}", info.session.user_name()?, info.station_name, info.session.protocol()?);
+///     }
+/// }
+/// ```
+///
+/// Changes to sessions, such as logons and remote connections, are reported by `PowerNotifications::all_sessions`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Session(u32);
+
+impl Session {
+    /// Returns the session with the given identifier. The session might not exist.
+    pub fn from_id(id: u32) -> Self {
+        Self(id)
+    }
+
+    /// Returns the session in which the current process is running.
+    pub fn current() -> Result<Self> {
+        unsafe { query(WTS_CURRENT_SESSION, WTSSessionId, |buffer| *(buffer as *const u32)).map(Self) }
+    }
+
+    /// Returns the session attached to the physical console, or `None` if the console is being attached or detached.
+    pub fn console() -> Option<Self> {
+        match unsafe { WTSGetActiveConsoleSessionId() } {
+            u32::MAX => None,
+            id => Some(Self(id)),
+        }
+    }
+
+    /// Returns the sessions on the local machine, including the services session and any listening sessions.
+    pub fn enumerate() -> Result<Vec<SessionInfo>> {
+        unsafe {
+            let mut buffer = core::ptr::null_mut();
+            let mut len = 0;
+            WTSEnumerateSessionsW(WTS_CURRENT_SERVER_HANDLE, 0, 1, &mut buffer, &mut len)?;
+
+            if buffer.is_null() {
+                return Ok(Vec::new());
+            }
+
+            let sessions = core::slice::from_raw_parts(buffer, len as usize).iter().map(|info| SessionInfo { session: Self(info.SessionId), station_name: info.pWinStationName.to_string().unwrap_or_default(), state: info.State }).collect();
+
+            WTSFreeMemory(buffer as _);
+            Ok(sessions)
+        }
+    }
+
+    /// Returns the session identifier.
+    pub fn id(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the name of the user logged on to the session, which is empty if no user is logged on.
+    pub fn user_name(&self) -> Result<String> {
+        self.query_string(WTSUserName)
+    }
+
+    /// Returns the domain of the user logged on to the session.
+    pub fn domain_name(&self) -> Result<String> {
+        self.query_string(WTSDomainName)
+    }
+
+    /// Returns the name of the session's window station, such as `Console` or `RDP-Tcp#3`.
+    pub fn station_name(&self) -> Result<String> {
+        self.query_string(WTSWinStationName)
+    }
+
+    /// Returns the name of the machine that a remote session is connected from.
+    pub fn client_name(&self) -> Result<String> {
+        self.query_string(WTSClientName)
+    }
+
+    /// Returns the connection state of the session, such as `WTSActive` or `WTSDisconnected`.
+    pub fn state(&self) -> Result<WTS_CONNECTSTATE_CLASS> {
+        unsafe { query(self.0, WTSConnectState, |buffer| WTS_CONNECTSTATE_CLASS(*(buffer as *const i32))) }
+    }
+
+    /// Returns the protocol through which the session is connected.
+    pub fn protocol(&self) -> Result<SessionProtocol> {
+        unsafe {
+            query(self.0, WTSClientProtocolType, |buffer| match *(buffer as *const u16) {
+                0 => SessionProtocol::Console,
+                1 => SessionProtocol::Ica,
+                2 => SessionProtocol::Rdp,
+                value => SessionProtocol::Other(value),
+            })
+        }
+    }
+
+    /// Returns `true` if the session is connected remotely rather than through the physical console.
+    pub fn is_remote(&self) -> Result<bool> {
+        self.protocol().map(|protocol| protocol != SessionProtocol::Console)
+    }
+
+    /// Logs off the session, waiting for the logoff to complete if `wait` is `true`.
+    pub fn logoff(&self, wait: bool) -> Result<()> {
+        unsafe { WTSLogoffSession(WTS_CURRENT_SERVER_HANDLE, self.0, wait) }
+    }
+
+    /// Disconnects the user from the session without logging off, leaving their apps running.
+    pub fn disconnect(&self, wait: bool) -> Result<()> {
+        unsafe { WTSDisconnectSession(WTS_CURRENT_SERVER_HANDLE, self.0, wait) }
+    }
+
+    /// Displays a message box on the session's desktop and waits for the user's response.
+    ///
+    /// The style is a `MESSAGEBOX_STYLE` such as `MB_OK | MB_ICONWARNING`. If the timeout elapses before the user
+    /// responds, the message box is closed and `IDTIMEOUT` is returned.
+    #[cfg(feature = "Win32_UI_WindowsAndMessaging")]
+    pub fn send_message(&self, title: &str, message: &str, style: crate::Win32::UI::WindowsAndMessaging::MESSAGEBOX_STYLE, timeout: Option<core::time::Duration>) -> Result<crate::Win32::UI::WindowsAndMessaging::MESSAGEBOX_RESULT> {
+        let title = windows_core::HSTRING::from(title);
+        let message = windows_core::HSTRING::from(message);
+        let timeout = timeout.map_or(0, |timeout| timeout.as_secs().clamp(1, u32::MAX as u64) as u32);
+        let mut response = Default::default();
+
+        // The lengths are in bytes rather than characters.
+        unsafe { WTSSendMessageW(WTS_CURRENT_SERVER_HANDLE, self.0, &title, title.len() as u32 * 2, &message, message.len() as u32 * 2, style, timeout, &mut response, true)? };
+        Ok(response)
+    }
+
+    fn query_string(&self, class: WTS_INFO_CLASS) -> Result<String> {
+        unsafe { query(self.0, class, |buffer| PWSTR(buffer as _).to_string().unwrap_or_default()) }
+    }
+}
+
+/// A session reported by `Session::enumerate`.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct SessionInfo {
+    /// The session.
+    pub session: Session,
+    /// The name of the session's window station, such as `Console` or `RDP-Tcp#3`.
+    pub station_name: String,
+    /// The connection state of the session, such as `WTSActive` or `WTSDisconnected`.
+    pub state: WTS_CONNECTSTATE_CLASS,
+}
+
+/// The protocol through which a session is connected, reported by `Session::protocol`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SessionProtocol {
+    /// The physical console.
+    Console,
+    /// The legacy Citrix ICA protocol.
+    Ica,
+    /// The Remote Desktop Protocol.
+    Rdp,
+    /// A protocol with the given value that isn't otherwise known.
+    Other(u16),
+}
+
+// Queries information about a session, passing the returned buffer to `f` before it is freed.
+unsafe fn query<T>(id: u32, class: WTS_INFO_CLASS, f: impl FnOnce(*const u8) -> T) -> Result<T> {
+    let mut buffer = PWSTR::null();
+    let mut len = 0;
+    WTSQuerySessionInformationW(WTS_CURRENT_SERVER_HANDLE, id, class, &mut buffer, &mut len)?;
+
+    if buffer.is_null() {
+        return Err(E_UNEXPECTED.into());
+    }
+
+    let value = f(buffer.0 as _);
+    WTSFreeMemory(buffer.0 as _);
+    Ok(value)
+}
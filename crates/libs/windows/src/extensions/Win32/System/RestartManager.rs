@@ -0,0 +1,167 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::RestartManager::*;
+use std::path::Path;
+use windows_core::{Result, HSTRING, PCWSTR, PWSTR};
+
+/// A Restart Manager session, used to find the processes and services that are using files and to
+/// shut them down and restart them so that the files can be replaced.
+///
+/// ```rust,ignore
+/// let session = RestartSession::new()?;
+/// session.register_files(["C:\\app\\app.exe"])?;
+///
+/// for process in session.processes()? {
+///     println!("{} ({}) is using the file", process.app_name, process.id());
+/// }
+///
+/// session.shutdown(RM_SHUTDOWN_TYPE(0))?;
+/// // Replace the file...
+/// session.restart()?;
+/// ```
+#[derive(Debug)]
+pub struct RestartSession(u32);
+
+impl RestartSession {
+    /// Starts a new session. A user can have at most 64 sessions open at the same time.
+    pub fn new() -> Result<Self> {
+        let mut handle = 0;
+        let mut key = [0; CCH_RM_SESSION_KEY as usize + 1];
+        unsafe { RmStartSession(&mut handle, 0, PWSTR(key.as_mut_ptr())).ok()? };
+        Ok(Self(handle))
+    }
+
+    /// Returns the session handle.
+    pub fn handle(&self) -> u32 {
+        self.0
+    }
+
+    /// Adds files to the set of resources whose users are reported and shut down.
+    pub fn register_files<I, P>(&self, files: I) -> Result<()>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let files: Vec<HSTRING> = files.into_iter().map(|file| file.as_ref().into()).collect();
+        let files: Vec<PCWSTR> = files.iter().map(|file| PCWSTR(file.as_ptr())).collect();
+        unsafe { RmRegisterResources(self.0, Some(&files), None, None).ok() }
+    }
+
+    /// Adds processes to the set of resources that are shut down.
+    pub fn register_processes(&self, processes: &[RM_UNIQUE_PROCESS]) -> Result<()> {
+        unsafe { RmRegisterResources(self.0, None, Some(processes), None).ok() }
+    }
+
+    /// Adds services, by short name, to the set of resources that are shut down.
+    pub fn register_services(&self, services: &[&str]) -> Result<()> {
+        let services: Vec<HSTRING> = services.iter().map(|service| HSTRING::from(*service)).collect();
+        let services: Vec<PCWSTR> = services.iter().map(|service| PCWSTR(service.as_ptr())).collect();
+        unsafe { RmRegisterResources(self.0, None, None, Some(&services)).ok() }
+    }
+
+    /// Returns the processes and services that are using the registered resources.
+    pub fn processes(&self) -> Result<Vec<LockingProcess>> {
+        self.list().map(|(processes, _)| processes)
+    }
+
+    /// Returns the reasons that a restart of the machine would be needed to release the registered
+    /// resources, or `RmRebootReasonNone` if the processes using them can be shut down.
+    pub fn reboot_reasons(&self) -> Result<RM_REBOOT_REASON> {
+        self.list().map(|(_, reasons)| reasons)
+    }
+
+    fn list(&self) -> Result<(Vec<LockingProcess>, RM_REBOOT_REASON)> {
+        let mut buffer: Vec<RM_PROCESS_INFO> = Vec::new();
+
+        loop {
+            let mut needed = 0;
+            let mut len = buffer.len() as u32;
+            let mut reasons = 0;
+            let result = unsafe { RmGetList(self.0, &mut needed, &mut len, (!buffer.is_empty()).then_some(buffer.as_mut_ptr()), &mut reasons) };
+
+            // The list may grow between calls, in which case the buffer is grown again.
+            if result == ERROR_MORE_DATA {
+                buffer.resize(needed as usize, Default::default());
+                continue;
+            }
+
+            result.ok()?;
+            let processes = buffer[..len as usize].iter().map(LockingProcess::from_info).collect();
+            return Ok((processes, RM_REBOOT_REASON(reasons as i32)));
+        }
+    }
+
+    /// Shuts down the processes and services using the registered resources, such as with
+    /// `RmForceShutdown` to shut down apps that don't respond, or `RM_SHUTDOWN_TYPE(0)` otherwise.
+    pub fn shutdown(&self, flags: RM_SHUTDOWN_TYPE) -> Result<()> {
+        unsafe { RmShutdown(self.0, flags.0 as u32, None).ok() }
+    }
+
+    /// Restarts the apps and services that were shut down by `shutdown` and registered for restart.
+    pub fn restart(&self) -> Result<()> {
+        unsafe { RmRestart(self.0, 0, None).ok() }
+    }
+}
+
+impl Drop for RestartSession {
+    fn drop(&mut self) {
+        unsafe {
+            _ = RmEndSession(self.0);
+        }
+    }
+}
+
+/// A process or service that is using a resource registered with a `RestartSession`.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct LockingProcess {
+    /// The process identifier and start time, which together identify the process even if the
+    /// identifier is later reused.
+    pub process: RM_UNIQUE_PROCESS,
+    /// The display name of the app.
+    pub app_name: String,
+    /// The short name of the service, if the process is a service.
+    pub service_name: String,
+    /// The kind of app, such as `RmMainWindow` or `RmService`.
+    pub app_type: RM_APP_TYPE,
+    /// The state of the app, such as `RmStatusRunning`.
+    pub status: RM_APP_STATUS,
+    /// The Terminal Services session in which the process is running.
+    pub session_id: u32,
+    /// `true` if the app can be restarted by `RestartSession::restart`.
+    pub restartable: bool,
+}
+
+impl LockingProcess {
+    /// Returns the process identifier.
+    pub fn id(&self) -> u32 {
+        self.process.dwProcessId
+    }
+
+    fn from_info(info: &RM_PROCESS_INFO) -> Self {
+        Self {
+            process: info.Process,
+            app_name: from_wide(&info.strAppName),
+            service_name: from_wide(&info.strServiceShortName),
+            app_type: info.ApplicationType,
+            status: RM_APP_STATUS(info.AppStatus as i32),
+            session_id: info.TSSessionId,
+            restartable: info.bRestartable.as_bool(),
+        }
+    }
+}
+
+/// Returns the processes and services that are using any of the files.
+pub fn locking_processes<I, P>(files: I) -> Result<Vec<LockingProcess>>
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+{
+    let session = RestartSession::new()?;
+    session.register_files(files)?;
+    session.processes()
+}
+
+fn from_wide(value: &[u16]) -> String {
+    let len = value.iter().position(|c| *c == 0).unwrap_or(value.len());
+    String::from_utf16_lossy(&value[..len])
+}
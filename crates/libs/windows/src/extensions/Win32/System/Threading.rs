@@ -0,0 +1,3 @@
+#[cfg(feature = "Win32_Security")]
+pub mod Process;
+pub mod WaitAsync;
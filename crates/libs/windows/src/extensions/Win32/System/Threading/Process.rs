@@ -0,0 +1,416 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::Threading::*;
+use core::time::Duration;
+use std::collections::BTreeMap;
+use std::ffi::{OsStr, OsString};
+use std::os::windows::ffi::OsStrExt;
+use windows_core::{Owned, Result, PCWSTR, PWSTR};
+
+/// A builder for a process created with `CreateProcessW`.
+///
+/// Like `std::process::Command`, the program is found by searching the application's directory, the current
+/// directory, the system directories, and the `PATH`, and the arguments are quoted so that the new process
+/// receives them unchanged. In addition, the builder supports the attributes of an extended startup information
+/// list:
+///
+/// ```rust,ignore
+/// let process = ProcessBuilder::new("child.exe")
+///     .arg("--pipe")
+///     .inherit_handle(pipe.handle())
+///     .job(job.handle())
+///     .env("MODE", "worker")
+///     .spawn()?;
+///
+/// process.wait(None)?;
+/// ```
+#[derive(Clone)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct ProcessBuilder {
+    program: OsString,
+    args: Vec<OsString>,
+    current_dir: Option<OsString>,
+    env_clear: bool,
+    env: Vec<(OsString, Option<OsString>)>,
+    flags: PROCESS_CREATION_FLAGS,
+    std_handles: Option<[HANDLE; 3]>,
+    inherit: Vec<HANDLE>,
+    parent: Option<HANDLE>,
+    mitigation: Option<u64>,
+    jobs: Vec<HANDLE>,
+    #[cfg(feature = "Win32_System_Console")]
+    pseudo_console: Option<crate::Win32::System::Console::HPCON>,
+}
+
+impl ProcessBuilder {
+    /// Creates a builder for running the given program.
+    pub fn new<T: AsRef<OsStr>>(program: T) -> Self {
+        Self {
+            program: program.as_ref().to_os_string(),
+            args: Vec::new(),
+            current_dir: None,
+            env_clear: false,
+            env: Vec::new(),
+            flags: PROCESS_CREATION_FLAGS(0),
+            std_handles: None,
+            inherit: Vec::new(),
+            parent: None,
+            mitigation: None,
+            jobs: Vec::new(),
+            #[cfg(feature = "Win32_System_Console")]
+            pseudo_console: None,
+        }
+    }
+
+    /// Adds an argument to pass to the program.
+    pub fn arg<T: AsRef<OsStr>>(&mut self, arg: T) -> &mut Self {
+        self.args.push(arg.as_ref().to_os_string());
+        self
+    }
+
+    /// Adds arguments to pass to the program.
+    pub fn args<I: IntoIterator<Item = T>, T: AsRef<OsStr>>(&mut self, args: I) -> &mut Self {
+        self.args.extend(args.into_iter().map(|arg| arg.as_ref().to_os_string()));
+        self
+    }
+
+    /// Sets the working directory of the process, which otherwise inherits the current directory.
+    pub fn current_dir<T: AsRef<OsStr>>(&mut self, dir: T) -> &mut Self {
+        self.current_dir = Some(dir.as_ref().to_os_string());
+        self
+    }
+
+    /// Sets an environment variable for the process, which otherwise inherits the current environment.
+    pub fn env<K: AsRef<OsStr>, V: AsRef<OsStr>>(&mut self, key: K, value: V) -> &mut Self {
+        self.env.push((key.as_ref().to_os_string(), Some(value.as_ref().to_os_string())));
+        self
+    }
+
+    /// Removes an environment variable from the environment of the process.
+    pub fn env_remove<K: AsRef<OsStr>>(&mut self, key: K) -> &mut Self {
+        self.env.push((key.as_ref().to_os_string(), None));
+        self
+    }
+
+    /// Starts the process with an empty environment, apart from variables subsequently set with `env`.
+    pub fn env_clear(&mut self) -> &mut Self {
+        self.env_clear = true;
+        self.env.clear();
+        self
+    }
+
+    /// Adds to the flags passed to `CreateProcessW`, such as `CREATE_SUSPENDED` or `CREATE_NO_WINDOW`.
+    pub fn creation_flags(&mut self, flags: PROCESS_CREATION_FLAGS) -> &mut Self {
+        self.flags |= flags;
+        self
+    }
+
+    /// Sets the standard input, output, and error handles of the process.
+    ///
+    /// The handles are inherited by the process as with `inherit_handle`.
+    pub fn std_handles(&mut self, input: HANDLE, output: HANDLE, error: HANDLE) -> &mut Self {
+        self.std_handles = Some([input, output, error]);
+        self
+    }
+
+    /// Allows the process to inherit the handle.
+    ///
+    /// Only the handles passed to this method and `std_handles` are inherited, which avoids leaking other inheritable
+    /// handles into the process. The handles are only marked as inheritable while the process is created, after which
+    /// handles that weren't already inheritable are restored.
+    pub fn inherit_handle(&mut self, handle: HANDLE) -> &mut Self {
+        self.inherit.push(handle);
+        self
+    }
+
+    /// Creates the process as a child of another process, which requires the `PROCESS_CREATE_PROCESS` access right.
+    ///
+    /// The process inherits its attributes, including inherited handles, from the parent rather than the caller.
+    pub fn parent_process(&mut self, parent: HANDLE) -> &mut Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Sets the mitigation policies of the process, a combination of the
+    /// `PROCESS_CREATION_MITIGATION_POLICY_*` flags.
+    pub fn mitigation_policy(&mut self, policy: u64) -> &mut Self {
+        self.mitigation = Some(policy);
+        self
+    }
+
+    /// Assigns the process to the job object before it starts running, so that it cannot escape the job.
+    pub fn job(&mut self, job: HANDLE) -> &mut Self {
+        self.jobs.push(job);
+        self
+    }
+
+    /// Attaches the process to a pseudo console rather than to the caller's console.
+    #[cfg(feature = "Win32_System_Console")]
+    pub fn pseudo_console(&mut self, console: crate::Win32::System::Console::HPCON) -> &mut Self {
+        self.pseudo_console = Some(console);
+        self
+    }
+
+    /// Creates the process.
+    pub fn spawn(&self) -> Result<Process> {
+        let mut command_line = command_line(&self.program, &self.args);
+        let environment = self.environment();
+        let current_dir = self.current_dir.as_ref().map(|dir| wide(dir));
+
+        let mut inherit = self.inherit.clone();
+        inherit.extend(self.std_handles.iter().flatten().filter(|handle| !handle.is_invalid()));
+        inherit.sort_by_key(|handle| handle.0 as usize);
+        inherit.dedup();
+
+        let _inheritable = Inheritable::new(&inherit)?;
+        let mut attributes = AttributeList::new(self.attribute_count(!inherit.is_empty()))?;
+
+        unsafe {
+            if !inherit.is_empty() {
+                attributes.update(PROC_THREAD_ATTRIBUTE_HANDLE_LIST, inherit.as_ptr(), core::mem::size_of_val(inherit.as_slice()))?;
+            }
+
+            if let Some(parent) = &self.parent {
+                attributes.update(PROC_THREAD_ATTRIBUTE_PARENT_PROCESS, parent, core::mem::size_of::<HANDLE>())?;
+            }
+
+            if let Some(mitigation) = &self.mitigation {
+                attributes.update(PROC_THREAD_ATTRIBUTE_MITIGATION_POLICY, mitigation, core::mem::size_of::<u64>())?;
+            }
+
+            if !self.jobs.is_empty() {
+                attributes.update(PROC_THREAD_ATTRIBUTE_JOB_LIST, self.jobs.as_ptr(), core::mem::size_of_val(self.jobs.as_slice()))?;
+            }
+
+            #[cfg(feature = "Win32_System_Console")]
+            if let Some(console) = &self.pseudo_console {
+                attributes.update(PROC_THREAD_ATTRIBUTE_PSEUDOCONSOLE, console.0 as *const core::ffi::c_void, core::mem::size_of::<crate::Win32::System::Console::HPCON>())?;
+            }
+
+            let mut startup = STARTUPINFOEXW::default();
+            startup.StartupInfo.cb = core::mem::size_of::<STARTUPINFOEXW>() as u32;
+            startup.lpAttributeList = attributes.as_raw();
+
+            if let Some([input, output, error]) = self.std_handles {
+                startup.StartupInfo.dwFlags |= STARTF_USESTDHANDLES;
+                startup.StartupInfo.hStdInput = input;
+                startup.StartupInfo.hStdOutput = output;
+                startup.StartupInfo.hStdError = error;
+            }
+
+            let mut info = PROCESS_INFORMATION::default();
+
+            CreateProcessW(PCWSTR::null(), PWSTR(command_line.as_mut_ptr()), None, None, !inherit.is_empty(), self.flags | EXTENDED_STARTUPINFO_PRESENT | CREATE_UNICODE_ENVIRONMENT, environment.as_ref().map(|environment| environment.as_ptr() as *const core::ffi::c_void), current_dir.as_ref().map_or(PCWSTR::null(), |dir| PCWSTR(dir.as_ptr())), &startup.StartupInfo, &mut info)?;
+
+            Ok(Process { process: Owned::new(info.hProcess), thread: Owned::new(info.hThread), id: info.dwProcessId, thread_id: info.dwThreadId })
+        }
+    }
+
+    fn attribute_count(&self, inherit: bool) -> u32 {
+        #[cfg(feature = "Win32_System_Console")]
+        let console = self.pseudo_console.is_some();
+        #[cfg(not(feature = "Win32_System_Console"))]
+        let console = false;
+
+        [inherit, self.parent.is_some(), self.mitigation.is_some(), !self.jobs.is_empty(), console].into_iter().filter(|present| *present).count() as u32
+    }
+
+    // Returns `None` if the process inherits the current environment unchanged.
+    fn environment(&self) -> Option<Vec<u16>> {
+        if !self.env_clear && self.env.is_empty() {
+            return None;
+        }
+
+        // Variable names are case-insensitive and the block must be sorted by name.
+        let mut vars = BTreeMap::new();
+
+        if !self.env_clear {
+            for (key, value) in std::env::vars_os() {
+                vars.insert(key.to_string_lossy().to_uppercase(), (key, value));
+            }
+        }
+
+        for (key, value) in &self.env {
+            let name = key.to_string_lossy().to_uppercase();
+
+            match value {
+                Some(value) => vars.insert(name, (key.clone(), value.clone())),
+                None => vars.remove(&name),
+            };
+        }
+
+        let mut block = Vec::new();
+
+        for (key, value) in vars.values() {
+            block.extend(key.encode_wide());
+            block.push(b'=' as u16);
+            block.extend(value.encode_wide());
+            block.push(0);
+        }
+
+        // An empty block still needs two terminating characters.
+        if block.is_empty() {
+            block.push(0);
+        }
+
+        block.push(0);
+        Some(block)
+    }
+}
+
+// Quotes the program and arguments so that `CommandLineToArgvW` and the C runtime recover them unchanged.
+fn command_line(program: &OsStr, args: &[OsString]) -> Vec<u16> {
+    let mut line = vec![b'"' as u16];
+    line.extend(program.encode_wide());
+    line.push(b'"' as u16);
+
+    for arg in args {
+        line.push(b' ' as u16);
+        let quote = arg.is_empty() || arg.encode_wide().any(|c| c == b' ' as u16 || c == b'\t' as u16);
+
+        if quote {
+            line.push(b'"' as u16);
+        }
+
+        let mut backslashes = 0;
+
+        for c in arg.encode_wide() {
+            if c == b'\\' as u16 {
+                backslashes += 1;
+            } else {
+                if c == b'"' as u16 {
+                    line.extend(core::iter::repeat(b'\\' as u16).take(backslashes + 1));
+                }
+
+                backslashes = 0;
+            }
+
+            line.push(c);
+        }
+
+        if quote {
+            line.extend(core::iter::repeat(b'\\' as u16).take(backslashes));
+            line.push(b'"' as u16);
+        }
+    }
+
+    line.push(0);
+    line
+}
+
+fn wide(value: &OsStr) -> Vec<u16> {
+    value.encode_wide().chain(core::iter::once(0)).collect()
+}
+
+struct AttributeList(Vec<usize>);
+
+impl AttributeList {
+    fn new(count: u32) -> Result<Self> {
+        let mut size = 0;
+        _ = unsafe { InitializeProcThreadAttributeList(LPPROC_THREAD_ATTRIBUTE_LIST::default(), count, 0, &mut size) };
+
+        let mut list = Self(vec![0; size.div_ceil(core::mem::size_of::<usize>())]);
+        unsafe { InitializeProcThreadAttributeList(list.as_raw(), count, 0, &mut size)? };
+        Ok(list)
+    }
+
+    fn as_raw(&mut self) -> LPPROC_THREAD_ATTRIBUTE_LIST {
+        LPPROC_THREAD_ATTRIBUTE_LIST(self.0.as_mut_ptr() as _)
+    }
+
+    // The value must remain valid until the list is dropped.
+    unsafe fn update<T>(&mut self, attribute: u32, value: *const T, size: usize) -> Result<()> {
+        UpdateProcThreadAttribute(self.as_raw(), 0, attribute as usize, Some(value as _), size, None, None)
+    }
+}
+
+impl Drop for AttributeList {
+    fn drop(&mut self) {
+        unsafe { DeleteProcThreadAttributeList(self.as_raw()) };
+    }
+}
+
+// Marks handles as inheritable, and clears the flag again on drop for those that weren't already inheritable, so
+// that processes created later by other threads don't inherit them.
+struct Inheritable(Vec<HANDLE>);
+
+impl Inheritable {
+    fn new(handles: &[HANDLE]) -> Result<Self> {
+        let mut inheritable = Self(Vec::new());
+
+        for handle in handles {
+            let mut flags = 0;
+            unsafe { GetHandleInformation(*handle, &mut flags)? };
+
+            if flags & HANDLE_FLAG_INHERIT.0 == 0 {
+                unsafe { SetHandleInformation(*handle, HANDLE_FLAG_INHERIT.0, HANDLE_FLAG_INHERIT)? };
+                inheritable.0.push(*handle);
+            }
+        }
+
+        Ok(inheritable)
+    }
+}
+
+impl Drop for Inheritable {
+    fn drop(&mut self) {
+        for handle in &self.0 {
+            _ = unsafe { SetHandleInformation(*handle, HANDLE_FLAG_INHERIT.0, HANDLE_FLAGS(0)) };
+        }
+    }
+}
+
+/// A process created by `ProcessBuilder`, along with its primary thread.
+///
+/// The handles are closed when this is dropped, which does not affect the process.
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct Process {
+    /// The process handle.
+    pub process: Owned<HANDLE>,
+    /// The handle of the process's primary thread.
+    pub thread: Owned<HANDLE>,
+    /// The process identifier.
+    pub id: u32,
+    /// The identifier of the process's primary thread.
+    pub thread_id: u32,
+}
+
+unsafe impl Send for Process {}
+unsafe impl Sync for Process {}
+
+impl Process {
+    /// Resumes the primary thread of a process created with `CREATE_SUSPENDED`.
+    pub fn resume(&self) -> Result<()> {
+        if unsafe { ResumeThread(*self.thread) } == u32::MAX {
+            Err(windows_core::Error::from_win32())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Waits for the process to exit, returning its exit code, or `None` if it is still running after the timeout.
+    ///
+    /// A timeout of `None` waits indefinitely.
+    pub fn wait(&self, timeout: Option<Duration>) -> Result<Option<u32>> {
+        let timeout = timeout.map_or(INFINITE, |timeout| timeout.as_millis().min(INFINITE as u128 - 1) as u32);
+
+        match unsafe { WaitForSingleObject(*self.process, timeout) } {
+            WAIT_OBJECT_0 => self.exit_code(),
+            WAIT_TIMEOUT => Ok(None),
+            _ => Err(windows_core::Error::from_win32()),
+        }
+    }
+
+    /// Returns the exit code of the process, or `None` if it is still running.
+    pub fn exit_code(&self) -> Result<Option<u32>> {
+        let mut code = 0;
+        unsafe { GetExitCodeProcess(*self.process, &mut code)? };
+
+        // A process may also exit with the `STILL_ACTIVE` code, but this is discouraged for exactly this reason.
+        Ok((code != STILL_ACTIVE.0 as u32).then_some(code))
+    }
+
+    /// Terminates the process with the given exit code.
+    pub fn kill(&self, exit_code: u32) -> Result<()> {
+        unsafe { TerminateProcess(*self.process, exit_code) }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::Threading::*;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+use std::sync::{Arc, Mutex};
+use windows_core::Result;
+
+/// Returns a future that completes when the kernel object is signaled.
+///
+/// The wait is registered with the thread pool when the future is first polled, so no thread is blocked
+/// while waiting. This works with events, processes, threads, semaphores, and waitable timers. As with
+/// any wait, a successful wait on a synchronization object such as an auto-reset event or semaphore
+/// changes its state. Mutexes are acquired by the thread pool thread rather than the awaiting thread.
+///
+/// # Safety
+///
+/// The handle must remain valid until the future completes or is dropped.
+pub unsafe fn wait_async(handle: HANDLE) -> WaitFuture {
+    WaitFuture { handle, timeout: None, wait: None }
+}
+
+/// A future that completes when a kernel object is signaled.
+///
+/// This is produced by `wait_async`.
+pub struct WaitFuture {
+    handle: HANDLE,
+    timeout: Option<Duration>,
+    wait: Option<Wait>,
+}
+
+struct Wait {
+    handle: PTP_WAIT,
+    state: Arc<Mutex<WaitState>>,
+}
+
+#[derive(Default)]
+struct WaitState {
+    result: Option<u32>,
+    waker: Option<Waker>,
+}
+
+impl WaitFuture {
+    /// Completes with an `ERROR_TIMEOUT` error if the object is not signaled within the given duration.
+    ///
+    /// The duration is measured from the time the future is first polled.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = Some(duration);
+        self
+    }
+}
+
+impl Wait {
+    fn new(handle: HANDLE, timeout: Option<Duration>) -> Result<Self> {
+        let state = Arc::new(Mutex::new(WaitState::default()));
+
+        unsafe {
+            let wait = CreateThreadpoolWait(Some(Self::callback), Some(Arc::as_ptr(&state) as *mut _), None)?;
+
+            // A negative due time is relative to the current time, in 100-nanosecond intervals.
+            let timeout = timeout.map(|timeout| {
+                let due = -((timeout.as_nanos() / 100).min(i64::MAX as u128) as i64);
+                FILETIME { dwLowDateTime: due as u32, dwHighDateTime: (due >> 32) as u32 }
+            });
+
+            SetThreadpoolWait(wait, handle, timeout.as_ref().map(|timeout| timeout as *const _));
+            Ok(Self { handle: wait, state })
+        }
+    }
+
+    unsafe extern "system" fn callback(_instance: PTP_CALLBACK_INSTANCE, context: *mut core::ffi::c_void, _wait: PTP_WAIT, result: u32) {
+        // Safe because the wait is closed, waiting for callbacks, before the state is released.
+        let state = &*(context as *const Mutex<WaitState>);
+        let mut state = state.lock().unwrap();
+        state.result = Some(result);
+
+        if let Some(waker) = state.waker.take() {
+            drop(state);
+            waker.wake();
+        }
+    }
+}
+
+impl Drop for Wait {
+    fn drop(&mut self) {
+        unsafe {
+            SetThreadpoolWait(self.handle, None, None);
+            WaitForThreadpoolWaitCallbacks(self.handle, true);
+            CloseThreadpoolWait(self.handle);
+        }
+    }
+}
+
+unsafe impl Send for WaitFuture {}
+unsafe impl Sync for WaitFuture {}
+
+impl Future for WaitFuture {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+
+        let wait = match &this.wait {
+            Some(wait) => wait,
+            None => this.wait.insert(Wait::new(this.handle, this.timeout)?),
+        };
+
+        let mut state = wait.state.lock().unwrap();
+
+        match state.result {
+            Some(result) if result == WAIT_TIMEOUT.0 => Poll::Ready(Err(windows_core::HRESULT::from_win32(windows_core::imp::ERROR_TIMEOUT).into())),
+            Some(_) => Poll::Ready(Ok(())),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
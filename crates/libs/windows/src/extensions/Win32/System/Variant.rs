@@ -0,0 +1,140 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::Variant::*;
+
+/// An OLE Automation date, as stored in a `VARIANT` of type `VT_DATE`.
+///
+/// The value counts days since midnight on December 30, 1899, with the fractional part giving the time of day.
+/// For dates before then, the fractional part is still added to the day rather than subtracted, so that `-1.25`
+/// is 6:00 AM on December 29, 1899.
+///
+/// ```rust,ignore
+/// let date = OleDate::from_system_time(&SYSTEMTIME { wYear: 2024, wMonth: 1, wDay: 1, ..Default::default() })?;
+/// assert_eq!(date.0, 45292.0);
+/// ```
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct OleDate(pub f64);
+
+impl OleDate {
+    /// The earliest date that OLE Automation supports, January 1, 100.
+    pub const MIN: Self = Self(-657_434.0);
+
+    /// The latest date that OLE Automation supports, the last moment of December 31, 9999.
+    pub const MAX: Self = Self(2_958_465.999_999_988);
+
+    /// Converts a `SYSTEMTIME`, including its milliseconds.
+    pub fn from_system_time(time: &SYSTEMTIME) -> windows_core::Result<Self> {
+        let mut date = Self::default();
+
+        if unsafe { SystemTimeToVariantTime(time, &mut date.0) } == 0 {
+            return Err(E_INVALIDARG.into());
+        }
+
+        // `SystemTimeToVariantTime` ignores the milliseconds.
+        Ok(date.with_time_of_day(date.time_of_day() + time.wMilliseconds as f64 / MILLISECONDS_PER_DAY))
+    }
+
+    /// Converts to a `SYSTEMTIME`, rounded to the nearest millisecond.
+    pub fn to_system_time(&self) -> windows_core::Result<SYSTEMTIME> {
+        let (days, milliseconds) = self.split()?;
+        let mut time = SYSTEMTIME::default();
+
+        if unsafe { VariantTimeToSystemTime(days as f64, &mut time) } == 0 {
+            return Err(E_INVALIDARG.into());
+        }
+
+        time.wHour = (milliseconds / 3_600_000) as u16;
+        time.wMinute = (milliseconds / 60_000 % 60) as u16;
+        time.wSecond = (milliseconds / 1_000 % 60) as u16;
+        time.wMilliseconds = (milliseconds % 1_000) as u16;
+        Ok(time)
+    }
+
+    // Returns the whole days and the time of day in milliseconds, rounded to the nearest millisecond.
+    fn split(&self) -> windows_core::Result<(i64, i64)> {
+        if !(Self::MIN.0..Self::MAX.0 + 1.0 / MILLISECONDS_PER_DAY).contains(&self.0) {
+            return Err(DISP_E_OVERFLOW.into());
+        }
+
+        let mut days = self.0 as i64;
+        let mut milliseconds = (self.time_of_day() * MILLISECONDS_PER_DAY + 0.5) as i64;
+
+        if milliseconds == MILLISECONDS_PER_DAY as i64 {
+            milliseconds = 0;
+            days += 1;
+        }
+
+        Ok((days, milliseconds))
+    }
+
+    fn time_of_day(&self) -> f64 {
+        let fraction = self.0 - self.0 as i64 as f64;
+
+        if fraction < 0.0 {
+            -fraction
+        } else {
+            fraction
+        }
+    }
+
+    fn with_time_of_day(&self, time_of_day: f64) -> Self {
+        let days = self.0 as i64 as f64;
+
+        if self.0 < 0.0 {
+            Self(days - time_of_day)
+        } else {
+            Self(days + time_of_day)
+        }
+    }
+}
+
+const MILLISECONDS_PER_DAY: f64 = 86_400_000.0;
+
+impl From<f64> for OleDate {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<OleDate> for f64 {
+    fn from(value: OleDate) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "time")]
+impl OleDate {
+    fn epoch() -> time::PrimitiveDateTime {
+        time::Date::from_calendar_date(1899, time::Month::December, 30).unwrap().midnight()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<OleDate> for time::PrimitiveDateTime {
+    type Error = windows_core::Error;
+    /// Converts the date, rounded to the nearest millisecond.
+    fn try_from(value: OleDate) -> windows_core::Result<Self> {
+        let (days, milliseconds) = value.split()?;
+        let duration = time::Duration::days(days) + time::Duration::milliseconds(milliseconds);
+        OleDate::epoch().checked_add(duration).ok_or_else(|| DISP_E_OVERFLOW.into())
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::PrimitiveDateTime> for OleDate {
+    type Error = windows_core::Error;
+    /// Converts the date, truncated to the millisecond.
+    fn try_from(value: time::PrimitiveDateTime) -> windows_core::Result<Self> {
+        let milliseconds = (value - Self::epoch()).whole_milliseconds();
+        let days = milliseconds.div_euclid(MILLISECONDS_PER_DAY as i128);
+        let time_of_day = milliseconds.rem_euclid(MILLISECONDS_PER_DAY as i128) as f64 / MILLISECONDS_PER_DAY;
+
+        let date = if days < 0 { Self(days as f64 - time_of_day) } else { Self(days as f64 + time_of_day) };
+
+        if date < Self::MIN || date > Self::MAX {
+            Err(DISP_E_OVERFLOW.into())
+        } else {
+            Ok(date)
+        }
+    }
+}
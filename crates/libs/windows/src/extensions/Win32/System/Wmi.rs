@@ -0,0 +1,303 @@
+use crate::Win32::System::Com::*;
+use crate::Win32::System::Wmi::*;
+use core::time::Duration;
+use windows_core::{Error, IUnknown, Interface, Result, BSTR, HSTRING, PCWSTR, VARIANT};
+
+// The variant type that WMI uses for properties without a value.
+const VT_NULL: u16 = 1;
+
+// Lets COM choose the authentication and authorization services, as `RPC_C_AUTHN_DEFAULT` and `RPC_C_AUTHZ_DEFAULT` do.
+const RPC_C_DEFAULT: u32 = u32::MAX;
+
+/// A connection to a WMI namespace, through which WQL queries are run.
+///
+/// COM must be initialized on the calling thread before connecting:
+///
+/// ```rust,ignore
+/// let wmi = WmiConnection::new()?;
+///
+/// for process in wmi.query("SELECT Name, ProcessId FROM Win32_Process")? {
+///     let process = process?;
+///     println!("{:?} {:?}", process.get::<BSTR>("Name")?, process.get::<u32>("ProcessId")?);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct WmiConnection(IWbemServices);
+
+impl WmiConnection {
+    /// Connects to the `ROOT\CIMV2` namespace on the local computer.
+    pub fn new() -> Result<Self> {
+        Self::connect(r"ROOT\CIMV2")
+    }
+
+    /// Connects to a namespace, such as `ROOT\StandardCimv2` or `\\server\ROOT\CIMV2`, as the current user.
+    pub fn connect(namespace: &str) -> Result<Self> {
+        unsafe {
+            let locator: IWbemLocator = CoCreateInstance(&WbemLocator, None, CLSCTX_INPROC_SERVER)?;
+            let services = locator.ConnectServer(&BSTR::from(namespace), &BSTR::new(), &BSTR::new(), &BSTR::new(), 0, &BSTR::new(), None)?;
+
+            // Many providers act on behalf of the caller, which fails unless the caller allows impersonation.
+            CoSetProxyBlanket(&services, RPC_C_DEFAULT, RPC_C_DEFAULT, PCWSTR::null(), RPC_C_AUTHN_LEVEL_CALL, RPC_C_IMP_LEVEL_IMPERSONATE, None, EOAC_NONE)?;
+            Ok(Self(services))
+        }
+    }
+
+    /// Returns the underlying `IWbemServices` interface.
+    pub fn services(&self) -> &IWbemServices {
+        &self.0
+    }
+
+    /// Runs a WQL query, returning an iterator over the matching objects.
+    ///
+    /// Objects are retrieved as the iterator advances, rather than all at once.
+    pub fn query(&self, wql: &str) -> Result<WmiObjects> {
+        let enumerator = unsafe { self.0.ExecQuery(&BSTR::from("WQL"), &BSTR::from(wql), semisynchronous(), None)? };
+        Ok(WmiObjects { enumerator, done: false })
+    }
+
+    /// Retrieves a single object by path, such as `Win32_Process.Handle="4"`.
+    pub fn get(&self, path: &str) -> Result<WmiObject> {
+        let mut object = None;
+        unsafe { self.0.GetObject(&BSTR::from(path), WBEM_FLAG_RETURN_WBEM_COMPLETE, None, Some(&mut object), None)? };
+        object.map(WmiObject).ok_or_else(|| Error::from(windows_core::imp::E_POINTER))
+    }
+
+    /// Runs a WQL event query, such as `SELECT * FROM __InstanceCreationEvent WITHIN 1 WHERE TargetInstance ISA
+    /// 'Win32_Process'`, returning the notifications as they arrive.
+    pub fn notifications(&self, wql: &str) -> Result<WmiNotifications> {
+        unsafe { Ok(WmiNotifications(self.0.ExecNotificationQuery(&BSTR::from("WQL"), &BSTR::from(wql), semisynchronous(), None)?)) }
+    }
+
+    /// Runs a WQL event query asynchronously, returning a stream that is notified as events arrive.
+    ///
+    /// The subscription is cancelled when the stream is dropped.
+    #[cfg(feature = "implement")]
+    pub fn subscribe(&self, wql: &str) -> Result<WmiEventStream> {
+        let state = std::sync::Arc::new(std::sync::Mutex::new(SinkState::default()));
+        let sink: IWbemObjectSink = Sink(state.clone()).into();
+        unsafe { self.0.ExecNotificationQueryAsync(&BSTR::from("WQL"), &BSTR::from(wql), WBEM_GENERIC_FLAG_TYPE(0), None, &sink)? };
+        Ok(WmiEventStream { services: self.0.clone(), sink, state })
+    }
+}
+
+// Semisynchronous calls return immediately and retrieve results on demand, which WMI recommends over
+// synchronous calls and requires for event queries.
+fn semisynchronous() -> WBEM_GENERIC_FLAG_TYPE {
+    WBEM_GENERIC_FLAG_TYPE(WBEM_FLAG_RETURN_IMMEDIATELY.0 | WBEM_FLAG_FORWARD_ONLY.0)
+}
+
+/// An object returned by WMI, such as an instance of a class or an event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WmiObject(IWbemClassObject);
+
+impl WmiObject {
+    /// Returns the underlying `IWbemClassObject` interface.
+    pub fn object(&self) -> &IWbemClassObject {
+        &self.0
+    }
+
+    /// Returns the value of a property, which is empty or `VT_NULL` if the property has no value.
+    pub fn property(&self, name: &str) -> Result<VARIANT> {
+        let mut value = VARIANT::new();
+        unsafe { self.0.Get(&HSTRING::from(name), 0, &mut value, None, None)? };
+        Ok(value)
+    }
+
+    /// Returns the value of a property converted to `T`, or `None` if the property has no value.
+    pub fn get<T: for<'a> TryFrom<&'a VARIANT, Error = Error>>(&self, name: &str) -> Result<Option<T>> {
+        let value = self.property(name)?;
+
+        if value.is_empty() || unsafe { value.as_raw().Anonymous.Anonymous.vt } == VT_NULL {
+            Ok(None)
+        } else {
+            T::try_from(&value).map(Some)
+        }
+    }
+
+    /// Returns the value of a property that holds an embedded object, such as the `TargetInstance` of an
+    /// `__InstanceCreationEvent`.
+    pub fn get_object(&self, name: &str) -> Result<Option<WmiObject>> {
+        match self.get::<IUnknown>(name)? {
+            Some(object) => Ok(Some(WmiObject(object.cast()?))),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the name of the object's class.
+    pub fn class_name(&self) -> Result<String> {
+        Ok(self.get::<BSTR>("__CLASS")?.map(|name| name.to_string()).unwrap_or_default())
+    }
+
+    /// Returns the names of the object's properties, excluding system properties such as `__CLASS`.
+    pub fn names(&self) -> Result<Vec<String>> {
+        let mut names = Vec::new();
+
+        unsafe {
+            self.0.BeginEnumeration(WBEM_FLAG_NONSYSTEM_ONLY.0)?;
+
+            // The enumeration ends with `WBEM_S_NO_MORE_DATA`, which is a success code that returns no name.
+            loop {
+                let mut name = BSTR::new();
+                self.0.Next(0, &mut name, core::ptr::null_mut(), core::ptr::null_mut(), core::ptr::null_mut())?;
+
+                if name.is_empty() {
+                    break;
+                }
+
+                names.push(name.to_string());
+            }
+
+            self.0.EndEnumeration()?;
+        }
+
+        Ok(names)
+    }
+}
+
+/// An iterator over the objects returned by `WmiConnection::query`.
+///
+/// Errors in the query, such as an unknown class, are reported by the first call to `next`, after which the
+/// iterator ends.
+pub struct WmiObjects {
+    enumerator: IEnumWbemClassObject,
+    done: bool,
+}
+
+impl Iterator for WmiObjects {
+    type Item = Result<WmiObject>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut objects = [None];
+        let mut returned = 0;
+        let result = unsafe { self.enumerator.Next(WBEM_INFINITE, &mut objects, &mut returned) };
+
+        if result.is_err() {
+            self.done = true;
+            return Some(Err(result.into()));
+        }
+
+        let object = objects[0].take().map(|object| Ok(WmiObject(object)));
+        self.done = object.is_none();
+        object
+    }
+}
+
+/// The notifications returned by `WmiConnection::notifications`.
+pub struct WmiNotifications(IEnumWbemClassObject);
+
+impl WmiNotifications {
+    /// Waits for the next notification, returning `None` if the timeout elapses first.
+    pub fn next(&self, timeout: Option<Duration>) -> Result<Option<WmiObject>> {
+        let timeout = timeout.map_or(WBEM_INFINITE, |timeout| timeout.as_millis().min(i32::MAX as u128) as i32);
+        let mut objects = [None];
+        let mut returned = 0;
+        let result = unsafe { self.0.Next(timeout, &mut objects, &mut returned) };
+
+        if result.is_err() {
+            return Err(result.into());
+        }
+
+        Ok(objects[0].take().map(WmiObject))
+    }
+}
+
+#[cfg(feature = "implement")]
+#[derive(Default)]
+struct SinkState {
+    values: std::collections::VecDeque<Result<WmiObject>>,
+    waker: Option<core::task::Waker>,
+    done: bool,
+}
+
+#[cfg(feature = "implement")]
+#[windows_core::implement(IWbemObjectSink)]
+struct Sink(std::sync::Arc<std::sync::Mutex<SinkState>>);
+
+#[cfg(feature = "implement")]
+impl Sink {
+    fn update<F: FnOnce(&mut SinkState)>(&self, f: F) {
+        let mut state = self.0.lock().unwrap();
+        f(&mut state);
+
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+#[cfg(feature = "implement")]
+impl IWbemObjectSink_Impl for Sink_Impl {
+    fn Indicate(&self, count: i32, objects: *const Option<IWbemClassObject>) -> Result<()> {
+        let objects = unsafe { core::slice::from_raw_parts(objects, count as usize) };
+        self.update(|state| state.values.extend(objects.iter().flatten().map(|object| Ok(WmiObject(object.clone())))));
+        Ok(())
+    }
+
+    fn SetStatus(&self, flags: i32, result: windows_core::HRESULT, _: &BSTR, _: Option<&IWbemClassObject>) -> Result<()> {
+        if flags == WBEM_STATUS_COMPLETE.0 {
+            self.update(|state| {
+                // Cancelling the subscription completes it with `WBEM_E_CALL_CANCELLED`, which isn't reported.
+                if result.is_err() && result.0 != WBEM_E_CALL_CANCELLED.0 {
+                    state.values.push_back(Err(result.into()));
+                }
+
+                state.done = true;
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// A stream of the events delivered to a subscription created by `WmiConnection::subscribe`.
+///
+/// Events are queued until they are polled. The stream ends if WMI cancels the subscription, after yielding the
+/// reason as an error.
+#[cfg(feature = "implement")]
+pub struct WmiEventStream {
+    services: IWbemServices,
+    sink: IWbemObjectSink,
+    state: std::sync::Arc<std::sync::Mutex<SinkState>>,
+}
+
+#[cfg(feature = "implement")]
+impl WmiEventStream {
+    /// Attempts to pull out the next event.
+    pub fn poll_next(&mut self, cx: &mut core::task::Context<'_>) -> core::task::Poll<Option<Result<WmiObject>>> {
+        let mut state = self.state.lock().unwrap();
+
+        if let Some(value) = state.values.pop_front() {
+            core::task::Poll::Ready(Some(value))
+        } else if state.done {
+            core::task::Poll::Ready(None)
+        } else {
+            state.waker = Some(cx.waker().clone());
+            core::task::Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "implement")]
+impl Drop for WmiEventStream {
+    fn drop(&mut self) {
+        unsafe {
+            _ = self.services.CancelAsyncCall(&self.sink);
+        }
+    }
+}
+
+#[cfg(feature = "implement")]
+impl Unpin for WmiEventStream {}
+
+#[cfg(all(feature = "implement", feature = "futures"))]
+impl futures_core::Stream for WmiEventStream {
+    type Item = Result<WmiObject>;
+
+    fn poll_next(self: core::pin::Pin<&mut Self>, cx: &mut core::task::Context<'_>) -> core::task::Poll<Option<Self::Item>> {
+        self.get_mut().poll_next(cx)
+    }
+}
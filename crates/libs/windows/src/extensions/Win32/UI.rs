@@ -0,0 +1,4 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Com", feature = "Win32_UI_Accessibility", feature = "std"))]
+pub(crate) mod Accessibility;
+#[cfg(all(feature = "Win32_UI_WindowsAndMessaging", feature = "std"))]
+pub(crate) mod WindowsAndMessaging;
@@ -0,0 +1,496 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::System::Com::*;
+use crate::Win32::UI::Accessibility::*;
+use windows_core::{Interface, Result, BSTR, VARIANT};
+
+// Searches that match nothing succeed with a null element, which surfaces as an empty error.
+fn optional<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(error) if error.code().is_ok() => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// A client of UI Automation, through which elements are found and events are handled.
+///
+/// COM must be initialized on the calling thread. UI Automation recommends the multithreaded apartment for clients
+/// that handle events, since handlers are called on threads that UI Automation manages.
+///
+/// ```rust,ignore
+/// let automation = Automation::new()?;
+/// let window = automation.element_from_handle(hwnd)?;
+///
+/// if let Some(button) = window.find_first(TreeScope_Descendants, &Condition::name("OK").and(Condition::control_type(UIA_ButtonControlTypeId)))? {
+///     button.invoke()?;
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Automation(IUIAutomation);
+
+unsafe impl Send for Automation {}
+unsafe impl Sync for Automation {}
+
+impl Automation {
+    /// Creates a UI Automation client.
+    pub fn new() -> Result<Self> {
+        unsafe { CoCreateInstance(&CUIAutomation, None, CLSCTX_INPROC_SERVER).map(Self) }
+    }
+
+    /// Returns the underlying `IUIAutomation` interface.
+    pub fn automation(&self) -> &IUIAutomation {
+        &self.0
+    }
+
+    /// Returns the element that represents the desktop.
+    pub fn root(&self) -> Result<Element> {
+        unsafe { self.0.GetRootElement().map(|element| self.element(element)) }
+    }
+
+    /// Returns the element that has the keyboard focus.
+    pub fn focused(&self) -> Result<Element> {
+        unsafe { self.0.GetFocusedElement().map(|element| self.element(element)) }
+    }
+
+    /// Returns the element that represents a window or control.
+    pub fn element_from_handle(&self, hwnd: HWND) -> Result<Element> {
+        unsafe { self.0.ElementFromHandle(hwnd).map(|element| self.element(element)) }
+    }
+
+    /// Returns the element at a point on the screen.
+    pub fn element_from_point(&self, point: POINT) -> Result<Element> {
+        unsafe { self.0.ElementFromPoint(point).map(|element| self.element(element)) }
+    }
+
+    /// Creates the `IUIAutomationCondition` that a `Condition` describes.
+    pub fn create_condition(&self, condition: &Condition) -> Result<IUIAutomationCondition> {
+        unsafe {
+            match &condition.0 {
+                ConditionKind::True => self.0.CreateTrueCondition(),
+                ConditionKind::Property(id, value, flags) => self.0.CreatePropertyConditionEx(*id, value, *flags),
+                ConditionKind::And(conditions) => self.0.CreateAndConditionFromNativeArray(&self.create_conditions(conditions)?),
+                ConditionKind::Or(conditions) => self.0.CreateOrConditionFromNativeArray(&self.create_conditions(conditions)?),
+                ConditionKind::Not(condition) => self.0.CreateNotCondition(&self.create_condition(condition)?),
+            }
+        }
+    }
+
+    fn create_conditions(&self, conditions: &[Condition]) -> Result<Vec<Option<IUIAutomationCondition>>> {
+        conditions.iter().map(|condition| self.create_condition(condition).map(Some)).collect()
+    }
+
+    fn element(&self, element: IUIAutomationElement) -> Element {
+        Element { automation: self.clone(), element }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+enum ConditionKind {
+    True,
+    Property(UIA_PROPERTY_ID, VARIANT, PropertyConditionFlags),
+    And(Vec<Condition>),
+    Or(Vec<Condition>),
+    Not(Box<Condition>),
+}
+
+/// Describes which elements a search matches, combining property conditions with `and`, `or`, and `!`.
+///
+/// ```rust,ignore
+/// let condition = Condition::control_type(UIA_EditControlTypeId).and(Condition::name("search").ignore_case());
+/// ```
+#[derive(Clone, PartialEq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct Condition(ConditionKind);
+
+impl Condition {
+    /// Matches every element.
+    pub fn always() -> Self {
+        Self(ConditionKind::True)
+    }
+
+    /// Matches elements whose property has the given value.
+    pub fn property<T: Into<VARIANT>>(id: UIA_PROPERTY_ID, value: T) -> Self {
+        Self(ConditionKind::Property(id, value.into(), PropertyConditionFlags_None))
+    }
+
+    /// Matches elements with the given name.
+    pub fn name(name: &str) -> Self {
+        Self::property(UIA_NamePropertyId, name)
+    }
+
+    /// Matches elements with the given automation identifier.
+    pub fn automation_id(id: &str) -> Self {
+        Self::property(UIA_AutomationIdPropertyId, id)
+    }
+
+    /// Matches elements with the given class name.
+    pub fn class_name(name: &str) -> Self {
+        Self::property(UIA_ClassNamePropertyId, name)
+    }
+
+    /// Matches elements of the given control type, such as `UIA_ButtonControlTypeId`.
+    pub fn control_type(control_type: UIA_CONTROLTYPE_ID) -> Self {
+        Self::property(UIA_ControlTypePropertyId, control_type.0)
+    }
+
+    /// Compares a string property without regard to case. This has no effect on other conditions.
+    pub fn ignore_case(mut self) -> Self {
+        if let ConditionKind::Property(_, _, flags) = &mut self.0 {
+            flags.0 |= PropertyConditionFlags_IgnoreCase.0;
+        }
+
+        self
+    }
+
+    /// Matches elements that match both conditions.
+    pub fn and(self, other: Self) -> Self {
+        match self.0 {
+            ConditionKind::And(mut conditions) => {
+                conditions.push(other);
+                Self(ConditionKind::And(conditions))
+            }
+            _ => Self(ConditionKind::And(vec![self, other])),
+        }
+    }
+
+    /// Matches elements that match either condition.
+    pub fn or(self, other: Self) -> Self {
+        match self.0 {
+            ConditionKind::Or(mut conditions) => {
+                conditions.push(other);
+                Self(ConditionKind::Or(conditions))
+            }
+            _ => Self(ConditionKind::Or(vec![self, other])),
+        }
+    }
+}
+
+impl core::ops::Not for Condition {
+    type Output = Self;
+
+    /// Matches elements that don't match the condition.
+    fn not(self) -> Self {
+        Self(ConditionKind::Not(Box::new(self)))
+    }
+}
+
+/// A control pattern interface that can be retrieved from an element with `Element::pattern`.
+pub trait Pattern: Interface {
+    /// The identifier of the pattern, such as `UIA_InvokePatternId`.
+    const ID: UIA_PATTERN_ID;
+}
+
+impl Pattern for IUIAutomationInvokePattern {
+    const ID: UIA_PATTERN_ID = UIA_InvokePatternId;
+}
+
+impl Pattern for IUIAutomationValuePattern {
+    const ID: UIA_PATTERN_ID = UIA_ValuePatternId;
+}
+
+impl Pattern for IUIAutomationTogglePattern {
+    const ID: UIA_PATTERN_ID = UIA_TogglePatternId;
+}
+
+impl Pattern for IUIAutomationExpandCollapsePattern {
+    const ID: UIA_PATTERN_ID = UIA_ExpandCollapsePatternId;
+}
+
+impl Pattern for IUIAutomationSelectionItemPattern {
+    const ID: UIA_PATTERN_ID = UIA_SelectionItemPatternId;
+}
+
+/// An element in the UI Automation tree, such as a window, button, or text box.
+#[derive(Clone, Debug)]
+pub struct Element {
+    automation: Automation,
+    element: IUIAutomationElement,
+}
+
+// UI Automation client objects are free-threaded.
+unsafe impl Send for Element {}
+unsafe impl Sync for Element {}
+
+impl Element {
+    /// Returns the underlying `IUIAutomationElement` interface.
+    pub fn element(&self) -> &IUIAutomationElement {
+        &self.element
+    }
+
+    /// Returns the name of the element.
+    pub fn name(&self) -> Result<String> {
+        unsafe { self.element.CurrentName().map(|name| name.to_string()) }
+    }
+
+    /// Returns the automation identifier of the element.
+    pub fn automation_id(&self) -> Result<String> {
+        unsafe { self.element.CurrentAutomationId().map(|id| id.to_string()) }
+    }
+
+    /// Returns the class name of the element.
+    pub fn class_name(&self) -> Result<String> {
+        unsafe { self.element.CurrentClassName().map(|name| name.to_string()) }
+    }
+
+    /// Returns the control type of the element, such as `UIA_ButtonControlTypeId`.
+    pub fn control_type(&self) -> Result<UIA_CONTROLTYPE_ID> {
+        unsafe { self.element.CurrentControlType() }
+    }
+
+    /// Returns the identifier of the process that owns the element.
+    pub fn process_id(&self) -> Result<u32> {
+        unsafe { self.element.CurrentProcessId().map(|id| id as u32) }
+    }
+
+    /// Returns the window handle of the element, which is null for elements without their own window.
+    pub fn native_window(&self) -> Result<HWND> {
+        unsafe { self.element.CurrentNativeWindowHandle() }
+    }
+
+    /// Returns the bounding rectangle of the element in screen coordinates.
+    pub fn bounding_rectangle(&self) -> Result<RECT> {
+        unsafe { self.element.CurrentBoundingRectangle() }
+    }
+
+    /// Returns whether the element is enabled.
+    pub fn is_enabled(&self) -> Result<bool> {
+        unsafe { self.element.CurrentIsEnabled().map(|enabled| enabled.as_bool()) }
+    }
+
+    /// Returns the value of a property of the element.
+    pub fn property(&self, id: UIA_PROPERTY_ID) -> Result<VARIANT> {
+        unsafe { self.element.GetCurrentPropertyValue(id) }
+    }
+
+    /// Gives the element the keyboard focus.
+    pub fn set_focus(&self) -> Result<()> {
+        unsafe { self.element.SetFocus() }
+    }
+
+    /// Returns the first element within the scope that matches the condition, or `None` if none do.
+    pub fn find_first(&self, scope: TreeScope, condition: &Condition) -> Result<Option<Element>> {
+        let condition = self.automation.create_condition(condition)?;
+        let element = optional(unsafe { self.element.FindFirst(scope, &condition) })?;
+        Ok(element.map(|element| self.automation.element(element)))
+    }
+
+    /// Returns every element within the scope that matches the condition.
+    pub fn find_all(&self, scope: TreeScope, condition: &Condition) -> Result<Vec<Element>> {
+        let condition = self.automation.create_condition(condition)?;
+
+        let Some(elements) = optional(unsafe { self.element.FindAll(scope, &condition) })? else {
+            return Ok(Vec::new());
+        };
+
+        let len = unsafe { elements.Length()? };
+        (0..len).map(|index| unsafe { elements.GetElement(index) }.map(|element| self.automation.element(element))).collect()
+    }
+
+    /// Returns a control pattern of the element, or `None` if the element doesn't support it.
+    pub fn pattern<T: Pattern>(&self) -> Result<Option<T>> {
+        optional(unsafe { self.element.GetCurrentPatternAs::<T>(T::ID) })
+    }
+
+    fn require<T: Pattern>(&self) -> Result<T> {
+        self.pattern()?.ok_or_else(|| windows_core::Error::from(windows_core::HRESULT(UIA_E_NOTSUPPORTED as i32)))
+    }
+
+    /// Invokes the element with the Invoke pattern, such as by clicking a button.
+    pub fn invoke(&self) -> Result<()> {
+        unsafe { self.require::<IUIAutomationInvokePattern>()?.Invoke() }
+    }
+
+    /// Returns the value of the element with the Value pattern, such as the text in an edit control.
+    pub fn value(&self) -> Result<String> {
+        unsafe { self.require::<IUIAutomationValuePattern>()?.CurrentValue().map(|value| value.to_string()) }
+    }
+
+    /// Sets the value of the element with the Value pattern.
+    pub fn set_value(&self, value: &str) -> Result<()> {
+        unsafe { self.require::<IUIAutomationValuePattern>()?.SetValue(&BSTR::from(value)) }
+    }
+
+    /// Cycles the toggle state of the element with the Toggle pattern, such as by checking a check box.
+    pub fn toggle(&self) -> Result<()> {
+        unsafe { self.require::<IUIAutomationTogglePattern>()?.Toggle() }
+    }
+
+    /// Returns the toggle state of the element with the Toggle pattern.
+    pub fn toggle_state(&self) -> Result<ToggleState> {
+        unsafe { self.require::<IUIAutomationTogglePattern>()?.CurrentToggleState() }
+    }
+}
+
+#[cfg(feature = "implement")]
+pub use events::*;
+
+#[cfg(feature = "implement")]
+mod events {
+    use super::*;
+    use core::task::{Context, Poll, Waker};
+    use std::collections::VecDeque;
+    use std::sync::{Arc, Mutex};
+
+    #[windows_core::implement(IUIAutomationEventHandler)]
+    struct AutomationHandler(Automation, Box<dyn Fn(Element, UIA_EVENT_ID) + Send + Sync>);
+
+    impl IUIAutomationEventHandler_Impl for AutomationHandler_Impl {
+        fn HandleAutomationEvent(&self, sender: Option<&IUIAutomationElement>, id: UIA_EVENT_ID) -> Result<()> {
+            if let Some(sender) = sender {
+                (self.1)(self.0.element(sender.clone()), id);
+            }
+
+            Ok(())
+        }
+    }
+
+    #[windows_core::implement(IUIAutomationFocusChangedEventHandler)]
+    struct FocusHandler(Automation, Box<dyn Fn(Element) + Send + Sync>);
+
+    impl IUIAutomationFocusChangedEventHandler_Impl for FocusHandler_Impl {
+        fn HandleFocusChangedEvent(&self, sender: Option<&IUIAutomationElement>) -> Result<()> {
+            if let Some(sender) = sender {
+                (self.1)(self.0.element(sender.clone()));
+            }
+
+            Ok(())
+        }
+    }
+
+    #[windows_core::implement(IUIAutomationPropertyChangedEventHandler)]
+    struct PropertyHandler(Automation, Box<dyn Fn(Element, UIA_PROPERTY_ID, &VARIANT) + Send + Sync>);
+
+    impl IUIAutomationPropertyChangedEventHandler_Impl for PropertyHandler_Impl {
+        fn HandlePropertyChangedEvent(&self, sender: Option<&IUIAutomationElement>, id: UIA_PROPERTY_ID, value: &VARIANT) -> Result<()> {
+            if let Some(sender) = sender {
+                (self.1)(self.0.element(sender.clone()), id, value);
+            }
+
+            Ok(())
+        }
+    }
+
+    enum Registration {
+        Automation(UIA_EVENT_ID, IUIAutomationElement, IUIAutomationEventHandler),
+        Focus(IUIAutomationFocusChangedEventHandler),
+        Property(IUIAutomationElement, IUIAutomationPropertyChangedEventHandler),
+    }
+
+    /// An event handler registered with UI Automation, which is removed when dropped.
+    ///
+    /// Handlers are called on threads that UI Automation manages. Dropping the handler waits for any calls in
+    /// progress to return, so it must not be dropped from within the handler itself.
+    pub struct EventHandler {
+        automation: Automation,
+        registration: Registration,
+    }
+
+    unsafe impl Send for EventHandler {}
+    unsafe impl Sync for EventHandler {}
+
+    impl Drop for EventHandler {
+        fn drop(&mut self) {
+            let automation = &self.automation.0;
+
+            unsafe {
+                _ = match &self.registration {
+                    Registration::Automation(id, element, handler) => automation.RemoveAutomationEventHandler(*id, element, handler),
+                    Registration::Focus(handler) => automation.RemoveFocusChangedEventHandler(handler),
+                    Registration::Property(element, handler) => automation.RemovePropertyChangedEventHandler(element, handler),
+                };
+            }
+        }
+    }
+
+    impl Automation {
+        /// Calls `callback` with the element that raises an event, such as `UIA_Invoke_InvokedEventId` or
+        /// `UIA_Window_WindowOpenedEventId`, within the scope of an element.
+        pub fn on_event<F: Fn(Element, UIA_EVENT_ID) + Send + Sync + 'static>(&self, id: UIA_EVENT_ID, element: &Element, scope: TreeScope, callback: F) -> Result<EventHandler> {
+            let handler: IUIAutomationEventHandler = AutomationHandler(self.clone(), Box::new(callback)).into();
+            unsafe { self.0.AddAutomationEventHandler(id, &element.element, scope, None, &handler)? };
+            Ok(EventHandler { automation: self.clone(), registration: Registration::Automation(id, element.element.clone(), handler) })
+        }
+
+        /// Calls `callback` with each element that receives the keyboard focus.
+        pub fn on_focus_changed<F: Fn(Element) + Send + Sync + 'static>(&self, callback: F) -> Result<EventHandler> {
+            let handler: IUIAutomationFocusChangedEventHandler = FocusHandler(self.clone(), Box::new(callback)).into();
+            unsafe { self.0.AddFocusChangedEventHandler(None, &handler)? };
+            Ok(EventHandler { automation: self.clone(), registration: Registration::Focus(handler) })
+        }
+
+        /// Calls `callback` with the element, property, and new value when one of the properties changes within the scope of an element.
+        pub fn on_property_changed<F: Fn(Element, UIA_PROPERTY_ID, &VARIANT) + Send + Sync + 'static>(&self, element: &Element, scope: TreeScope, properties: &[UIA_PROPERTY_ID], callback: F) -> Result<EventHandler> {
+            let handler: IUIAutomationPropertyChangedEventHandler = PropertyHandler(self.clone(), Box::new(callback)).into();
+            unsafe { self.0.AddPropertyChangedEventHandlerNativeArray(&element.element, scope, None, &handler, properties)? };
+            Ok(EventHandler { automation: self.clone(), registration: Registration::Property(element.element.clone(), handler) })
+        }
+
+        /// Returns a stream of the elements that raise an event within the scope of an element.
+        pub fn events(&self, id: UIA_EVENT_ID, element: &Element, scope: TreeScope) -> Result<ElementStream> {
+            ElementStream::new(|sender| self.on_event(id, element, scope, move |element, _| sender(element)))
+        }
+
+        /// Returns a stream of the elements that receive the keyboard focus.
+        pub fn focus_changes(&self) -> Result<ElementStream> {
+            ElementStream::new(|sender| self.on_focus_changed(sender))
+        }
+    }
+
+    #[derive(Default)]
+    struct StreamState {
+        elements: VecDeque<Element>,
+        waker: Option<Waker>,
+    }
+
+    /// A stream of the elements that raise a UI Automation event, produced by `Automation::events` and `Automation::focus_changes`.
+    ///
+    /// The stream never ends. The event handler is removed when it is dropped.
+    pub struct ElementStream {
+        state: Arc<Mutex<StreamState>>,
+        _handler: EventHandler,
+    }
+
+    impl ElementStream {
+        fn new<F: FnOnce(Box<dyn Fn(Element) + Send + Sync>) -> Result<EventHandler>>(register: F) -> Result<Self> {
+            let state = Arc::new(Mutex::new(StreamState::default()));
+            let sender = state.clone();
+
+            let handler = register(Box::new(move |element| {
+                let mut state = sender.lock().unwrap();
+                state.elements.push_back(element);
+
+                if let Some(waker) = state.waker.take() {
+                    drop(state);
+                    waker.wake();
+                }
+            }))?;
+
+            Ok(Self { state, _handler: handler })
+        }
+
+        /// Attempts to pull out the next element, registering the current task for wakeup if none is available.
+        pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Element>> {
+            let mut state = self.state.lock().unwrap();
+
+            match state.elements.pop_front() {
+                Some(element) => Poll::Ready(Some(element)),
+                None => {
+                    state.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        }
+    }
+
+    impl Unpin for ElementStream {}
+
+    #[cfg(feature = "futures")]
+    impl futures_core::Stream for ElementStream {
+        type Item = Element;
+
+        fn poll_next(self: core::pin::Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.get_mut().poll_next(cx)
+        }
+    }
+}
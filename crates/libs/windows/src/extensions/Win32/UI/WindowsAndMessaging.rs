@@ -0,0 +1,351 @@
+#[cfg(feature = "Win32_Graphics_Gdi")]
+pub mod Hook;
+pub mod Msg;
+pub mod WindowExt;
+
+#[cfg(feature = "Win32_Graphics_Gdi")]
+use crate::Win32::Foundation::*;
+#[cfg(feature = "Win32_Graphics_Gdi")]
+use crate::Win32::Graphics::Gdi::*;
+#[cfg(feature = "Win32_Graphics_Gdi")]
+use crate::Win32::UI::WindowsAndMessaging::*;
+#[cfg(feature = "Win32_Graphics_Gdi")]
+use windows_core::{Error, Result, HSTRING, PCWSTR};
+
+/// Handles the messages sent to a window created with `WindowBuilder`.
+///
+/// Closures with the same signature as `message` implement this trait. Handlers may be called
+/// reentrantly, for example when a handler calls a function that sends a message to the window.
+#[cfg(feature = "Win32_Graphics_Gdi")]
+pub trait WindowHandler {
+    /// Handles a message, returning `None` to let `DefWindowProcW` handle it instead.
+    fn message(&self, window: HWND, message: u32, wparam: WPARAM, lparam: LPARAM) -> Option<LRESULT>;
+}
+
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl<F: Fn(HWND, u32, WPARAM, LPARAM) -> Option<LRESULT>> WindowHandler for F {
+    fn message(&self, window: HWND, message: u32, wparam: WPARAM, lparam: LPARAM) -> Option<LRESULT> {
+        self(window, message, wparam, lparam)
+    }
+}
+
+/// Creates a window whose messages are handled by a Rust closure or `WindowHandler`.
+///
+/// ```rust,ignore
+/// let window = WindowBuilder::new("Contoso.MainWindow")
+///     .title("Contoso")
+///     .style(WS_OVERLAPPEDWINDOW | WS_VISIBLE)
+///     .create(|_, message, _, _| {
+///         if message == WM_DESTROY {
+///             unsafe { PostQuitMessage(0) };
+///             return Some(LRESULT(0));
+///         }
+///
+///         None
+///     })?;
+///
+/// MessageLoop::new().run()?;
+/// ```
+#[cfg(feature = "Win32_Graphics_Gdi")]
+#[derive(Clone)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct WindowBuilder {
+    class_name: HSTRING,
+    class_style: WNDCLASS_STYLES,
+    cursor: Option<HCURSOR>,
+    background: Option<HBRUSH>,
+    icon: HICON,
+    title: HSTRING,
+    style: WINDOW_STYLE,
+    ex_style: WINDOW_EX_STYLE,
+    position: (i32, i32),
+    size: (i32, i32),
+    parent: Option<HWND>,
+}
+
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl WindowBuilder {
+    /// Creates a builder for windows of the given class, which is registered when the first window is created.
+    ///
+    /// The class options, such as the cursor and background, are fixed by the first window of the class.
+    pub fn new(class_name: &str) -> Self {
+        Self {
+            class_name: HSTRING::from(class_name),
+            class_style: WNDCLASS_STYLES(0),
+            cursor: None,
+            background: None,
+            icon: HICON::default(),
+            title: HSTRING::new(),
+            style: WINDOW_STYLE(0),
+            ex_style: WINDOW_EX_STYLE(0),
+            position: (CW_USEDEFAULT, CW_USEDEFAULT),
+            size: (CW_USEDEFAULT, CW_USEDEFAULT),
+            parent: None,
+        }
+    }
+
+    /// Sets the class style, such as `CS_HREDRAW | CS_VREDRAW`.
+    pub fn class_style(mut self, style: WNDCLASS_STYLES) -> Self {
+        self.class_style = style;
+        self
+    }
+
+    /// Sets the class cursor. The default is the standard arrow.
+    pub fn cursor(mut self, cursor: HCURSOR) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
+    /// Sets the class background brush. The default is the system window color.
+    pub fn background(mut self, background: HBRUSH) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Sets the class icon.
+    pub fn icon(mut self, icon: HICON) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Sets the window title.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = HSTRING::from(title);
+        self
+    }
+
+    /// Sets the window style, such as `WS_OVERLAPPEDWINDOW | WS_VISIBLE`.
+    pub fn style(mut self, style: WINDOW_STYLE) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the extended window style.
+    pub fn ex_style(mut self, ex_style: WINDOW_EX_STYLE) -> Self {
+        self.ex_style = ex_style;
+        self
+    }
+
+    /// Sets the position of the window. The default lets the system choose.
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.position = (x, y);
+        self
+    }
+
+    /// Sets the size of the window. The default lets the system choose.
+    pub fn size(mut self, width: i32, height: i32) -> Self {
+        self.size = (width, height);
+        self
+    }
+
+    /// Sets the parent or owner window.
+    pub fn parent(mut self, parent: HWND) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Creates a message-only window, which is invisible and only receives messages sent or posted to it.
+    pub fn message_only(mut self) -> Self {
+        self.parent = Some(HWND_MESSAGE);
+        self
+    }
+
+    /// Creates a window on the current thread whose messages are passed to `handler`.
+    ///
+    /// The handler receives every message from `WM_NCCREATE` to `WM_NCDESTROY`, and is dropped along with the `Window`.
+    pub fn create<F: Fn(HWND, u32, WPARAM, LPARAM) -> Option<LRESULT> + 'static>(&self, handler: F) -> Result<Window> {
+        self.create_with(Box::new(handler))
+    }
+
+    /// Creates a window on the current thread whose messages are passed to a `WindowHandler` trait object.
+    pub fn create_with(&self, handler: Box<dyn WindowHandler>) -> Result<Window> {
+        unsafe {
+            let class = WNDCLASSW {
+                style: self.class_style,
+                lpfnWndProc: Some(window_proc),
+                hIcon: self.icon,
+                hCursor: match self.cursor {
+                    Some(cursor) => cursor,
+                    None => LoadCursorW(None, IDC_ARROW)?,
+                },
+                hbrBackground: self.background.unwrap_or(HBRUSH((COLOR_WINDOW.0 + 1) as _)),
+                lpszClassName: PCWSTR(self.class_name.as_ptr()),
+                ..Default::default()
+            };
+
+            // Classes are shared by every window of the process, so it may already be registered.
+            if RegisterClassW(&class) == 0 && GetLastError() != ERROR_CLASS_ALREADY_EXISTS {
+                return Err(Error::from_win32());
+            }
+
+            let handler = Box::new(handler);
+
+            // The window procedure stores this pointer when it receives `WM_NCCREATE`.
+            let param = &*handler as *const Box<dyn WindowHandler>;
+
+            let handle = CreateWindowExW(self.ex_style, &self.class_name, &self.title, self.style, self.position.0, self.position.1, self.size.0, self.size.1, self.parent.unwrap_or_default(), None, None, Some(param as _))?;
+
+            Ok(Window { handle, handler })
+        }
+    }
+}
+
+/// A window created with `WindowBuilder`, which is destroyed when dropped.
+///
+/// The window's `GWLP_USERDATA` slot refers to its handler and must not be changed.
+#[cfg(feature = "Win32_Graphics_Gdi")]
+pub struct Window {
+    handle: HWND,
+    handler: Box<Box<dyn WindowHandler>>,
+}
+
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl Window {
+    /// Returns the window handle.
+    pub fn handle(&self) -> HWND {
+        self.handle
+    }
+
+    /// Returns `true` if the window hasn't yet been destroyed, for example by the user closing it.
+    pub fn is_alive(&self) -> bool {
+        unsafe { GetWindowLongPtrW(self.handle, GWLP_USERDATA) == &*self.handler as *const _ as isize }
+    }
+
+    /// Sets the window's show state, returning `true` if it was previously visible.
+    pub fn show(&self, command: SHOW_WINDOW_CMD) -> bool {
+        unsafe { ShowWindow(self.handle, command).as_bool() }
+    }
+}
+
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "raw-window-handle"))]
+impl raw_window_handle::HasWindowHandle for Window {
+    fn window_handle(&self) -> core::result::Result<raw_window_handle::WindowHandle<'_>, raw_window_handle::HandleError> {
+        if !self.is_alive() {
+            return Err(raw_window_handle::HandleError::Unavailable);
+        }
+
+        let raw = raw_window_handle::RawWindowHandle::try_from(self.handle).map_err(|_| raw_window_handle::HandleError::Unavailable)?;
+        Ok(unsafe { raw_window_handle::WindowHandle::borrow_raw(raw) })
+    }
+}
+
+#[cfg(all(feature = "Win32_Graphics_Gdi", feature = "raw-window-handle"))]
+impl raw_window_handle::HasDisplayHandle for Window {
+    fn display_handle(&self) -> core::result::Result<raw_window_handle::DisplayHandle<'_>, raw_window_handle::HandleError> {
+        Ok(raw_window_handle::DisplayHandle::windows())
+    }
+}
+
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl Drop for Window {
+    fn drop(&mut self) {
+        // The handle may have been reused if the window was already destroyed.
+        if self.is_alive() {
+            unsafe {
+                _ = DestroyWindow(self.handle);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "Win32_Graphics_Gdi")]
+extern "system" fn window_proc(window: HWND, message: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    unsafe {
+        if message == WM_NCCREATE {
+            let create = &*(lparam.0 as *const CREATESTRUCTW);
+            SetWindowLongPtrW(window, GWLP_USERDATA, create.lpCreateParams as isize);
+        }
+
+        let handler = GetWindowLongPtrW(window, GWLP_USERDATA) as *const Box<dyn WindowHandler>;
+
+        // The `Window` owns the handler and may free it once the window is destroyed.
+        if message == WM_NCDESTROY {
+            SetWindowLongPtrW(window, GWLP_USERDATA, 0);
+        }
+
+        if !handler.is_null() {
+            if let Some(result) = (*handler).message(window, message, wparam, lparam) {
+                return result;
+            }
+        }
+
+        DefWindowProcW(window, message, wparam, lparam)
+    }
+}
+
+/// Runs a message loop on the current thread until `WM_QUIT` is received.
+///
+/// ```rust,ignore
+/// let exit_code = MessageLoop::new()
+///     .on_idle(|| {
+///         render();
+///         true
+///     })
+///     .run()?;
+/// ```
+#[cfg(feature = "Win32_Graphics_Gdi")]
+#[derive(Default)]
+pub struct MessageLoop<'a> {
+    idle: Option<Box<dyn FnMut() -> bool + 'a>>,
+    dialog: Option<HWND>,
+}
+
+#[cfg(feature = "Win32_Graphics_Gdi")]
+impl<'a> MessageLoop<'a> {
+    /// Creates a message loop that waits for messages.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a callback that is called when the message queue is empty.
+    ///
+    /// The callback returns `true` to be called again as soon as the queue is empty, such as to render
+    /// the next frame, or `false` to wait for the next message first.
+    pub fn on_idle<F: FnMut() -> bool + 'a>(mut self, idle: F) -> Self {
+        self.idle = Some(Box::new(idle));
+        self
+    }
+
+    /// Passes messages for a modeless dialog box, or a window with tab-navigable controls, to `IsDialogMessageW`.
+    pub fn dialog(mut self, dialog: HWND) -> Self {
+        self.dialog = Some(dialog);
+        self
+    }
+
+    /// Runs the loop, returning the exit code passed to `PostQuitMessage`.
+    pub fn run(&mut self) -> Result<i32> {
+        let mut message = MSG::default();
+
+        unsafe {
+            loop {
+                match &mut self.idle {
+                    Some(idle) => {
+                        while !PeekMessageW(&mut message, None, 0, 0, PM_REMOVE).as_bool() {
+                            if !idle() {
+                                WaitMessage()?;
+                            }
+                        }
+                    }
+                    None => {
+                        if GetMessageW(&mut message, None, 0, 0).0 == -1 {
+                            return Err(Error::from_win32());
+                        }
+                    }
+                }
+
+                if message.message == WM_QUIT {
+                    return Ok(message.wParam.0 as i32);
+                }
+
+                if let Some(dialog) = self.dialog {
+                    if IsDialogMessageW(dialog, &message).as_bool() {
+                        continue;
+                    }
+                }
+
+                _ = TranslateMessage(&message);
+                DispatchMessageW(&message);
+            }
+        }
+    }
+}
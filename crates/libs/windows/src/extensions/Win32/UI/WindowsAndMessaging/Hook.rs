@@ -0,0 +1,220 @@
+use super::{MessageLoop, WindowBuilder};
+use crate::Win32::Foundation::*;
+use crate::Win32::UI::WindowsAndMessaging::*;
+use core::marker::PhantomData;
+use std::cell::RefCell;
+use std::rc::Rc;
+use windows_core::Result;
+
+type Callback = Rc<RefCell<dyn FnMut(i32, WPARAM, LPARAM) -> Option<LRESULT>>>;
+
+struct Entry {
+    id: WINDOWS_HOOK_ID,
+    key: usize,
+    callback: Callback,
+}
+
+// The hooks installed by the current thread. Hook procedures have no context parameter, so each
+// thread installs at most one system hook of each type and dispatches to every callback of that type.
+#[derive(Default)]
+struct Registry {
+    next_key: usize,
+    entries: Vec<Entry>,
+    hooks: Vec<(WINDOWS_HOOK_ID, HHOOK)>,
+}
+
+std::thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::default();
+}
+
+/// A hook installed with `SetWindowsHookExW` whose callback is a Rust closure.
+///
+/// Low-level keyboard and mouse hooks monitor input for the whole desktop, while other types of hook
+/// monitor the current thread. Either way, the callback is called on the thread that installed the
+/// hook, which must pump messages for the callback to be called. The hook is removed when dropped.
+///
+/// ```rust,ignore
+/// let hook = Hook::keyboard_ll(|message, info| {
+///     println!("{message:#x} {}", info.vkCode);
+///     false
+/// })?;
+///
+/// MessageLoop::new().run()?;
+/// ```
+pub struct Hook {
+    id: WINDOWS_HOOK_ID,
+    key: usize,
+    // Hooks belong to the thread that installed them.
+    _thread: PhantomData<*const ()>,
+}
+
+impl Hook {
+    /// Installs a hook of the given type.
+    ///
+    /// The callback is only called for hook codes that may be processed, and returns `None` to pass the
+    /// event on to the next hook with `CallNextHookEx`.
+    pub fn new<F: FnMut(i32, WPARAM, LPARAM) -> Option<LRESULT> + 'static>(id: WINDOWS_HOOK_ID, callback: F) -> Result<Self> {
+        let proc = hook_proc(id).ok_or(windows_core::Error::from(windows_core::imp::E_INVALIDARG))?;
+
+        REGISTRY.with(|registry| {
+            let mut registry = registry.borrow_mut();
+
+            if !registry.hooks.iter().any(|(hook_id, _)| *hook_id == id) {
+                let thread = if id == WH_KEYBOARD_LL || id == WH_MOUSE_LL { 0 } else { current_thread_id() };
+                let hook = unsafe { SetWindowsHookExW(id, Some(proc), None, thread)? };
+                registry.hooks.push((id, hook));
+            }
+
+            registry.next_key += 1;
+            let key = registry.next_key;
+            registry.entries.push(Entry { id, key, callback: Rc::new(RefCell::new(callback)) });
+
+            Ok(Self { id, key, _thread: PhantomData })
+        })
+    }
+
+    /// Installs a low-level keyboard hook. The callback receives the keyboard message, such as
+    /// `WM_KEYDOWN`, and returns `true` to prevent the event from reaching other hooks and applications.
+    pub fn keyboard_ll<F: FnMut(u32, &KBDLLHOOKSTRUCT) -> bool + 'static>(mut callback: F) -> Result<Self> {
+        Self::new(WH_KEYBOARD_LL, move |_, wparam, lparam| callback(wparam.0 as u32, unsafe { &*(lparam.0 as *const KBDLLHOOKSTRUCT) }).then_some(LRESULT(1)))
+    }
+
+    /// Installs a low-level mouse hook. The callback receives the mouse message, such as
+    /// `WM_MOUSEMOVE`, and returns `true` to prevent the event from reaching other hooks and applications.
+    pub fn mouse_ll<F: FnMut(u32, &MSLLHOOKSTRUCT) -> bool + 'static>(mut callback: F) -> Result<Self> {
+        Self::new(WH_MOUSE_LL, move |_, wparam, lparam| callback(wparam.0 as u32, unsafe { &*(lparam.0 as *const MSLLHOOKSTRUCT) }).then_some(LRESULT(1)))
+    }
+}
+
+impl Drop for Hook {
+    fn drop(&mut self) {
+        let entry = REGISTRY.with(|registry| {
+            let mut registry = registry.borrow_mut();
+            let index = registry.entries.iter().position(|entry| entry.key == self.key)?;
+            let entry = registry.entries.remove(index);
+
+            if !registry.entries.iter().any(|entry| entry.id == self.id) {
+                if let Some(index) = registry.hooks.iter().position(|(id, _)| *id == self.id) {
+                    let (_, hook) = registry.hooks.remove(index);
+                    unsafe { _ = UnhookWindowsHookEx(hook) };
+                }
+            }
+
+            Some(entry)
+        });
+
+        // The callback is dropped after the registry is released, since it may own other hooks.
+        drop(entry);
+    }
+}
+
+fn current_thread_id() -> u32 {
+    windows_targets::link!("kernel32.dll" "system" fn GetCurrentThreadId() -> u32);
+    unsafe { GetCurrentThreadId() }
+}
+
+fn hook_proc(id: WINDOWS_HOOK_ID) -> Option<unsafe extern "system" fn(i32, WPARAM, LPARAM) -> LRESULT> {
+    Some(match id.0 {
+        -1 => dispatch::<-1>,
+        0 => dispatch::<0>,
+        1 => dispatch::<1>,
+        2 => dispatch::<2>,
+        3 => dispatch::<3>,
+        4 => dispatch::<4>,
+        5 => dispatch::<5>,
+        6 => dispatch::<6>,
+        7 => dispatch::<7>,
+        9 => dispatch::<9>,
+        10 => dispatch::<10>,
+        11 => dispatch::<11>,
+        12 => dispatch::<12>,
+        13 => dispatch::<13>,
+        14 => dispatch::<14>,
+        _ => return None,
+    })
+}
+
+unsafe extern "system" fn dispatch<const ID: i32>(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    // Negative codes must be passed to the next hook without further processing.
+    if code >= 0 {
+        let callbacks: Vec<Callback> = REGISTRY.with(|registry| registry.borrow().entries.iter().filter(|entry| entry.id.0 == ID).map(|entry| entry.callback.clone()).collect());
+
+        for callback in callbacks {
+            // A callback that is already running is skipped if the hook is called reentrantly.
+            if let Ok(mut callback) = callback.try_borrow_mut() {
+                if let Some(result) = callback(code, wparam, lparam) {
+                    return result;
+                }
+            }
+        }
+    }
+
+    CallNextHookEx(None, code, wparam, lparam)
+}
+
+/// A thread that installs hooks and pumps messages so that their callbacks are called.
+///
+/// Low-level hooks time out if their callbacks don't return promptly, so a dedicated thread keeps
+/// them responsive regardless of what the rest of the app is doing.
+///
+/// ```rust,ignore
+/// let thread = HookThread::new(|| Ok(vec![Hook::mouse_ll(|message, info| {
+///     println!("{message:#x} at {}, {}", info.pt.x, info.pt.y);
+///     false
+/// })?]))?;
+/// ```
+pub struct HookThread {
+    // The window handle, stored as an integer since `HWND` can't be sent between threads.
+    window: isize,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl HookThread {
+    /// Starts a thread that calls `setup` to install hooks and then pumps messages until the `HookThread` is dropped.
+    pub fn new<F: FnOnce() -> Result<Vec<Hook>> + Send + 'static>(setup: F) -> Result<Self> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let thread = std::thread::Builder::new().name("HookThread".to_string()).spawn(move || {
+            let started = WindowBuilder::new("windows-rs-hook-thread")
+                .message_only()
+                .create(|_, message, _, _| {
+                    if message == WM_DESTROY {
+                        unsafe { PostQuitMessage(0) };
+                        return Some(LRESULT(0));
+                    }
+
+                    None
+                })
+                .and_then(|window| Ok((setup()?, window)));
+
+            match started {
+                Ok((_hooks, window)) => {
+                    _ = sender.send(Ok(window.handle().0 as isize));
+                    _ = MessageLoop::new().run();
+                }
+                Err(error) => _ = sender.send(Err(error)),
+            }
+        })?;
+
+        match receiver.recv() {
+            Ok(Ok(window)) => Ok(Self { window, thread: Some(thread) }),
+            Ok(Err(error)) => {
+                _ = thread.join();
+                Err(error)
+            }
+            Err(_) => Err(E_UNEXPECTED.into()),
+        }
+    }
+}
+
+impl Drop for HookThread {
+    fn drop(&mut self) {
+        unsafe {
+            _ = PostMessageW(HWND(self.window as _), WM_CLOSE, WPARAM(0), LPARAM(0));
+        }
+
+        if let Some(thread) = self.thread.take() {
+            _ = thread.join();
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::UI::WindowsAndMessaging::*;
+
+/// A window message whose `WPARAM` and `LPARAM` have been decoded into typed values, in the manner of the
+/// `windowsx.h` message crackers.
+///
+/// ```rust,ignore
+/// match unsafe { Msg::decode(message, wparam, lparam) } {
+///     Msg::Size(size) => resize(size.width, size.height),
+///     Msg::Command(command) if command.id == ID_EXIT => close(window),
+///     message => return message.forward(window),
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub enum Msg<'a> {
+    /// `WM_SIZE`
+    Size(SizeMsg),
+    /// `WM_KEYDOWN`
+    KeyDown(KeyDownMsg),
+    /// `WM_MOUSEMOVE`
+    MouseMove(MouseMoveMsg),
+    /// `WM_COMMAND`
+    Command(CommandMsg),
+    /// `WM_DPICHANGED`
+    DpiChanged(DpiChangedMsg<'a>),
+    /// `WM_COPYDATA`
+    #[cfg(feature = "Win32_System_DataExchange")]
+    CopyData(CopyDataMsg<'a>),
+    /// Any message without a typed representation.
+    Other(u32, WPARAM, LPARAM),
+}
+
+/// The parameters of `WM_SIZE`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct SizeMsg {
+    /// The type of resizing, such as `SIZE_RESTORED` or `SIZE_MINIMIZED`.
+    pub kind: u32,
+    /// The new width of the client area.
+    pub width: u16,
+    /// The new height of the client area.
+    pub height: u16,
+}
+
+/// The parameters of `WM_KEYDOWN`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct KeyDownMsg {
+    /// The virtual-key code.
+    pub key: u16,
+    /// The number of times the keystroke is repeated because the user is holding down the key.
+    pub repeat_count: u16,
+    /// The scan code, which depends on the keyboard manufacturer.
+    pub scan_code: u8,
+    /// Whether the key is an extended key, such as the right-hand `ALT` and `CTRL` keys.
+    pub extended: bool,
+    /// Whether the key was already down before this message was sent.
+    pub previous_down: bool,
+}
+
+/// The parameters of `WM_MOUSEMOVE`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct MouseMoveMsg {
+    /// The x-coordinate of the cursor relative to the client area, which may be negative on multiple monitor systems.
+    pub x: i32,
+    /// The y-coordinate of the cursor relative to the client area.
+    pub y: i32,
+    /// The virtual keys that are down, as a combination of the `MK_*` flags.
+    pub keys: u32,
+}
+
+/// The parameters of `WM_COMMAND`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct CommandMsg {
+    /// The identifier of the menu item, accelerator, or control.
+    pub id: u16,
+    /// The notification code, which is `0` for menus and `1` for accelerators.
+    pub code: u16,
+    /// The control that sent the notification, or null for menus and accelerators.
+    pub control: HWND,
+}
+
+/// The parameters of `WM_DPICHANGED`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct DpiChangedMsg<'a> {
+    /// The new horizontal dots per inch of the window.
+    pub dpi_x: u16,
+    /// The new vertical dots per inch of the window.
+    pub dpi_y: u16,
+    /// The suggested size and position of the window, in screen coordinates, for the new DPI.
+    pub suggested: &'a RECT,
+}
+
+/// The parameters of `WM_COPYDATA`.
+#[cfg(feature = "Win32_System_DataExchange")]
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(not(windows_no_debug), derive(Debug))]
+pub struct CopyDataMsg<'a> {
+    /// The window passing the data.
+    pub sender: HWND,
+    /// The data being passed.
+    pub data: &'a crate::Win32::System::DataExchange::COPYDATASTRUCT,
+}
+
+#[cfg(feature = "Win32_System_DataExchange")]
+impl CopyDataMsg<'_> {
+    /// Returns the bytes being passed.
+    pub fn bytes(&self) -> &[u8] {
+        if self.data.lpData.is_null() || self.data.cbData == 0 {
+            &[]
+        } else {
+            unsafe { core::slice::from_raw_parts(self.data.lpData as *const u8, self.data.cbData as usize) }
+        }
+    }
+}
+
+impl<'a> Msg<'a> {
+    /// Decodes a message's parameters.
+    ///
+    /// # Safety
+    ///
+    /// The parameters must be those of a message sent by the system or encoded by [`Msg::encode`], as any
+    /// pointers they contain are dereferenced and must remain valid for `'a`.
+    pub unsafe fn decode(message: u32, wparam: WPARAM, lparam: LPARAM) -> Self {
+        match message {
+            WM_SIZE => Self::Size(SizeMsg { kind: wparam.0 as u32, width: loword(lparam.0 as usize), height: hiword(lparam.0 as usize) }),
+            WM_KEYDOWN => Self::KeyDown(KeyDownMsg {
+                key: wparam.0 as u16,
+                repeat_count: loword(lparam.0 as usize),
+                scan_code: (lparam.0 >> 16) as u8,
+                extended: lparam.0 & (1 << 24) != 0,
+                previous_down: lparam.0 & (1 << 30) != 0,
+            }),
+            WM_MOUSEMOVE => Self::MouseMove(MouseMoveMsg { x: loword(lparam.0 as usize) as i16 as i32, y: hiword(lparam.0 as usize) as i16 as i32, keys: wparam.0 as u32 }),
+            WM_COMMAND => Self::Command(CommandMsg { id: loword(wparam.0), code: hiword(wparam.0), control: HWND(lparam.0 as _) }),
+            WM_DPICHANGED if lparam.0 != 0 => Self::DpiChanged(DpiChangedMsg { dpi_x: loword(wparam.0), dpi_y: hiword(wparam.0), suggested: &*(lparam.0 as *const RECT) }),
+            #[cfg(feature = "Win32_System_DataExchange")]
+            WM_COPYDATA if lparam.0 != 0 => Self::CopyData(CopyDataMsg { sender: HWND(wparam.0 as _), data: &*(lparam.0 as *const _) }),
+            _ => Self::Other(message, wparam, lparam),
+        }
+    }
+
+    /// Encodes the message and its parameters, for example to pass to `SendMessageW`.
+    pub fn encode(&self) -> (u32, WPARAM, LPARAM) {
+        match self {
+            Self::Size(size) => (WM_SIZE, WPARAM(size.kind as usize), LPARAM(makelong(size.width, size.height) as isize)),
+            Self::KeyDown(key) => {
+                let mut lparam = makelong(key.repeat_count, key.scan_code as u16);
+                if key.extended {
+                    lparam |= 1 << 24;
+                }
+                if key.previous_down {
+                    lparam |= 1 << 30;
+                }
+                (WM_KEYDOWN, WPARAM(key.key as usize), LPARAM(lparam as isize))
+            }
+            Self::MouseMove(mouse) => (WM_MOUSEMOVE, WPARAM(mouse.keys as usize), LPARAM(makelong(mouse.x as u16, mouse.y as u16) as isize)),
+            Self::Command(command) => (WM_COMMAND, WPARAM(makelong(command.id, command.code) as usize), LPARAM(command.control.0 as isize)),
+            Self::DpiChanged(dpi) => (WM_DPICHANGED, WPARAM(makelong(dpi.dpi_x, dpi.dpi_y) as usize), LPARAM(dpi.suggested as *const RECT as isize)),
+            #[cfg(feature = "Win32_System_DataExchange")]
+            Self::CopyData(copy) => (WM_COPYDATA, WPARAM(copy.sender.0 as usize), LPARAM(copy.data as *const _ as isize)),
+            Self::Other(message, wparam, lparam) => (*message, *wparam, *lparam),
+        }
+    }
+
+    /// Returns the result a window procedure returns to indicate that it processed the message.
+    pub fn handled(&self) -> LRESULT {
+        match self {
+            #[cfg(feature = "Win32_System_DataExchange")]
+            Self::CopyData(_) => LRESULT(1),
+            _ => LRESULT(0),
+        }
+    }
+
+    /// Passes the message to `DefWindowProcW` for default processing.
+    pub fn forward(&self, window: HWND) -> LRESULT {
+        let (message, wparam, lparam) = self.encode();
+        unsafe { DefWindowProcW(window, message, wparam, lparam) }
+    }
+}
+
+fn loword(value: usize) -> u16 {
+    value as u16
+}
+
+fn hiword(value: usize) -> u16 {
+    (value >> 16) as u16
+}
+
+fn makelong(low: u16, high: u16) -> u32 {
+    low as u32 | (high as u32) << 16
+}
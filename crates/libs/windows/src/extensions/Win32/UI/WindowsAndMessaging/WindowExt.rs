@@ -0,0 +1,82 @@
+use crate::Win32::Foundation::*;
+use crate::Win32::UI::WindowsAndMessaging::*;
+use windows_core::{Error, Result};
+
+/// Convenience methods for the most common operations on a window handle.
+///
+/// A stale or null handle is not undefined behavior: the underlying functions validate the handle and
+/// the methods report the failure as an error, or as `false` or `0` as documented below.
+///
+/// ```rust,ignore
+/// use windows::Win32::UI::WindowsAndMessaging::WindowExt;
+///
+/// if hwnd.is_window() {
+///     let rect = hwnd.client_rect()?;
+///     println!("{}: {}x{}", hwnd.get_class_name()?, rect.right, rect.bottom);
+/// }
+/// ```
+pub trait WindowExt {
+    /// Sends a message to the window and waits for it to be processed, returning the window procedure's result.
+    ///
+    /// # Safety
+    ///
+    /// Any pointers packed into `wparam` or `lparam` must be valid as the message requires.
+    unsafe fn send_message(&self, message: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT;
+
+    /// Posts a message to the window's message queue and returns without waiting for it to be processed.
+    ///
+    /// # Safety
+    ///
+    /// Any pointers packed into `wparam` or `lparam` must remain valid until the message is processed.
+    unsafe fn post_message(&self, message: u32, wparam: WPARAM, lparam: LPARAM) -> Result<()>;
+
+    /// Returns the name of the window's class.
+    fn get_class_name(&self) -> Result<String>;
+
+    /// Returns the window's client area, whose `left` and `top` are always zero.
+    fn client_rect(&self) -> Result<RECT>;
+
+    /// Returns the dots per inch of the window, or zero if the handle is not a valid window.
+    #[cfg(feature = "Win32_UI_HiDpi")]
+    fn dpi(&self) -> u32;
+
+    /// Returns `true` if the handle identifies an existing window.
+    fn is_window(&self) -> bool;
+}
+
+impl WindowExt for HWND {
+    unsafe fn send_message(&self, message: u32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+        SendMessageW(*self, message, wparam, lparam)
+    }
+
+    unsafe fn post_message(&self, message: u32, wparam: WPARAM, lparam: LPARAM) -> Result<()> {
+        PostMessageW(*self, message, wparam, lparam)
+    }
+
+    fn get_class_name(&self) -> Result<String> {
+        // Class names are limited to 256 characters.
+        let mut buffer = [0u16; 257];
+        let len = unsafe { GetClassNameW(*self, &mut buffer) };
+
+        if len == 0 {
+            return Err(Error::from_win32());
+        }
+
+        Ok(String::from_utf16_lossy(&buffer[..len as usize]))
+    }
+
+    fn client_rect(&self) -> Result<RECT> {
+        let mut rect = RECT::default();
+        unsafe { GetClientRect(*self, &mut rect)? };
+        Ok(rect)
+    }
+
+    #[cfg(feature = "Win32_UI_HiDpi")]
+    fn dpi(&self) -> u32 {
+        unsafe { crate::Win32::UI::HiDpi::GetDpiForWindow(*self) }
+    }
+
+    fn is_window(&self) -> bool {
+        unsafe { IsWindow(*self).as_bool() }
+    }
+}
@@ -0,0 +1,2 @@
+#[cfg(feature = "std")]
+pub use crate::extensions::Foundation::Async::{Async, AsyncFuture, AsyncProgressResult, AsyncProgressStream, AsyncTimeout, AsyncWithProgress, CancellationToken};
@@ -0,0 +1 @@
+pub use crate::extensions::Foundation::Deferral::DeferralGuard;
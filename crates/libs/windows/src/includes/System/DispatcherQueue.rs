@@ -0,0 +1,2 @@
+#[cfg(feature = "std")]
+pub use crate::extensions::System::DispatcherQueue::{resume_background, resume_on, DispatcherQueueExecutor, Resume};
@@ -0,0 +1,4 @@
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections", feature = "std"))]
+pub use crate::extensions::UI::Notifications::{Toast, ToastActivation};
+#[cfg(all(feature = "Data_Xml_Dom", feature = "Foundation_Collections", feature = "Win32_Foundation", feature = "Win32_System_Registry", feature = "std"))]
+pub use crate::extensions::UI::Notifications::{register_app_id, unregister_app_id};
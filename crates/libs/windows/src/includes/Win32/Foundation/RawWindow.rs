@@ -0,0 +1,2 @@
+#[cfg(feature = "raw-window-handle")]
+pub use crate::extensions::Win32::Foundation::HWND::RawWindow;
@@ -0,0 +1,2 @@
+#[cfg(feature = "Win32_Foundation")]
+pub use crate::extensions::Win32::Graphics::Gdi::{CreatedHDC, WindowHDC};
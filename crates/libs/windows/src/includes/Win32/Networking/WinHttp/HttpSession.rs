@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "std"))]
+pub use crate::extensions::Win32::Networking::WinHttp::{HttpConnection, HttpProxy, HttpRequest, HttpRequestBody, HttpResponse, HttpSession, HttpSessionBuilder, WebSocket, WebSocketMessage};
@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security_Cryptography", feature = "std"))]
+pub use crate::extensions::Win32::Security::Cryptography::Bcrypt::{hmac_sha256, random, sha256, AesGcm, Algorithm, Hash};
@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security_Authorization", feature = "Win32_System_SystemServices", feature = "std"))]
+pub use crate::extensions::Win32::Security::Descriptor::{Ace, AceKind, SecurityDescriptor, SecurityDescriptorBuilder};
@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_SystemServices", feature = "Win32_System_Threading", feature = "std"))]
+pub use crate::extensions::Win32::Security::Token::{Impersonation, Token};
@@ -0,0 +1,4 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Com_StructuredStorage", feature = "std"))]
+pub use crate::extensions::Win32::System::Com::Persist::{create_object_from_bytes, load_from_bytes, load_from_file, save_object_to_bytes, save_to_bytes, save_to_file, StreamIo};
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Com_StructuredStorage", feature = "implement", feature = "serde_json", feature = "std"))]
+pub use crate::extensions::Win32::System::Com::Persist::{PersistState, SerdePersist};
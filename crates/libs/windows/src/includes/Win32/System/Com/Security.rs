@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security"))]
+pub use crate::extensions::Win32::System::Com::Security::ComSecurity;
@@ -0,0 +1,6 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "std"))]
+pub use crate::extensions::Win32::System::Console::{ConsoleEvent, ConsoleEvents, ConsoleInput, ConsoleModeGuard, ConsoleOutput, KeyEvent};
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Threading", feature = "std"))]
+pub use crate::extensions::Win32::System::Console::ConsoleEventStream;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security", feature = "Win32_System_Pipes", feature = "std"))]
+pub use crate::extensions::Win32::System::Console::PseudoConsole;
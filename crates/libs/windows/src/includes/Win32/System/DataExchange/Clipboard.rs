@@ -0,0 +1,4 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Memory", feature = "Win32_System_Ole", feature = "std"))]
+pub use crate::extensions::Win32::System::DataExchange::Clipboard;
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Graphics_Gdi", feature = "Win32_System_Memory", feature = "Win32_System_Ole", feature = "Win32_UI_WindowsAndMessaging", feature = "std"))]
+pub use crate::extensions::Win32::System::DataExchange::ClipboardListener;
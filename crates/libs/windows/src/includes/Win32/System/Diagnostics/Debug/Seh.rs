@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security", feature = "Win32_System_Kernel", feature = "Win32_System_Threading", feature = "std"))]
+pub use crate::extensions::Win32::System::Diagnostics::Debug::seh;
@@ -0,0 +1,6 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "std"))]
+pub use crate::extensions::Win32::System::Diagnostics::ToolHelp::{HeapBlock, HeapBlocks, HeapEntry, Heaps, ModuleEntry, Modules, ProcessEntry, Processes, Snapshot, ThreadEntry, Threads};
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Threading", feature = "std"))]
+pub use crate::extensions::Win32::System::Diagnostics::ToolHelp::{ProcessInfo, ProcessTimes};
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_ProcessStatus", feature = "Win32_System_Threading", feature = "std"))]
+pub use crate::extensions::Win32::System::Diagnostics::ToolHelp::MemoryCounters;
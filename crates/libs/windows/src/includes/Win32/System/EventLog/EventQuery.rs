@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security", feature = "std"))]
+pub use crate::extensions::Win32::System::EventLog::{EventQuery, EventRecord, EventSubscription, EventSystem, EventValue, Events, SubscriptionStart};
@@ -0,0 +1,4 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "std"))]
+pub use crate::extensions::Win32::System::IO::{associate_handle, AsyncHandle, CompletionPacket, CompletionPort, IoFuture};
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Networking_WinSock", feature = "std"))]
+pub use crate::extensions::Win32::System::IO::associate_socket;
@@ -0,0 +1,4 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security", feature = "Win32_System_Threading", feature = "std"))]
+pub use crate::extensions::Win32::System::JobObjects::{Job, JobLimits, JobNotification};
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security", feature = "Win32_System_IO", feature = "Win32_System_SystemServices", feature = "Win32_System_Threading", feature = "std"))]
+pub use crate::extensions::Win32::System::JobObjects::JobNotifications;
@@ -0,0 +1,2 @@
+#[cfg(feature = "Win32_Foundation")]
+pub use crate::extensions::Win32::System::Memory::VirtualMemory::{large_page_size, ProtectionGuard, VirtualRegion};
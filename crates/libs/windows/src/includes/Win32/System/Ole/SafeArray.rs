@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Com", feature = "Win32_System_Variant", feature = "std"))]
+pub use crate::extensions::Win32::System::Ole::{SafeArray, SafeArrayElement, SafeArrayLock, SafeArrayLockMut};
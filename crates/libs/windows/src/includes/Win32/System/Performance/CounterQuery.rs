@@ -0,0 +1,4 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "std"))]
+pub use crate::extensions::Win32::System::Performance::{counter_object_items, counter_objects, expand_counter_path, Counter, CounterQuery};
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security", feature = "Win32_System_Threading", feature = "std"))]
+pub use crate::extensions::Win32::System::Performance::CollectTimer;
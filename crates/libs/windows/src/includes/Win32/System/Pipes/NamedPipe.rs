@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Security", feature = "Win32_Storage_FileSystem", feature = "Win32_System_IO", feature = "std"))]
+pub use crate::extensions::Win32::System::Pipes::{NamedPipe, NamedPipeClient, NamedPipeServer};
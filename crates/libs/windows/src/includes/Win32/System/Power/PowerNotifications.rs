@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Graphics_Gdi", feature = "Win32_UI_WindowsAndMessaging", feature = "std"))]
+pub use crate::extensions::Win32::System::Power::{PowerEvent, PowerEventStream, PowerListener, PowerNotifications};
@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "std"))]
+pub use crate::extensions::Win32::System::RemoteDesktop::{Session, SessionInfo, SessionProtocol};
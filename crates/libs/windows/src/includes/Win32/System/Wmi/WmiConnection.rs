@@ -0,0 +1,4 @@
+#[cfg(all(feature = "Win32_System_Com", feature = "std"))]
+pub use crate::extensions::Win32::System::Wmi::{WmiConnection, WmiNotifications, WmiObject, WmiObjects};
+#[cfg(all(feature = "Win32_System_Com", feature = "implement", feature = "std"))]
+pub use crate::extensions::Win32::System::Wmi::WmiEventStream;
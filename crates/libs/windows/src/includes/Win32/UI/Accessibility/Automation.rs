@@ -0,0 +1,4 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Com", feature = "std"))]
+pub use crate::extensions::Win32::UI::Accessibility::{Automation, Condition, Element, Pattern};
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_Com", feature = "implement", feature = "std"))]
+pub use crate::extensions::Win32::UI::Accessibility::{ElementStream, EventHandler};
@@ -0,0 +1,4 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "std"))]
+pub use crate::extensions::Win32::UI::WindowsAndMessaging::Msg::{CommandMsg, DpiChangedMsg, KeyDownMsg, MouseMoveMsg, Msg, SizeMsg};
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_System_DataExchange", feature = "std"))]
+pub use crate::extensions::Win32::UI::WindowsAndMessaging::Msg::CopyDataMsg;
@@ -0,0 +1,2 @@
+#[cfg(all(feature = "Win32_Foundation", feature = "Win32_Graphics_Gdi", feature = "std"))]
+pub use crate::extensions::Win32::UI::WindowsAndMessaging::{MessageLoop, Window, WindowBuilder, WindowHandler};
@@ -0,0 +1,33 @@
+use windows::{core::*, Foundation::*, Win32::System::Com::*};
+
+#[test]
+fn agile_cell() -> Result<()> {
+    unsafe { CoInitializeEx(None, COINIT_MULTITHREADED).ok()? };
+
+    let uri = Uri::CreateUri(h!("http://kennykerr.ca"))?;
+    let cell = AgileCell::new(&uri)?;
+    assert!(cell.is_current_context());
+    assert_eq!(cell.with(|uri| uri.Domain())?, "kennykerr.ca");
+    assert!(format!("{cell:?}").starts_with("AgileCell(AgileReference("));
+
+    let handle = std::thread::spawn(move || -> Result<()> {
+        unsafe { CoInitializeEx(None, COINIT_APARTMENTTHREADED).ok()? };
+        assert!(!cell.is_current_context());
+        assert_eq!(cell.get()?.Host()?, "kennykerr.ca");
+
+        // The closure runs back in the multithreaded apartment.
+        let clone = cell.clone();
+        assert_eq!(
+            cell.with(move |uri| {
+                assert!(clone.is_current_context());
+                uri.SchemeName()
+            })?,
+            "http"
+        );
+
+        unsafe { CoUninitialize() };
+        Ok(())
+    });
+
+    handle.join().unwrap()
+}
@@ -16,3 +16,50 @@ fn array() {
     assert!(a[0].as_ref().unwrap().Domain().unwrap() == "kennykerr.ca");
     assert!(a[1].as_ref().unwrap().Domain().unwrap() == "microsoft.com");
 }
+
+#[test]
+fn conversions() {
+    let a = Array::<i32>::from(vec![1, 2, 3]);
+    assert_eq!(a.len(), 3);
+    assert_eq!(a.clone(), a);
+    assert_eq!(format!("{a:?}"), "[1, 2, 3]");
+    assert_eq!(a.iter().sum::<i32>(), 6);
+    assert_eq!(Vec::from(a), [1, 2, 3]);
+
+    let a: Array<Uri> = ["http://kennykerr.ca", "http://microsoft.com"]
+        .into_iter()
+        .map(|uri| Uri::CreateUri(&windows::core::HSTRING::from(uri)).ok())
+        .collect();
+
+    let mut domains = vec![];
+    for uri in &a {
+        domains.push(uri.as_ref().unwrap().Domain().unwrap());
+    }
+    assert_eq!(domains, ["kennykerr.ca", "microsoft.com"]);
+
+    let boxed: Box<[Option<Uri>]> = a.into();
+    assert_eq!(boxed.len(), 2);
+    let a = Array::<Uri>::from(boxed);
+    assert_eq!(a.into_iter().flatten().count(), 2);
+
+    let mut a = Array::<windows::core::HSTRING>::from(vec!["a".into(), "b".into(), "c".into()]);
+    a.truncate(1);
+    assert_eq!(a.into_vec(), ["a"]);
+}
+
+#[test]
+fn receive() -> windows::core::Result<()> {
+    use windows::core::Interface;
+    use windows::Foundation::*;
+
+    let value = PropertyValue::CreateUInt8Array(&[1, 2, 3])?.cast::<IPropertyValue>()?;
+    let a = Array::receive(|a| value.GetUInt8Array(a))?;
+    assert_eq!(a.as_slice(), [1, 2, 3]);
+
+    let a = Array::<u32>::fill(4, |values| {
+        values[..2].copy_from_slice(&[5, 6]);
+        Ok(2)
+    })?;
+    assert_eq!(a.as_slice(), [5, 6]);
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use futures::{executor::block_on, FutureExt};
+use std::future::IntoFuture;
+use windows::{core::*, Foundation::*, System::Threading::*};
+
+// Runs a work item that only completes once it observes that it has been canceled.
+fn run_until_canceled() -> Result<IAsyncAction> {
+    ThreadPool::RunAsync(&WorkItemHandler::new(|action| {
+        let action = action.unwrap();
+
+        while action.Status()? != AsyncStatus::Canceled {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        Ok(())
+    }))
+}
+
+fn wait_for_canceled(action: &IAsyncAction) -> Result<()> {
+    while action.Status()? == AsyncStatus::Started {
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+
+    assert_eq!(action.Status()?, AsyncStatus::Canceled);
+    Ok(())
+}
+
+#[test]
+fn drop_cancels() -> Result<()> {
+    let action = run_until_canceled()?;
+
+    // Polling once registers the completion handler, after which the future is dropped.
+    assert!(action.clone().into_future().now_or_never().is_none());
+    wait_for_canceled(&action)
+}
+
+#[test]
+fn token() -> Result<()> {
+    let token = CancellationToken::new();
+    let action = run_until_canceled()?;
+    let future = action.clone().with_cancellation(&token);
+    assert!(!token.is_canceled());
+
+    let canceler = token.clone();
+    std::thread::spawn(move || canceler.cancel());
+
+    let error = block_on(future).unwrap_err();
+    assert_eq!(error.code(), HRESULT(0x80004004u32 as i32)); // E_ABORT
+    assert!(token.is_canceled());
+    wait_for_canceled(&action)
+}
+
+#[test]
+fn token_already_canceled() -> Result<()> {
+    let token = CancellationToken::new();
+    token.cancel();
+
+    let action = run_until_canceled()?;
+    let error = block_on(action.clone().with_cancellation(&token)).unwrap_err();
+    assert_eq!(error.code(), HRESULT(0x80004004u32 as i32)); // E_ABORT
+    wait_for_canceled(&action)
+}
+
+#[test]
+fn completed_not_canceled() -> Result<()> {
+    let token = CancellationToken::new();
+    let action = ThreadPool::RunAsync(&WorkItemHandler::new(|_| Ok(())))?;
+    block_on(action.clone().with_cancellation(&token))?;
+
+    // Canceling after completion has no effect.
+    token.cancel();
+    assert_eq!(action.Status()?, AsyncStatus::Completed);
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use futures::executor::block_on;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use windows::{core::*, Foundation::*};
+
+fn create() -> Result<(Deferral, Arc<AtomicUsize>)> {
+    let completed = Arc::new(AtomicUsize::new(0));
+    let counter = completed.clone();
+
+    let deferral = Deferral::Create(&DeferralCompletedHandler::new(move || {
+        counter.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }))?;
+
+    Ok((deferral, completed))
+}
+
+#[test]
+fn drop_completes() -> Result<()> {
+    let (deferral, completed) = create()?;
+    let guard = DeferralGuard::new(deferral, Deferral::Complete);
+    assert_eq!(completed.load(Ordering::Relaxed), 0);
+
+    drop(guard);
+    assert_eq!(completed.load(Ordering::Relaxed), 1);
+    Ok(())
+}
+
+#[test]
+fn complete() -> Result<()> {
+    let (deferral, completed) = create()?;
+    let guard = DeferralGuard::new(deferral.clone(), Deferral::Complete);
+    assert_eq!(guard.deferral(), &deferral);
+
+    guard.complete()?;
+    assert_eq!(completed.load(Ordering::Relaxed), 1);
+    Ok(())
+}
+
+#[test]
+fn complete_after() -> Result<()> {
+    let (deferral, completed) = create()?;
+    let guard = DeferralGuard::new(deferral, Deferral::Complete);
+
+    let value = block_on(guard.complete_after(async {
+        assert_eq!(completed.load(Ordering::Relaxed), 0);
+        123
+    }));
+
+    assert_eq!(value, 123);
+    assert_eq!(completed.load(Ordering::Relaxed), 1);
+
+    // Abandoning the work still completes the deferral.
+    let (deferral, completed) = create()?;
+    drop(DeferralGuard::new(deferral, Deferral::Complete).complete_after(async {}));
+    assert_eq!(completed.load(Ordering::Relaxed), 1);
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use futures::{executor::block_on, FutureExt};
+use windows::{core::*, Foundation::*, Win32::Foundation::E_FAIL};
+
+#[test]
+fn wait() -> Result<()> {
+    let deferrals = Deferrals::new();
+    assert_eq!(deferrals.wait().now_or_never(), Some(Ok(())));
+
+    let first = deferrals.get_deferral()?;
+    let second = deferrals.get_deferral()?;
+    let mut wait = deferrals.wait();
+    assert!((&mut wait).now_or_never().is_none());
+
+    // Completing a deferral more than once only counts once.
+    first.Complete()?;
+    first.Complete()?;
+    assert!((&mut wait).now_or_never().is_none());
+
+    second.Complete()?;
+    assert_eq!(block_on(wait), Ok(()));
+    Ok(())
+}
+
+#[test]
+fn call() -> Result<()> {
+    let event = Event::<EventHandler<i32>>::new();
+    let deferrals = Deferrals::new();
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    event.add(&EventHandler::new({
+        let deferrals = deferrals.clone();
+        move |_, _| {
+            sender.send(deferrals.get_deferral()?).unwrap();
+            Ok(())
+        }
+    }))?;
+
+    event.add(&EventHandler::new(|_, _| Err(Error::from(E_FAIL))))?;
+
+    let mut future = deferrals.call(&event, |handler| handler.Invoke(None, 0));
+
+    // The handlers have returned but the deferral is still pending.
+    let deferral = receiver.try_recv().unwrap();
+    assert!((&mut future).now_or_never().is_none());
+
+    deferral.Complete()?;
+    assert_eq!(block_on(future).unwrap_err().code(), E_FAIL);
+    Ok(())
+}
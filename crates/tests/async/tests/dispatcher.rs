@@ -0,0 +1,61 @@
+use futures::executor::block_on;
+use std::sync::mpsc::channel;
+use windows::{core::*, System::*};
+
+#[test]
+fn resume() -> Result<()> {
+    let executor = DispatcherQueueExecutor::dedicated()?;
+    let queue = executor.queue().clone();
+    let (sender, receiver) = channel();
+
+    executor.spawn(async move {
+        let result = async {
+            assert!(queue.HasThreadAccess()?);
+
+            resume_background().await?;
+            assert!(!queue.HasThreadAccess()?);
+
+            resume_on(&queue).await?;
+            assert!(queue.HasThreadAccess()?);
+            Ok(())
+        };
+
+        sender.send(result.await).unwrap();
+    })?;
+
+    receiver.recv().unwrap()
+}
+
+#[test]
+fn spawn_local() -> Result<()> {
+    let executor = DispatcherQueueExecutor::dedicated()?;
+
+    // A local future may only be spawned from the queue's own thread.
+    let error = executor.spawn_local(async {}).unwrap_err();
+    assert_eq!(error.code(), HRESULT(0x8001010Eu32 as i32)); // RPC_E_WRONG_THREAD
+
+    let (sender, receiver) = channel();
+    let queue = executor.queue().clone();
+
+    executor.spawn(async move {
+        let local = std::rc::Rc::new(123);
+        let executor = DispatcherQueueExecutor::new(queue);
+
+        let result = executor.spawn_local(async move {
+            let error = resume_background().await.unwrap_err();
+            assert_eq!(error.code(), HRESULT(0x8000000Eu32 as i32)); // E_ILLEGAL_METHOD_CALL
+            sender.send(*local).unwrap();
+        });
+
+        assert!(result.is_ok());
+    })?;
+
+    assert_eq!(receiver.recv().unwrap(), 123);
+    Ok(())
+}
+
+#[test]
+fn outside_executor() {
+    let error = block_on(resume_background()).unwrap_err();
+    assert_eq!(error.code(), HRESULT(0x8000000Eu32 as i32)); // E_ILLEGAL_METHOD_CALL
+}
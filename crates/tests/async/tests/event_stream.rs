@@ -0,0 +1,70 @@
+use futures::{executor::block_on, FutureExt, StreamExt};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use windows::{core::*, Devices::Enumeration::*, Foundation::*};
+
+#[test]
+fn typed() -> Result<()> {
+    let handler = Rc::new(RefCell::new(None::<TypedEventHandler<i32, i32>>));
+    let removed = Arc::new(Mutex::new(None));
+
+    let mut stream = {
+        let added = handler.clone();
+        let removed = removed.clone();
+
+        EventStream::new(
+            move |handler| {
+                *added.borrow_mut() = Some(handler.clone());
+                Ok(EventRegistrationToken { Value: 123 })
+            },
+            move |token| {
+                *removed.lock().unwrap() = Some(token.Value);
+                Ok(())
+            },
+        )?
+    };
+
+    assert!(stream.next().now_or_never().is_none());
+
+    let invoke = handler.borrow().clone().unwrap();
+    invoke.Invoke(0, 1)?;
+    invoke.Invoke(0, 2)?;
+
+    assert_eq!(block_on(stream.next()), Some(1));
+    assert_eq!(block_on(stream.next()), Some(2));
+    assert_eq!(*removed.lock().unwrap(), None);
+
+    drop(stream);
+    assert_eq!(*removed.lock().unwrap(), Some(123));
+    Ok(())
+}
+
+#[test]
+fn untyped() -> Result<()> {
+    let handler = Rc::new(RefCell::new(None::<EventHandler<HSTRING>>));
+    let added = handler.clone();
+
+    let mut stream = EventStream::from_event_handler(
+        move |handler| {
+            *added.borrow_mut() = Some(handler.clone());
+            Ok(EventRegistrationToken::default())
+        },
+        |_| Ok(()),
+    )?;
+
+    let invoke = handler.borrow().clone().unwrap();
+    invoke.Invoke(None, h!("hello"))?;
+    assert_eq!(block_on(stream.next()), Some(h!("hello").clone()));
+    stream.close()
+}
+
+#[test]
+fn watcher() -> Result<()> {
+    let watcher = DeviceInformation::CreateWatcher()?;
+    let stream = EventStream::new(
+        |handler| watcher.Added(handler),
+        |token| watcher.RemoveAdded(token),
+    )?;
+    stream.close()
+}
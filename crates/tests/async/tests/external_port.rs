@@ -0,0 +1,87 @@
+use futures::executor::block_on;
+use windows::{core::*, Win32::Foundation::*, Win32::Storage::FileSystem::*, Win32::System::IO::*};
+
+fn create_file(name: &str) -> Result<Owned<HANDLE>> {
+    let path = std::env::temp_dir().join(name);
+
+    unsafe {
+        Ok(Owned::new(CreateFileW(
+            &HSTRING::from(path.as_path()),
+            (FILE_GENERIC_READ | FILE_GENERIC_WRITE).0,
+            FILE_SHARE_NONE,
+            None,
+            CREATE_ALWAYS,
+            FILE_FLAG_OVERLAPPED | FILE_FLAG_DELETE_ON_CLOSE,
+            None,
+        )?))
+    }
+}
+
+#[test]
+fn reactor() -> Result<()> {
+    // The port is owned by the "reactor", which is this test.
+    let port = unsafe { Owned::new(CreateIoCompletionPort(INVALID_HANDLE_VALUE, None, 0, 1)?) };
+    let shared = unsafe { CompletionPort::external(*port, 7) };
+    assert_eq!(shared.key(), 7);
+
+    let file = shared.associate(create_file("test_async_external_port.txt")?)?;
+    let write = file.write(0, b"hello".to_vec());
+
+    let packet = CompletionPacket::dequeue(*port, 5000)?.unwrap();
+    assert_eq!(packet.key, 7);
+    assert_eq!(packet.bytes, 5);
+    assert!(packet.result.is_ok());
+    unsafe { shared.complete(packet) };
+    assert_eq!(block_on(write)?, 5);
+
+    // Failures are decoded from the packets dequeued in bulk in the same way.
+    let read = file.read(100, vec![0; 3]);
+    unsafe { PostQueuedCompletionStatus(*port, 3, 1, None)? };
+
+    let mut entries = [OVERLAPPED_ENTRY::default(); 4];
+    let mut packets: Vec<_> = Vec::new();
+
+    while packets.len() < 2 {
+        packets.extend(CompletionPacket::dequeue_many(*port, &mut entries, 5000)?);
+    }
+
+    packets.sort_by_key(|packet| packet.key);
+    assert_eq!((packets[0].key, packets[0].bytes), (1, 3));
+    assert!(packets[0].overlapped.is_null());
+
+    let packet = packets.pop().unwrap();
+    assert_eq!(packet.key, 7);
+    assert_eq!(
+        packet.result.as_ref().unwrap_err().code(),
+        ERROR_HANDLE_EOF.to_hresult()
+    );
+    unsafe { shared.complete(packet) };
+    assert_eq!(
+        block_on(read).unwrap_err().code(),
+        ERROR_HANDLE_EOF.to_hresult()
+    );
+
+    assert!(CompletionPacket::dequeue(*port, 0)?.is_none());
+    assert_eq!(
+        CompletionPacket::dequeue_many(*port, &mut entries, 0)?.count(),
+        0
+    );
+    Ok(())
+}
+
+#[test]
+fn raw_handle() -> Result<()> {
+    let port = unsafe { Owned::new(CreateIoCompletionPort(INVALID_HANDLE_VALUE, None, 0, 1)?) };
+    let file = create_file("test_async_external_port_raw.txt")?;
+    associate_handle(*port, *file, 9)?;
+
+    let mut overlapped = OVERLAPPED::default();
+    let result = unsafe { WriteFile(*file, Some(b"abc"), None, Some(&mut overlapped)) };
+    assert!(result.is_ok() || result.unwrap_err().code() == ERROR_IO_PENDING.to_hresult());
+
+    let packet = CompletionPacket::dequeue(*port, 5000)?.unwrap();
+    assert_eq!(packet.key, 9);
+    assert_eq!(packet.bytes, 3);
+    assert_eq!(packet.overlapped, &mut overlapped as *mut _);
+    Ok(())
+}
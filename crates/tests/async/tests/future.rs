@@ -0,0 +1,39 @@
+use futures::executor::block_on;
+use std::future::IntoFuture;
+use windows::{core::*, Foundation::*, Storage::Streams::*};
+
+#[test]
+fn await_action() -> Result<()> {
+    block_on(async {
+        let stream = &InMemoryRandomAccessStream::new()?;
+        let writer = DataWriter::CreateDataWriter(stream)?;
+        writer.WriteByte(1)?;
+        writer.WriteByte(2)?;
+        writer.WriteByte(3)?;
+        assert_eq!(writer.StoreAsync()?.await?, 3);
+
+        stream.Seek(0)?;
+        let reader = DataReader::CreateDataReader(stream)?;
+        assert_eq!(reader.LoadAsync(3)?.await?, 3);
+
+        let mut bytes: [u8; 3] = [0; 3];
+        reader.ReadBytes(&mut bytes)?;
+        assert_eq!(bytes, [1, 2, 3]);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn await_completed() -> Result<()> {
+    let stream = &InMemoryRandomAccessStream::new()?;
+    let writer = DataWriter::CreateDataWriter(stream)?;
+    writer.WriteByte(1)?;
+    let operation = writer.StoreAsync()?;
+    assert_eq!(operation.get()?, 1);
+    assert_eq!(operation.Status()?, AsyncStatus::Completed);
+
+    // Awaiting an operation that has already completed returns the results immediately.
+    assert_eq!(block_on(operation.into_future())?, 1);
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use futures::executor::block_on;
+use std::io::{Read, Write};
+use std::time::Duration;
+use windows::{core::*, Win32::Foundation::*, Win32::System::Pipes::*, Win32::System::IO::*};
+
+#[test]
+fn byte_mode() -> Result<()> {
+    let name = r"\\.\pipe\test_named_pipe_byte";
+    let server = NamedPipeServer::new(name).max_instances(1).create()?;
+    let mut client = NamedPipeClient::new(name).open()?;
+    server.connect()?;
+
+    client.write_all(b"hello world").unwrap();
+    let mut buffer = [0; 5];
+    assert_eq!(server.read(&mut buffer)?, 5);
+    assert_eq!(&buffer, b"hello");
+    assert_eq!(server.read(&mut buffer)?, 5);
+    assert_eq!(&buffer, b" worl");
+
+    // Reading from a pipe whose other end was closed is reported as the end of the stream.
+    assert_eq!(server.read(&mut buffer)?, 1);
+    drop(client);
+    assert_eq!(server.read(&mut buffer)?, 0);
+
+    let mut server = server;
+    let mut rest = Vec::new();
+    assert_eq!(server.read_to_end(&mut rest).unwrap(), 0);
+    Ok(())
+}
+
+#[test]
+fn message_mode() -> Result<()> {
+    let name = r"\\.\pipe\test_named_pipe_message";
+    let server = NamedPipeServer::new(name)
+        .message_mode()
+        .first_instance()
+        .create()?;
+    let client = NamedPipeClient::new(name).message_mode().open()?;
+    server.connect()?;
+
+    let large = vec![7; 10_000];
+    assert_eq!(client.write(b"first")?, 5);
+    assert_eq!(client.write(&large)?, large.len());
+    assert_eq!(server.read_message()?, b"first");
+    assert_eq!(server.read_message()?, large);
+
+    assert_eq!(server.write(b"reply")?, 5);
+    let mut buffer = [0; 3];
+    let error = client.read(&mut buffer).unwrap_err();
+    assert_eq!(error.code(), ERROR_MORE_DATA.to_hresult());
+    assert_eq!(&buffer, b"rep");
+    assert_eq!(client.read(&mut buffer)?, 2);
+    assert_eq!(&buffer[..2], b"ly");
+
+    // Only one instance may be created when the first instance is requested.
+    let error = NamedPipeServer::new(name)
+        .first_instance()
+        .create()
+        .unwrap_err();
+    assert_eq!(error.code(), ERROR_ACCESS_DENIED.to_hresult());
+    Ok(())
+}
+
+#[test]
+fn wait() -> Result<()> {
+    let name = r"\\.\pipe\test_named_pipe_wait";
+    let server = NamedPipeServer::new(name).max_instances(1).create()?;
+    let _first = NamedPipeClient::new(name).open()?;
+    server.connect()?;
+
+    // All instances are busy, so a second client fails immediately or once its wait times out.
+    let error = NamedPipeClient::new(name).open().unwrap_err();
+    assert_eq!(error.code(), ERROR_PIPE_BUSY.to_hresult());
+    let error = NamedPipeClient::new(name)
+        .wait(Duration::from_millis(10))
+        .open()
+        .unwrap_err();
+    assert_eq!(error.code(), ERROR_SEM_TIMEOUT.to_hresult());
+
+    let waiting = std::thread::spawn(move || {
+        NamedPipeClient::new(name)
+            .wait(Duration::from_secs(10))
+            .read_only()
+            .open()
+    });
+    server.disconnect()?;
+    server.connect()?;
+    let second = waiting.join().unwrap()?;
+    assert_eq!(server.write(b"ok")?, 2);
+    let mut buffer = [0; 2];
+    assert_eq!(second.read(&mut buffer)?, 2);
+    assert_eq!(&buffer, b"ok");
+    Ok(())
+}
+
+#[test]
+fn overlapped() -> Result<()> {
+    let port = CompletionPort::new()?;
+    let name = r"\\.\pipe\test_named_pipe_overlapped";
+    let server = NamedPipeServer::new(name).overlapped().create()?;
+    let server = port.associate(server.into_handle())?;
+    let connected = server.connect_named_pipe();
+
+    let client = NamedPipeClient::new(name).overlapped().open()?;
+    let client = port.associate(client.into_handle())?;
+
+    block_on(async {
+        connected.await?;
+        assert_eq!(client.write(0, b"ping".to_vec()).await?, 4);
+        assert_eq!(server.read(0, vec![0; 16]).await?, b"ping");
+        Ok(())
+    })
+}
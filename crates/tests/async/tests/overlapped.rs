@@ -0,0 +1,117 @@
+use futures::executor::block_on;
+use windows::{
+    core::*, Win32::Foundation::*, Win32::Storage::FileSystem::*, Win32::System::Pipes::*,
+    Win32::System::IO::*,
+};
+
+#[test]
+fn file() -> Result<()> {
+    let port = CompletionPort::new()?;
+    let path = std::env::temp_dir().join("test_async_overlapped.txt");
+
+    let file = unsafe {
+        Owned::new(CreateFileW(
+            &HSTRING::from(path.as_path()),
+            (FILE_GENERIC_READ | FILE_GENERIC_WRITE).0,
+            FILE_SHARE_NONE,
+            None,
+            CREATE_ALWAYS,
+            FILE_FLAG_OVERLAPPED | FILE_FLAG_DELETE_ON_CLOSE,
+            None,
+        )?)
+    };
+    let file = port.associate(file)?;
+
+    block_on(async {
+        assert_eq!(file.write(0, b"hello".to_vec()).await?, 5);
+        assert_eq!(file.write(5, b" world".to_vec()).await?, 6);
+        assert_eq!(file.read(0, vec![0; 64]).await?, b"hello world");
+        assert_eq!(file.read(6, vec![0; 3]).await?, b"wor");
+
+        // Reading past the end of the file is reported as an error rather than as an empty read.
+        let error = file.read(100, vec![0; 3]).await.unwrap_err();
+        assert_eq!(error.code(), ERROR_HANDLE_EOF.to_hresult());
+        Ok(())
+    })
+}
+
+#[test]
+fn pipe() -> Result<()> {
+    let port = CompletionPort::new()?;
+    let name = h!(r"\\.\pipe\test_async_overlapped");
+
+    let server = unsafe {
+        CreateNamedPipeW(
+            name,
+            FILE_FLAG_OVERLAPPED | PIPE_ACCESS_DUPLEX,
+            PIPE_TYPE_BYTE,
+            1,
+            0,
+            0,
+            0,
+            None,
+        )
+    };
+    assert!(!server.is_invalid());
+    let server = port.associate(unsafe { Owned::new(server) })?;
+    let connected = server.connect_named_pipe();
+
+    let client = unsafe {
+        Owned::new(CreateFileW(
+            name,
+            (FILE_GENERIC_READ | FILE_GENERIC_WRITE).0,
+            FILE_SHARE_NONE,
+            None,
+            OPEN_EXISTING,
+            FILE_FLAG_OVERLAPPED,
+            None,
+        )?)
+    };
+    let client = port.associate(client)?;
+
+    block_on(async {
+        connected.await?;
+        assert_eq!(client.write(0, b"ping".to_vec()).await?, 4);
+        assert_eq!(server.read(0, vec![0; 16]).await?, b"ping");
+
+        // A client that connected before the server started waiting is accepted immediately.
+        server.connect_named_pipe().await
+    })
+}
+
+#[test]
+fn cancel() -> Result<()> {
+    let port = CompletionPort::new()?;
+    let name = h!(r"\\.\pipe\test_async_overlapped_cancel");
+
+    let server = unsafe {
+        CreateNamedPipeW(
+            name,
+            FILE_FLAG_OVERLAPPED | PIPE_ACCESS_DUPLEX,
+            PIPE_TYPE_BYTE,
+            1,
+            0,
+            0,
+            0,
+            None,
+        )
+    };
+    let server = port.associate(unsafe { Owned::new(server) })?;
+
+    // Dropping a pending operation cancels it, after which the pipe can wait for a client again.
+    drop(server.connect_named_pipe());
+
+    let connected = server.connect_named_pipe();
+    let _client = unsafe {
+        Owned::new(CreateFileW(
+            name,
+            FILE_GENERIC_READ.0,
+            FILE_SHARE_NONE,
+            None,
+            OPEN_EXISTING,
+            FILE_FLAG_OVERLAPPED,
+            None,
+        )?)
+    };
+    block_on(connected)
+}
@@ -0,0 +1,53 @@
+use futures::{executor::block_on, StreamExt};
+use windows::{core::*, Foundation::*, Storage::Streams::*};
+
+fn buffer(bytes: &[u8]) -> Result<IBuffer> {
+    let writer = DataWriter::new()?;
+    writer.WriteBytes(bytes)?;
+    writer.DetachBuffer()
+}
+
+#[test]
+fn stream() -> Result<()> {
+    block_on(async {
+        let stream = InMemoryRandomAccessStream::new()?;
+        let operation = stream.WriteAsync(&buffer(&[1, 2, 3, 4])?)?;
+        let mut progress = operation.progress_stream()?;
+
+        // Any progress reported must be monotonic and bounded by the size of the buffer.
+        let mut previous = 0;
+        while let Some(written) = progress.next().await {
+            assert!(written >= previous);
+            assert!(written <= 4);
+            previous = written;
+        }
+
+        assert_eq!(progress.await?, 4);
+        assert_eq!(operation.Status()?, AsyncStatus::Completed);
+        assert_eq!(stream.Size()?, 4);
+        Ok(())
+    })
+}
+
+#[test]
+fn result_only() -> Result<()> {
+    block_on(async {
+        let stream = InMemoryRandomAccessStream::new()?;
+        let operation = stream.WriteAsync(&buffer(&[1, 2])?)?;
+
+        // The final result may be awaited without draining the progress values.
+        assert_eq!(operation.progress_stream()?.await?, 2);
+        Ok(())
+    })
+}
+
+#[test]
+fn completed_handler_claimed() -> Result<()> {
+    let stream = InMemoryRandomAccessStream::new()?;
+    let operation = stream.WriteAsync(&buffer(&[1])?)?;
+    let _progress = operation.progress_stream()?;
+
+    // The progress stream owns the `Progress` and `Completed` handlers, which may only be set once.
+    assert!(operation.progress_stream().is_err());
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use windows::{
+    core::*, Foundation::*, Storage::Streams::*, System::Threading::*, Win32::System::Com::*,
+};
+
+#[test]
+fn sta() -> Result<()> {
+    std::thread::spawn(|| unsafe {
+        CoInitializeEx(None, COINIT_APARTMENTTHREADED).ok()?;
+
+        let action = ThreadPool::RunAsync(&WorkItemHandler::new(|_| {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            Ok(())
+        }))?;
+
+        action.get_pumping()?;
+        assert_eq!(action.Status()?, AsyncStatus::Completed);
+
+        let stream = &InMemoryRandomAccessStream::new()?;
+        let writer = DataWriter::CreateDataWriter(stream)?;
+        writer.WriteByte(1)?;
+        assert_eq!(writer.StoreAsync()?.get_pumping()?, 1);
+
+        CoUninitialize();
+        Ok(())
+    })
+    .join()
+    .unwrap()
+}
+
+#[test]
+fn mta() -> Result<()> {
+    std::thread::spawn(|| {
+        // Outside of an STA, there is nothing to pump and the wait simply blocks.
+        let action = ThreadPool::RunAsync(&WorkItemHandler::new(|_| Ok(())))?;
+        action.get_pumping()?;
+        assert_eq!(action.Status()?, AsyncStatus::Completed);
+        Ok(())
+    })
+    .join()
+    .unwrap()
+}
@@ -0,0 +1,42 @@
+use futures::executor::block_on;
+use std::time::Duration;
+use windows::{core::*, Foundation::*, Storage::Streams::*, System::Threading::*};
+
+#[test]
+fn expired() -> Result<()> {
+    let action = ThreadPool::RunAsync(&WorkItemHandler::new(|action| {
+        let action = action.unwrap();
+
+        while action.Status()? != AsyncStatus::Canceled {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        Ok(())
+    }))?;
+
+    let error = block_on(action.clone().timeout(Duration::from_millis(50))).unwrap_err();
+    assert_eq!(error.code(), HRESULT(0x800705B4u32 as i32)); // ERROR_TIMEOUT
+
+    while action.Status()? == AsyncStatus::Started {
+        std::thread::sleep(Duration::from_millis(10));
+    }
+
+    assert_eq!(action.Status()?, AsyncStatus::Canceled);
+    Ok(())
+}
+
+#[test]
+fn completed() -> Result<()> {
+    let action = ThreadPool::RunAsync(&WorkItemHandler::new(|_| Ok(())))?;
+    block_on(action.timeout(Duration::from_secs(60)))?;
+
+    let stream = &InMemoryRandomAccessStream::new()?;
+    let writer = DataWriter::CreateDataWriter(stream)?;
+    writer.WriteByte(1)?;
+    let operation = writer.StoreAsync()?;
+    assert_eq!(operation.get()?, 1);
+
+    // An operation that has already completed wins over an expired timer.
+    assert_eq!(block_on(operation.timeout(Duration::ZERO))?, 1);
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use futures::{executor::block_on, FutureExt};
+use std::os::windows::io::AsRawHandle;
+use std::time::Duration;
+use windows::{core::*, Win32::Foundation::*, Win32::System::Threading::*};
+
+#[test]
+fn event() -> Result<()> {
+    unsafe {
+        let event = Owned::new(CreateEventW(None, true, false, None)?);
+        let mut future = wait_async(*event);
+        assert!((&mut future).now_or_never().is_none());
+
+        SetEvent(*event)?;
+        block_on(future)?;
+
+        // An object that is already signaled completes immediately.
+        block_on(wait_async(*event))
+    }
+}
+
+#[test]
+fn thread() -> Result<()> {
+    let thread = std::thread::spawn(|| std::thread::sleep(Duration::from_millis(10)));
+    unsafe { block_on(wait_async(HANDLE(thread.as_raw_handle()))) }
+}
+
+#[test]
+fn timeout() -> Result<()> {
+    unsafe {
+        let event = Owned::new(CreateEventW(None, true, false, None)?);
+        let error = block_on(wait_async(*event).timeout(Duration::from_millis(10))).unwrap_err();
+        assert_eq!(error.code(), HRESULT(0x800705B4u32 as i32)); // ERROR_TIMEOUT
+
+        SetEvent(*event)?;
+        block_on(wait_async(*event).timeout(Duration::from_secs(60)))
+    }
+}
@@ -0,0 +1,193 @@
+use futures::executor::block_on;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::JoinHandle;
+use windows::{core::*, Win32::Networking::WinHttp::*};
+
+// Serves a single request, replying with the given status and echoing the request's method, path,
+// and body. Returns the base URL along with the server thread.
+fn serve(status: &'static str) -> (String, JoinHandle<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let server = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(&stream);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        let mut len = 0;
+
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+
+            if line == "\r\n" {
+                break;
+            }
+
+            if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                len = value.trim().parse().unwrap();
+            }
+        }
+
+        let mut body = vec![0; len];
+        reader.read_exact(&mut body).unwrap();
+
+        let mut reply = request_line.trim_end().as_bytes().to_vec();
+        reply.push(b' ');
+        reply.extend_from_slice(&body);
+
+        write!(
+            &stream,
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nX-Test: winhttp\r\nConnection: close\r\n\r\n",
+            reply.len()
+        )
+        .unwrap();
+        (&stream).write_all(&reply).unwrap();
+    });
+
+    (url, server)
+}
+
+fn builder() -> HttpSessionBuilder {
+    HttpSessionBuilder::new("windows-rs").proxy(HttpProxy::None)
+}
+
+#[test]
+fn send() -> Result<()> {
+    let (url, server) = serve("200 OK");
+    let session = builder().open()?;
+    assert!(!session.is_async());
+
+    let request = session.request("POST", &format!("{url}/echo?x=1"))?;
+    request.header("Content-Type", "text/plain")?;
+    let response = request.send(b"hello")?;
+
+    assert_eq!(response.status(), 200);
+    assert_eq!(response.header("x-test")?.as_deref(), Some("winhttp"));
+    assert_eq!(response.header("X-Missing")?, None);
+    assert!(response.headers()?.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert_eq!(response.text()?, "POST /echo?x=1 HTTP/1.1 hello");
+
+    server.join().unwrap();
+    Ok(())
+}
+
+#[test]
+fn stream() -> Result<()> {
+    let (url, server) = serve("201 Created");
+    let connection = builder().open()?.connect(
+        "127.0.0.1",
+        url.rsplit(':').next().unwrap().parse().unwrap(),
+        false,
+    )?;
+    let mut body = connection.request("PUT", "/upload")?.stream(10)?;
+    body.write_all(b"01234")?;
+    body.write_all(b"56789")?;
+
+    let mut response = body.finish()?;
+    assert_eq!(response.status(), 201);
+    let mut text = String::new();
+    response.read_to_string(&mut text)?;
+    assert_eq!(text, "PUT /upload HTTP/1.1 0123456789");
+
+    server.join().unwrap();
+    Ok(())
+}
+
+#[test]
+fn errors() -> Result<()> {
+    let session = builder().open()?;
+    assert!(session.request("GET", "not a url").is_err());
+
+    // Nothing listens on the port once the listener has been dropped.
+    let port = TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    let error = session
+        .request("GET", &format!("http://127.0.0.1:{port}/"))?
+        .send(&[])
+        .err()
+        .unwrap();
+    assert_eq!(
+        error.code(),
+        HRESULT::from_win32(ERROR_WINHTTP_CANNOT_CONNECT)
+    );
+
+    // Servers that don't switch protocols can't be upgraded to a WebSocket.
+    let (url, server) = serve("200 OK");
+    assert!(session.request("GET", &url)?.upgrade().is_err());
+    server.join().unwrap();
+
+    // Synchronous sessions don't support the asynchronous methods, and vice versa.
+    assert!(block_on(
+        session
+            .request("GET", "http://127.0.0.1/")?
+            .send_async(Vec::new())
+    )
+    .is_err());
+    let session = builder().asynchronous().open()?;
+    assert!(session
+        .request("GET", "http://127.0.0.1/")?
+        .send(&[])
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn send_async() -> Result<()> {
+    let (url, server) = serve("200 OK");
+    let session = builder().asynchronous().open()?;
+    assert!(session.is_async());
+
+    let response = block_on(
+        session
+            .request("POST", &format!("{url}/async"))?
+            .send_async(b"hello".to_vec()),
+    )?;
+    assert_eq!(response.status(), 200);
+    assert_eq!(
+        block_on(response.bytes_async())?,
+        b"POST /async HTTP/1.1 hello"
+    );
+
+    server.join().unwrap();
+    Ok(())
+}
+
+#[test]
+fn stream_async() -> Result<()> {
+    let (url, server) = serve("200 OK");
+    let session = builder().asynchronous().open()?;
+
+    block_on(async {
+        let mut body = session
+            .request("PUT", &format!("{url}/upload"))?
+            .stream_async(6)
+            .await?;
+        assert_eq!(body.write_async(b"abc".to_vec()).await?, 3);
+        assert_eq!(body.write_async(b"def".to_vec()).await?, 3);
+
+        let mut response = body.finish_async().await?;
+        let mut text = Vec::new();
+
+        loop {
+            let buffer = response.read_async(4).await?;
+
+            if buffer.is_empty() {
+                break;
+            }
+
+            assert!(buffer.len() <= 4);
+            text.extend_from_slice(&buffer);
+        }
+
+        assert_eq!(text, b"PUT /upload HTTP/1.1 abcdef");
+        Ok::<_, Error>(())
+    })?;
+
+    server.join().unwrap();
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use futures::{executor::block_on, StreamExt};
+use std::process::{Child, Command};
+use std::time::Duration;
+use windows::{core::*, Win32::System::Com::*, Win32::System::Wmi::*};
+
+fn connect() -> Result<WmiConnection> {
+    unsafe { CoInitializeEx(None, COINIT_MULTITHREADED).ok()? };
+    WmiConnection::new()
+}
+
+// Starts a process that lives long enough for WMI's polling interval to notice it.
+fn spawn() -> Child {
+    Command::new("ping")
+        .args(["-n", "10", "127.0.0.1"])
+        .spawn()
+        .unwrap()
+}
+
+const CREATED: &str = "SELECT * FROM __InstanceCreationEvent WITHIN 1 WHERE TargetInstance ISA 'Win32_Process' AND TargetInstance.Name = 'PING.EXE'";
+
+#[test]
+fn query() -> Result<()> {
+    let wmi = connect()?;
+    let id = std::process::id();
+
+    let mut processes = wmi.query(&format!(
+        "SELECT Name, ProcessId FROM Win32_Process WHERE ProcessId = {id}"
+    ))?;
+    let process = processes.next().unwrap()?;
+    assert!(processes.next().is_none());
+
+    assert_eq!(process.class_name()?, "Win32_Process");
+    assert_eq!(process.get::<u32>("ProcessId")?, Some(id));
+    assert!(process
+        .get::<BSTR>("Name")?
+        .unwrap()
+        .to_string()
+        .ends_with(".exe"));
+
+    let names = process.names()?;
+    assert!(names.contains(&"Name".to_string()));
+    assert!(names.contains(&"ProcessId".to_string()));
+    assert!(!names.contains(&"__CLASS".to_string()));
+
+    // The System process has no executable path, so the property is null.
+    let system = wmi.get(r#"Win32_Process.Handle="4""#)?;
+    assert_eq!(system.get::<u32>("ProcessId")?, Some(4));
+    assert_eq!(system.get::<BSTR>("ExecutablePath")?, None);
+    Ok(())
+}
+
+#[test]
+fn errors() -> Result<()> {
+    let wmi = connect()?;
+
+    // Query errors are reported by the iterator, which then ends.
+    let mut objects = wmi.query("SELECT * FROM Test_Missing_Class")?;
+    assert!(objects.next().unwrap().is_err());
+    assert!(objects.next().is_none());
+
+    assert!(wmi.get("Test_Missing_Class").is_err());
+    assert!(WmiConnection::connect(r"ROOT\TEST_MISSING").is_err());
+    Ok(())
+}
+
+#[test]
+fn notifications() -> Result<()> {
+    let wmi = connect()?;
+    let notifications = wmi.notifications(CREATED)?;
+    assert!(notifications
+        .next(Some(Duration::from_millis(10)))?
+        .is_none());
+
+    let mut child = spawn();
+
+    // Processes started by other tests may be reported first.
+    let result = (|| loop {
+        let event = notifications.next(Some(Duration::from_secs(30)))?.unwrap();
+        assert_eq!(event.class_name()?, "__InstanceCreationEvent");
+        let process = event.get_object("TargetInstance")?.unwrap();
+
+        if process.get::<u32>("ProcessId")? == Some(child.id()) {
+            return Ok(());
+        }
+    })();
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    result
+}
+
+#[test]
+fn subscribe() -> Result<()> {
+    let wmi = connect()?;
+    let mut events = wmi.subscribe(CREATED)?;
+    let mut child = spawn();
+
+    let result = block_on(async {
+        loop {
+            let event = events.next().await.unwrap()?;
+            let process = event.get_object("TargetInstance")?.unwrap();
+
+            if process.get::<u32>("ProcessId")? == Some(child.id()) {
+                return Ok(());
+            }
+        }
+    });
+
+    child.kill().unwrap();
+    child.wait().unwrap();
+    result
+}
@@ -0,0 +1,31 @@
+use std::future::IntoFuture;
+use std::sync::mpsc::channel;
+use windows::{core::*, Storage::Streams::*, System::Threading::*};
+
+#[test]
+fn spawn() -> Result<()> {
+    let (sender, receiver) = channel();
+
+    let action = ThreadPool::RunAsync(&WorkItemHandler::new(move |_| {
+        receiver.recv().unwrap();
+        Ok(())
+    }))?;
+
+    async_std::task::block_on(async {
+        let action = async_std::task::spawn(action.into_future());
+        async_std::task::spawn(async move { sender.send(()).unwrap() });
+        action.await
+    })
+}
+
+#[test]
+fn store() -> Result<()> {
+    async_std::task::block_on(async {
+        let stream = InMemoryRandomAccessStream::new()?;
+        let writer = DataWriter::CreateDataWriter(&stream)?;
+        writer.WriteBytes(&[1, 2, 3])?;
+        let len = async_std::task::spawn(writer.StoreAsync()?.into_future()).await?;
+        assert_eq!(len, 3);
+        Ok(())
+    })
+}
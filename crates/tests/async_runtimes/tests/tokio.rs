@@ -0,0 +1,54 @@
+use std::future::IntoFuture;
+use std::sync::mpsc::{channel, Receiver};
+use windows::{core::*, Storage::Streams::*, System::Threading::*};
+
+// Runs a work item that only completes once it receives a value from another task.
+fn run_after(receiver: Receiver<()>) -> Result<impl std::future::Future<Output = Result<()>>> {
+    Ok(ThreadPool::RunAsync(&WorkItemHandler::new(move |_| {
+        receiver.recv().unwrap();
+        Ok(())
+    }))?
+    .into_future())
+}
+
+#[test]
+fn current_thread() -> Result<()> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let (sender, receiver) = channel();
+
+    runtime.block_on(async {
+        // The runtime has a single thread, so awaiting must yield to let the second task run.
+        let action = tokio::spawn(run_after(receiver)?);
+        tokio::spawn(async move { sender.send(()).unwrap() });
+        action.await.unwrap()
+    })
+}
+
+#[test]
+fn multi_thread() -> Result<()> {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .build()
+        .unwrap();
+
+    runtime.block_on(async {
+        let tasks: Vec<_> = (0..16)
+            .map(|len| {
+                tokio::spawn(async move {
+                    let stream = InMemoryRandomAccessStream::new()?;
+                    let writer = DataWriter::CreateDataWriter(&stream)?;
+                    writer.WriteBytes(&vec![0; len])?;
+                    writer.StoreAsync()?.await
+                })
+            })
+            .collect();
+
+        for (len, task) in tasks.into_iter().enumerate() {
+            assert_eq!(task.await.unwrap()?, len as u32);
+        }
+
+        Ok(())
+    })
+}
@@ -0,0 +1,83 @@
+use windows::{core::*, Win32::Foundation::*, Win32::Security::Cryptography::*};
+
+fn hex(value: &[u8]) -> String {
+    value.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[test]
+fn hash() -> Result<()> {
+    assert_eq!(
+        hex(&sha256(b"abc")?),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+
+    let algorithm = Algorithm::open(BCRYPT_SHA256_ALGORITHM)?;
+    assert_eq!(algorithm.hash_len()?, 32);
+
+    let mut hash = algorithm.hash()?;
+    hash.update(b"a")?;
+    hash.update(b"bc")?;
+    assert_eq!(hash.finish()?, sha256(b"abc")?);
+
+    Ok(())
+}
+
+#[test]
+fn hmac() -> Result<()> {
+    assert_eq!(
+        hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")?),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+
+    let algorithm = Algorithm::open_hmac(BCRYPT_SHA256_ALGORITHM)?;
+    let mut hash = algorithm.hmac(b"Jefe")?;
+    hash.update(b"what do ya want ")?;
+    hash.update(b"for nothing?")?;
+    assert_eq!(
+        hash.finish()?,
+        hmac_sha256(b"Jefe", b"what do ya want for nothing?")?
+    );
+
+    Ok(())
+}
+
+#[test]
+fn aes_gcm() -> Result<()> {
+    let mut key = [0; 32];
+    let mut nonce = [0; AesGcm::NONCE_LEN];
+    random(&mut key)?;
+    random(&mut nonce)?;
+    assert_ne!(key, [0; 32]);
+
+    let aes = AesGcm::new(&key)?;
+    let sealed = aes.encrypt(&nonce, b"attack at dawn", b"header")?;
+    assert_eq!(sealed.len(), 14 + AesGcm::TAG_LEN);
+    assert_eq!(aes.decrypt(&nonce, &sealed, b"header")?, b"attack at dawn");
+
+    let empty = aes.encrypt(&nonce, b"", b"")?;
+    assert_eq!(empty.len(), AesGcm::TAG_LEN);
+    assert!(aes.decrypt(&nonce, &empty, b"")?.is_empty());
+
+    let mismatch = Error::from(STATUS_AUTH_TAG_MISMATCH).code();
+    assert_eq!(
+        aes.decrypt(&nonce, &sealed, b"other").unwrap_err().code(),
+        mismatch
+    );
+
+    let mut tampered = sealed.clone();
+    tampered[0] ^= 1;
+    assert_eq!(
+        aes.decrypt(&nonce, &tampered, b"header")
+            .unwrap_err()
+            .code(),
+        mismatch
+    );
+    assert_eq!(
+        aes.decrypt(&nonce, &sealed[..8], b"header")
+            .unwrap_err()
+            .code(),
+        mismatch
+    );
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use windows::{core::*, Foundation::Collections::*};
+
+#[test]
+fn sort_and_search() -> Result<()> {
+    let v: IVector<i32> = [5, 3, 9, 1, 7].into_iter().collect();
+    v.sort_by(|a, b| a.cmp(b))?;
+    assert_eq!(v.clone().into_iter().collect::<Vec<_>>(), [1, 3, 5, 7, 9]);
+
+    assert_eq!(v.binary_search_by(|value| value.cmp(&7))?, Ok(3));
+    assert_eq!(v.binary_search_by(|value| value.cmp(&4))?, Err(2));
+    assert_eq!(v.binary_search_by(|value| value.cmp(&10))?, Err(5));
+
+    v.sort_by(|a, b| b.cmp(a))?;
+    assert_eq!(v.into_iter().collect::<Vec<_>>(), [9, 7, 5, 3, 1]);
+
+    let empty = IVector::<i32>::from(vec![]);
+    empty.sort_by(|a, b| a.cmp(b))?;
+    assert_eq!(empty.binary_search_by(|value| value.cmp(&1))?, Err(0));
+    Ok(())
+}
+
+#[test]
+fn retain() -> Result<()> {
+    let v: IVector<HSTRING> = ["apple", "banana", "cherry"]
+        .into_iter()
+        .map(HSTRING::from)
+        .collect();
+    v.retain(|value| value.len() > 5)?;
+    assert_eq!(
+        v.into_iter().collect::<Vec<_>>(),
+        [HSTRING::from("banana"), HSTRING::from("cherry")]
+    );
+    Ok(())
+}
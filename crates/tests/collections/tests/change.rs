@@ -0,0 +1,56 @@
+use std::sync::{Arc, Mutex};
+use windows::{core::*, Foundation::Boxable, Foundation::Collections::*};
+
+#[test]
+fn vector() -> Result<()> {
+    let v = IObservableVector::<i32>::from(vec![1]);
+    let changes = Arc::new(Mutex::new(vec![]));
+    let captured = changes.clone();
+
+    v.VectorChanged(&VectorChangedEventHandler::new(move |_, args| {
+        captured.lock().unwrap().push(args.unwrap().change()?);
+        Ok(())
+    }))?;
+
+    v.Append(2)?;
+    v.SetAt(0, 3)?;
+    v.RemoveAt(1)?;
+    v.Clear()?;
+
+    assert_eq!(
+        *changes.lock().unwrap(),
+        [
+            VectorChange::Inserted(1),
+            VectorChange::Changed(0),
+            VectorChange::Removed(1),
+            VectorChange::Reset
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn map() -> Result<()> {
+    let set = PropertySet::new()?;
+    let changes = Arc::new(Mutex::new(vec![]));
+    let captured = changes.clone();
+
+    set.MapChanged(&MapChangedEventHandler::new(move |_, args| {
+        captured.lock().unwrap().push(args.unwrap().change()?);
+        Ok(())
+    }))?;
+
+    set.Insert(h!("key"), &1i32.box_value()?)?;
+    set.Insert(h!("key"), &2i32.box_value()?)?;
+    set.Remove(h!("key"))?;
+
+    assert_eq!(
+        *changes.lock().unwrap(),
+        [
+            MapChange::Inserted(HSTRING::from("key")),
+            MapChange::Changed(HSTRING::from("key")),
+            MapChange::Removed(HSTRING::from("key"))
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use windows::{core::*, Foundation::Collections::*, Win32::Foundation::E_BOUNDS};
+
+#[test]
+fn iterator() -> Result<()> {
+    let iter = IIterator::<i32>::from_iterator(1..4);
+    assert!(iter.HasCurrent()?);
+    assert_eq!(iter.Current()?, 1);
+    assert_eq!(iter.Current()?, 1);
+    assert!(iter.MoveNext()?);
+    assert_eq!(iter.Current()?, 2);
+    assert!(iter.MoveNext()?);
+    assert!(!iter.MoveNext()?);
+    assert!(!iter.HasCurrent()?);
+    assert_eq!(iter.Current().unwrap_err().code(), E_BOUNDS);
+    assert!(!iter.MoveNext()?);
+
+    let iter = IIterator::<i32>::from_iterator(0..5);
+    let mut values = [0; 3];
+    assert_eq!(iter.GetMany(&mut values)?, 3);
+    assert_eq!(values, [0, 1, 2]);
+    assert_eq!(iter.Current()?, 3);
+    assert_eq!(iter.GetMany(&mut values)?, 2);
+    assert_eq!(values, [3, 4, 2]);
+    assert_eq!(iter.GetMany(&mut values)?, 0);
+    assert!(!iter.HasCurrent()?);
+    Ok(())
+}
+
+#[test]
+fn iterable() -> Result<()> {
+    let able = IIterable::<i32>::from_fn(|| (1..=3).map(|value| value * value));
+    assert_eq!(able.clone().into_iter().collect::<Vec<_>>(), [1, 4, 9]);
+    assert_eq!(able.into_iter().collect::<Vec<_>>(), [1, 4, 9]);
+
+    let able = IIterable::<HSTRING>::from_cloneable(["a", "b"].into_iter().map(HSTRING::from));
+    assert_eq!(able.clone().into_iter().count(), 2);
+    assert_eq!(
+        able.into_iter().collect::<Vec<_>>(),
+        [HSTRING::from("a"), HSTRING::from("b")]
+    );
+    Ok(())
+}
+
+#[test]
+fn lazy() -> Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let produced = Arc::new(AtomicUsize::new(0));
+    let counter = produced.clone();
+    let able = IIterable::<u64>::from_fn(move || {
+        let counter = counter.clone();
+        (0..).inspect(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        })
+    });
+
+    assert_eq!(produced.load(Ordering::Relaxed), 0);
+    let iter = able.First()?;
+    iter.MoveNext()?;
+    assert_eq!(iter.Current()?, 1);
+    assert_eq!(produced.load(Ordering::Relaxed), 2);
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use windows::{core::*, Foundation::Collections::*};
+
+#[test]
+fn vector_view() -> Result<()> {
+    let v: IVectorView<i32> = (1..=3).collect();
+    assert_eq!(v.Size()?, 3);
+
+    let mut sum = 0;
+    for value in &v {
+        sum += value;
+    }
+    assert_eq!(sum, 6);
+
+    let doubled: IVectorView<i32> = v.into_iter().map(|value| value * 2).collect();
+    assert_eq!(doubled.into_iter().collect::<Vec<_>>(), [2, 4, 6]);
+    Ok(())
+}
+
+#[test]
+fn iterable() -> Result<()> {
+    let able: IIterable<HSTRING> = ["a", "b", "c"].into_iter().map(HSTRING::from).collect();
+    let values: Vec<String> = able.into_iter().map(|value| value.to_string()).collect();
+    assert_eq!(values, ["a", "b", "c"]);
+
+    let empty: IIterable<IInspectable> = std::iter::empty().collect();
+    assert_eq!(empty.into_iter().count(), 0);
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use windows::{core::*, Foundation::Boxable, Foundation::Collections::*};
+
+#[test]
+fn tuple() -> Result<()> {
+    let map: IMap<HSTRING, i32> = [(HSTRING::from("one"), 1)].into_iter().collect();
+    let pair = map.First()?.Current()?;
+
+    let (key, value) = <(HSTRING, i32)>::try_from(&pair)?;
+    assert_eq!((key, value), (HSTRING::from("one"), 1));
+
+    let tuple: (HSTRING, i32) = pair.try_into()?;
+    assert_eq!(tuple.1, 1);
+    Ok(())
+}
+
+#[test]
+fn pairs() -> Result<()> {
+    let view: IMapView<i32, i32> = [(1, 10), (2, 20)].into_iter().collect();
+    let iterable: IIterable<IKeyValuePair<i32, i32>> = view.cast()?;
+
+    let mut sum = 0;
+    for (key, value) in iterable.pairs() {
+        sum += key * value;
+    }
+    assert_eq!(sum, 50);
+
+    let set = PropertySet::new()?;
+    set.insert_t(h!("key"), 123u32)?;
+    for (key, value) in set.iter()? {
+        assert_eq!(key, "key");
+        assert_eq!(u32::unbox_value(&value)?, 123);
+    }
+    Ok(())
+}
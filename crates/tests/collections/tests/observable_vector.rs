@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+use windows::{
+    core::*, Foundation::Collections::*, Foundation::EventRegistrationToken,
+    Win32::Foundation::E_BOUNDS,
+};
+
+type Changes = Arc<Mutex<Vec<(CollectionChange, u32)>>>;
+
+fn observe(v: &IObservableVector<i32>) -> Result<(EventRegistrationToken, Changes)> {
+    let changes = Arc::new(Mutex::new(vec![]));
+    let captured = changes.clone();
+
+    let token = v.VectorChanged(&VectorChangedEventHandler::new(move |_, args| {
+        let args = args.unwrap();
+        captured
+            .lock()
+            .unwrap()
+            .push((args.CollectionChange()?, args.Index()?));
+        Ok(())
+    }))?;
+
+    Ok((token, changes))
+}
+
+#[test]
+fn changes() -> Result<()> {
+    let v = IObservableVector::<i32>::from(vec![1, 2]);
+    let (token, changes) = observe(&v)?;
+
+    v.Append(3)?;
+    v.InsertAt(0, 0)?;
+    v.SetAt(1, 10)?;
+    v.RemoveAt(2)?;
+    v.RemoveAtEnd()?;
+    v.ReplaceAll(&[4, 5])?;
+    v.Clear()?;
+
+    // Failed changes don't raise the event.
+    assert_eq!(v.RemoveAtEnd().unwrap_err().code(), E_BOUNDS);
+    assert_eq!(v.SetAt(0, 1).unwrap_err().code(), E_BOUNDS);
+
+    assert_eq!(
+        *changes.lock().unwrap(),
+        [
+            (CollectionChange::ItemInserted, 2),
+            (CollectionChange::ItemInserted, 0),
+            (CollectionChange::ItemChanged, 1),
+            (CollectionChange::ItemRemoved, 2),
+            (CollectionChange::ItemRemoved, 1),
+            (CollectionChange::Reset, 0),
+            (CollectionChange::Reset, 0),
+        ]
+    );
+
+    v.RemoveVectorChanged(token)?;
+    v.Append(1)?;
+    assert_eq!(changes.lock().unwrap().len(), 7);
+    Ok(())
+}
+
+#[test]
+fn sender() -> Result<()> {
+    let v: IObservableVector<HSTRING> = [HSTRING::from("one")].into_iter().collect();
+    let sizes = Arc::new(Mutex::new(vec![]));
+    let captured = sizes.clone();
+
+    // The handler may read the vector while the event is raised.
+    v.VectorChanged(&VectorChangedEventHandler::new(move |sender, _| {
+        captured.lock().unwrap().push(sender.unwrap().Size()?);
+        Ok(())
+    }))?;
+
+    v.Append(h!("two"))?;
+    assert_eq!(*sizes.lock().unwrap(), [2]);
+
+    let view = v.GetView()?;
+    v.RemoveAt(0)?;
+    assert_eq!(view.Size()?, 1);
+    assert_eq!(v.into_iter().collect::<Vec<_>>(), [HSTRING::from("two")]);
+    Ok(())
+}
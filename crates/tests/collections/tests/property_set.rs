@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use windows::{core::*, Foundation::Collections::*, Foundation::*};
+
+#[test]
+fn boxing() -> Result<()> {
+    assert_eq!(u32::unbox_value(&123u32.box_value()?)?, 123);
+    assert_eq!(f64::unbox_value(&1.5f64.box_value()?)?, 1.5);
+    assert!(bool::unbox_value(&true.box_value()?)?);
+    assert_eq!(HSTRING::unbox_value(&h!("hello").box_value()?)?, "hello");
+    assert_eq!(
+        String::unbox_value(&"world".to_string().box_value()?)?,
+        "world"
+    );
+
+    let point = Point { X: 1.0, Y: 2.0 };
+    assert_eq!(Point::unbox_value(&point.box_value()?)?, point);
+
+    // Unboxing requires the exact type.
+    assert!(i32::unbox_value(&123u32.box_value()?).is_err());
+    Ok(())
+}
+
+#[test]
+fn property_set() -> Result<()> {
+    let set = PropertySet::new()?;
+    assert!(!set.insert_t(h!("count"), 42u32)?);
+    assert!(set.insert_t(h!("count"), 43u32)?);
+    set.insert_t(h!("name"), HSTRING::from("value"))?;
+
+    assert_eq!(set.get_t::<u32>(h!("count"))?, Some(43));
+    assert_eq!(
+        set.get_t::<HSTRING>(h!("name"))?,
+        Some(HSTRING::from("value"))
+    );
+    assert_eq!(set.get_t::<u32>(h!("missing"))?, None);
+    assert!(set.get_t::<u64>(h!("count")).is_err());
+    Ok(())
+}
+
+#[test]
+fn value_set() -> Result<()> {
+    let uri = Uri::CreateUri(h!("https://github.com/microsoft/windows-rs"))?;
+
+    let map = HashMap::from([
+        ("int".to_string(), Value::Int32(-1)),
+        ("char".to_string(), Value::Char16(b'x' as u16)),
+        ("string".to_string(), Value::String("text".into())),
+        ("uri".to_string(), Value::Inspectable(uri.cast()?)),
+    ]);
+
+    let set = ValueSet::try_from(map.clone())?;
+    assert_eq!(set.Size()?, 4);
+    assert_eq!(set.get_t::<i32>(h!("int"))?, Some(-1));
+
+    let round_trip = HashMap::<String, Value>::try_from(&set)?;
+    assert_eq!(round_trip, map);
+    Ok(())
+}
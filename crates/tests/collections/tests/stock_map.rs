@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use windows::{core::*, Foundation::Collections::*, Win32::Foundation::E_BOUNDS};
+
+#[test]
+fn primitive() -> Result<()> {
+    let m = IMap::<i32, u64>::from(HashMap::new());
+    assert_eq!(m.Lookup(1).unwrap_err().code(), E_BOUNDS);
+    assert_eq!(m.Remove(1).unwrap_err().code(), E_BOUNDS);
+    assert!(m.is_empty()?);
+
+    assert!(!m.Insert(1, 10)?);
+    assert!(!m.Insert(2, 20)?);
+    assert!(m.Insert(1, 11)?);
+    assert_eq!(m.Size()?, 2);
+    assert_eq!(m.Lookup(1)?, 11);
+    assert!(m.HasKey(2)?);
+
+    m.Remove(2)?;
+    assert!(!m.HasKey(2)?);
+    m.Clear()?;
+    assert_eq!(m.Size()?, 0);
+    Ok(())
+}
+
+#[test]
+fn helpers() -> Result<()> {
+    let m: IMap<HSTRING, i32> = [("one".into(), 1), ("two".into(), 2)].into_iter().collect();
+    assert_eq!(m.len()?, 2);
+    assert_eq!(m.get(h!("one"))?, Some(1));
+    assert_eq!(m.get(h!("three"))?, None);
+    assert!(m.contains_key(h!("two"))?);
+
+    assert!(!m.insert(h!("three"), 3)?);
+    assert!(m.remove(h!("three"))?);
+    assert!(!m.remove(h!("three"))?);
+
+    assert_eq!(m.get_or_insert_with(h!("one"), || 10)?, 1);
+    assert_eq!(m.get_or_insert_with(h!("four"), || 4)?, 4);
+
+    let mut entries: Vec<(String, i32)> = m
+        .iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+    entries.sort();
+    assert_eq!(
+        entries,
+        [
+            ("four".to_string(), 4),
+            ("one".to_string(), 1),
+            ("two".to_string(), 2)
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn view() -> Result<()> {
+    let m: IMap<i32, i32> = [(1, 10)].into_iter().collect();
+    let view = m.GetView()?;
+    let iterator = m.First()?;
+
+    // The view is live while existing iterators walk a snapshot.
+    m.Insert(2, 20)?;
+    assert_eq!(view.Size()?, 2);
+    assert_eq!(view.get(2)?, Some(20));
+    assert_eq!(view.get(3)?, None);
+    assert_eq!(iterator.into_iter().count(), 1);
+
+    let mut first = None;
+    let mut second = None;
+    view.Split(&mut first, &mut second)?;
+    assert!(first.is_none() && second.is_none());
+    Ok(())
+}
+
+#[test]
+fn map_view() -> Result<()> {
+    let view: IMapView<i32, i32> = [(2, 20), (1, 10)].into_iter().collect();
+    assert_eq!(view.len()?, 2);
+    assert_eq!(view.get(1)?, Some(10));
+    assert_eq!(view.iter().collect::<Vec<_>>(), [(1, 10), (2, 20)]);
+    Ok(())
+}
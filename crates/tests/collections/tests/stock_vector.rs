@@ -0,0 +1,91 @@
+use windows::{core::*, Foundation::Collections::*, Foundation::*, Win32::Foundation::E_BOUNDS};
+
+#[test]
+fn primitive() -> Result<()> {
+    let v = IVector::<i32>::from(vec![]);
+    assert_eq!(v.GetAt(0).unwrap_err().code(), E_BOUNDS);
+    assert_eq!(v.Size()?, 0);
+    assert_eq!(v.RemoveAtEnd().unwrap_err().code(), E_BOUNDS);
+
+    v.Append(1)?;
+    v.Append(3)?;
+    v.InsertAt(1, 2)?;
+    assert_eq!(v.InsertAt(4, 4).unwrap_err().code(), E_BOUNDS);
+    assert_eq!(v.Size()?, 3);
+    assert_eq!(v.GetAt(0)?, 1);
+    assert_eq!(v.GetAt(1)?, 2);
+    assert_eq!(v.GetAt(2)?, 3);
+
+    let mut index = 0;
+    assert!(v.IndexOf(3, &mut index)?);
+    assert_eq!(index, 2);
+    assert!(!v.IndexOf(4, &mut index)?);
+
+    v.SetAt(0, 10)?;
+    assert_eq!(v.SetAt(3, 10).unwrap_err().code(), E_BOUNDS);
+    v.RemoveAt(1)?;
+    assert_eq!(v.RemoveAt(2).unwrap_err().code(), E_BOUNDS);
+
+    let mut values = [0; 5];
+    assert_eq!(v.GetMany(0, &mut values)?, 2);
+    assert_eq!(values, [10, 3, 0, 0, 0]);
+
+    v.ReplaceAll(&[4, 5, 6])?;
+    v.RemoveAtEnd()?;
+    assert_eq!(v.into_iter().collect::<Vec<_>>(), [4, 5]);
+    Ok(())
+}
+
+#[test]
+fn view() -> Result<()> {
+    let v: IVector<HSTRING> = vec![h!("a").clone(), h!("b").clone()].into();
+    let view = v.GetView()?;
+    assert_eq!(view.Size()?, 2);
+
+    // The view reflects changes made to the vector.
+    v.Append(h!("c"))?;
+    assert_eq!(view.Size()?, 3);
+    assert_eq!(view.GetAt(2)?, "c");
+
+    let values: Vec<String> = view.into_iter().map(|value| value.to_string()).collect();
+    assert_eq!(values, ["a", "b", "c"]);
+
+    v.Clear()?;
+    assert!(!v.First()?.HasCurrent()?);
+    Ok(())
+}
+
+#[test]
+fn iterators() -> Result<()> {
+    let mut v: IVector<i32> = (1..=3).collect();
+    v.extend([4]);
+    v.try_extend([5])?;
+    assert_eq!(v.Size()?, 5);
+
+    let able: IIterable<i32> = v.cast()?;
+    let iterator = able.First()?;
+    let mut values = [0; 3];
+    assert_eq!(iterator.GetMany(&mut values)?, 3);
+    assert_eq!(values, [1, 2, 3]);
+    assert_eq!(iterator.Current()?, 4);
+    assert!(iterator.MoveNext()?);
+    assert!(!iterator.MoveNext()?);
+    assert!(!iterator.HasCurrent()?);
+
+    let mut sum = 0;
+    for value in &v {
+        sum += value;
+    }
+    assert_eq!(sum, 15);
+    Ok(())
+}
+
+#[test]
+fn objects() -> Result<()> {
+    let mut v = IVector::<IInspectable>::from(vec![None]);
+    v.extend([Some(PropertyValue::CreateInt32(1)?), None]);
+    assert_eq!(v.Size()?, 3);
+    assert!(v.GetAt(0).is_err());
+    assert_eq!(v.GetAt(1)?.cast::<IReference<i32>>()?.Value()?, 1);
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use windows::{core::*, Win32::Foundation::*};
+
+dyn_link! {
+    "kernel32.dll" "system" fn GetCurrentProcessId() -> u32;
+    "kernel32.dll" "system" fn SetThreadDescription(thread: HANDLE, description: PCWSTR) -> HRESULT;
+    "kernel32.dll" "system" fn NotARealFunction();
+    "not_a_real_library.dll" "system" fn GetCurrentThreadId() -> u32;
+}
+
+#[test]
+fn available() {
+    let get = GetCurrentProcessId().unwrap();
+    assert_eq!(unsafe { get() }, std::process::id());
+
+    // The cached address is returned the second time.
+    assert_eq!(
+        GetCurrentProcessId().map(|f| f as usize),
+        Some(get as usize)
+    );
+
+    if let Some(set) = SetThreadDescription() {
+        unsafe { set(HANDLE(-2isize as _), w!("dyn_link")).unwrap() };
+    }
+}
+
+#[test]
+fn missing() {
+    assert!(NotARealFunction().is_none());
+    assert!(NotARealFunction().is_none());
+    assert!(GetCurrentThreadId().is_none());
+}
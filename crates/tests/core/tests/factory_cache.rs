@@ -0,0 +1,69 @@
+use std::sync::{Arc, Barrier};
+use std::time::{Duration, Instant};
+use windows::{core::*, Foundation::*};
+
+const CONCURRENCY: usize = 8;
+const ITERATIONS: usize = 10_000;
+
+// Loads the `Uri` factory from many threads at once, all starting together, and returns the
+// time taken by the slowest thread.
+fn load_concurrently(load: fn() -> Result<()>) -> Duration {
+    let barrier = Arc::new(Barrier::new(CONCURRENCY));
+
+    let threads: Vec<_> = (0..CONCURRENCY)
+        .map(|_| {
+            let barrier = barrier.clone();
+            std::thread::spawn(move || {
+                barrier.wait();
+                let start = Instant::now();
+
+                for _ in 0..ITERATIONS {
+                    load().unwrap();
+                }
+
+                start.elapsed()
+            })
+        })
+        .collect();
+
+    threads
+        .into_iter()
+        .map(|thread| thread.join().unwrap())
+        .max()
+        .unwrap()
+}
+
+static CACHE: imp::FactoryCache<Uri, IUriRuntimeClassFactory> = imp::FactoryCache::new();
+
+fn cached() -> Result<()> {
+    CACHE.call(|factory| {
+        assert!(!factory.as_raw().is_null());
+        Ok(())
+    })
+}
+
+fn uncached() -> Result<()> {
+    let factory = factory::<Uri, IUriRuntimeClassFactory>()?;
+    assert!(!factory.as_raw().is_null());
+    Ok(())
+}
+
+#[test]
+fn race() {
+    // Every thread races to prime the cache and all must observe a usable factory.
+    load_concurrently(cached);
+    assert!(Uri::CreateUri(h!("http://kennykerr.ca")).is_ok());
+}
+
+// Timings depend on the machine, so run with `cargo test -p test_core --test factory_cache -- --ignored`.
+#[test]
+#[ignore]
+fn faster() {
+    let uncached = load_concurrently(uncached);
+    let cached = load_concurrently(cached);
+
+    assert!(
+        cached < uncached,
+        "{CONCURRENCY} threads x {ITERATIONS} factory loads: uncached {uncached:?}, cached {cached:?}"
+    );
+}
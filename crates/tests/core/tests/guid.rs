@@ -73,3 +73,211 @@ fn debug() {
 
     assert_eq!(format!("{value:?}"), "1FD63FEF-C0D2-42FE-823A-53A4052B8C8F");
 }
+
+#[test]
+fn parse_str() {
+    let value = GUID::from_u128(0x1fd63fef_c0d2_42fe_823a_53a4052b8c8f);
+    assert_eq!(
+        GUID::parse_str("1FD63FEF-C0D2-42FE-823A-53A4052B8C8F").unwrap(),
+        value
+    );
+    assert_eq!(
+        GUID::parse_str("{1fd63fef-c0d2-42fe-823a-53a4052b8c8f}").unwrap(),
+        value
+    );
+    assert_eq!(
+        GUID::parse_str("1fd63fefc0d242fe823a53a4052b8c8f").unwrap(),
+        value
+    );
+    assert_eq!(
+        GUID::parse_str("{1FD63FEFC0D242FE823A53A4052B8C8F}").unwrap(),
+        value
+    );
+    assert_eq!(
+        "{1FD63FEF-C0D2-42FE-823A-53A4052B8C8F}"
+            .parse::<GUID>()
+            .unwrap(),
+        value
+    );
+
+    assert_eq!(
+        GUID::parse_str("{1FD63FEF-C0D2-42FE-823A-53A4052B8C8F")
+            .unwrap_err()
+            .code(),
+        E_INVALIDARG
+    );
+    GUID::parse_str("1FD63FEF-C0D2-42FE-823A-53A4052B8C8F}").unwrap_err();
+    GUID::parse_str("{1FD63FEFC0D242FE823A53A4052B8C8F").unwrap_err();
+    GUID::parse_str("1FD63FEFC0D242FE823A53A4052B8C8F}").unwrap_err();
+    GUID::parse_str("{{1FD63FEF-C0D2-42FE-823A-53A4052B8C8F}}").unwrap_err();
+    GUID::parse_str("1fd63fefc0d242fe823a53a4052b8c8z").unwrap_err();
+    GUID::parse_str("").unwrap_err();
+}
+
+#[test]
+fn display() {
+    let value = GUID::from_u128(0x1fd63fef_c0d2_42fe_823a_53a4052b8c8f);
+    assert_eq!(value.to_string(), "{1FD63FEF-C0D2-42FE-823A-53A4052B8C8F}");
+    assert_eq!(GUID::parse_str(&value.to_string()).unwrap(), value);
+}
+
+#[test]
+fn uuid() {
+    let value = GUID::from_u128(0x1fd63fef_c0d2_42fe_823a_53a4052b8c8f);
+    let uuid = uuid::Uuid::parse_str("1fd63fef-c0d2-42fe-823a-53a4052b8c8f").unwrap();
+    assert_eq!(uuid::Uuid::from(value), uuid);
+    assert_eq!(GUID::from(uuid), value);
+    assert_eq!(uuid.to_string().to_uppercase(), format!("{value:?}"));
+}
+
+#[test]
+fn parse_formats() {
+    let value = GUID::from_u128(0x1fd63fef_c0d2_42fe_823a_53a4052b8c8f);
+    assert_eq!(
+        GUID::parse_str("(1fd63fef-c0d2-42fe-823a-53a4052b8c8f)").unwrap(),
+        value
+    );
+    assert_eq!(
+        GUID::parse_str("urn:uuid:1fd63fef-c0d2-42fe-823a-53a4052b8c8f").unwrap(),
+        value
+    );
+    assert_eq!(
+        GUID::parse_str("URN:UUID:1FD63FEF-C0D2-42FE-823A-53A4052B8C8F").unwrap(),
+        value
+    );
+    assert_eq!(
+        GUID::parse_str(
+            "{0x1fd63fef, 0xc0d2, 0x42fe, {0x82, 0x3a, 0x53, 0xa4, 0x05, 0x2b, 0x8c, 0x8f}}"
+        )
+        .unwrap(),
+        value
+    );
+    assert_eq!(
+        GUID::parse_str("{0x1FD63FEF,0xC0D2,0x42FE,{0x82,0x3A,0x53,0xA4,0x5,0x2B,0x8C,0x8F}}")
+            .unwrap(),
+        value
+    );
+
+    GUID::parse_str("(1fd63fef-c0d2-42fe-823a-53a4052b8c8f").unwrap_err();
+    GUID::parse_str("urn:uuid:1fd63fef").unwrap_err();
+    GUID::parse_str("{0x1fd63fef, 0xc0d2, 0x42fe, {0x82, 0x3a, 0x53, 0xa4, 0x05, 0x2b, 0x8c}}")
+        .unwrap_err();
+    GUID::parse_str(
+        "{0x1fd63fef, 0xc0d2, 0x42fe, {0x82, 0x3a, 0x53, 0xa4, 0x05, 0x2b, 0x8c, 0x8f, 0x00}}",
+    )
+    .unwrap_err();
+    GUID::parse_str(
+        "{0x1fd63fef, 0xc0d2, 0x42fe0, {0x82, 0x3a, 0x53, 0xa4, 0x05, 0x2b, 0x8c, 0x8f}}",
+    )
+    .unwrap_err();
+    GUID::parse_str(
+        "{0x1fd63fef, 0xc0d2, 0x42fe, {0x82, 0x3a, 0x53, 0xa4, 0x05, 0x2b, 0x8c, 0x8f}",
+    )
+    .unwrap_err();
+    GUID::parse_str(
+        "0x1fd63fef, 0xc0d2, 0x42fe, {0x82, 0x3a, 0x53, 0xa4, 0x05, 0x2b, 0x8c, 0x8f}}",
+    )
+    .unwrap_err();
+    GUID::parse_str("{0x1fd63fef, 0xc0d2, 0x42fe, 0x82, 0x3a, 0x53, 0xa4, 0x05, 0x2b, 0x8c, 0x8f}")
+        .unwrap_err();
+    GUID::parse_str(
+        "{{0x1fd63fef, 0xc0d2, 0x42fe, {0x82, 0x3a, 0x53, 0xa4, 0x05, 0x2b, 0x8c, 0x8f}}}",
+    )
+    .unwrap_err();
+    GUID::parse_str(
+        "{0x1fd63fef, 0xc0d2, 0x42fe, {0x82, 0x3a, 0x53, 0xa4}, {0x05, 0x2b, 0x8c, 0x8f}}",
+    )
+    .unwrap_err();
+}
+
+#[test]
+fn display_formats() {
+    let value = GUID::from_u128(0x1fd63fef_c0d2_42fe_823a_53a4052b8c8f);
+    assert_eq!(
+        value.display(GuidFormat::Braced).to_string(),
+        value.to_string()
+    );
+    assert_eq!(
+        value.display(GuidFormat::Hyphenated).to_string(),
+        format!("{value:?}")
+    );
+    assert_eq!(
+        value.display(GuidFormat::Simple).to_string(),
+        "1FD63FEFC0D242FE823A53A4052B8C8F"
+    );
+    assert_eq!(
+        value.display(GuidFormat::Urn).to_string(),
+        "urn:uuid:1fd63fef-c0d2-42fe-823a-53a4052b8c8f"
+    );
+    assert_eq!(
+        format!("{:x}", value.display(GuidFormat::Braced)),
+        "{1fd63fef-c0d2-42fe-823a-53a4052b8c8f}"
+    );
+    assert_eq!(
+        format!("{:X}", value.display(GuidFormat::Urn)),
+        "urn:uuid:1FD63FEF-C0D2-42FE-823A-53A4052B8C8F"
+    );
+
+    for format in [
+        GuidFormat::Braced,
+        GuidFormat::Hyphenated,
+        GuidFormat::Simple,
+        GuidFormat::Urn,
+    ] {
+        assert_eq!(
+            GUID::parse_str(&value.display(format).to_string()).unwrap(),
+            value
+        );
+    }
+}
+
+#[test]
+fn bytes() {
+    const VALUE: GUID = GUID::from_u128(0x1fd63fef_c0d2_42fe_823a_53a4052b8c8f);
+    const BE: [u8; 16] = VALUE.to_bytes();
+    const LE: [u8; 16] = VALUE.to_bytes_le();
+
+    assert_eq!(
+        BE,
+        [
+            0x1f, 0xd6, 0x3f, 0xef, 0xc0, 0xd2, 0x42, 0xfe, 0x82, 0x3a, 0x53, 0xa4, 0x05, 0x2b,
+            0x8c, 0x8f
+        ]
+    );
+    assert_eq!(
+        LE,
+        [
+            0xef, 0x3f, 0xd6, 0x1f, 0xd2, 0xc0, 0xfe, 0x42, 0x82, 0x3a, 0x53, 0xa4, 0x05, 0x2b,
+            0x8c, 0x8f
+        ]
+    );
+    assert_eq!(GUID::from_bytes(BE), VALUE);
+    assert_eq!(GUID::from_bytes_le(LE), VALUE);
+
+    // The memory layout of `GUID` matches the little-endian byte order.
+    assert_eq!(unsafe { std::mem::transmute::<GUID, [u8; 16]>(VALUE) }, LE);
+}
+
+#[test]
+fn versions() {
+    // Examples from RFC 9562 and Python's `uuid.uuid5`.
+    const DNS: GUID = GUID::new_v5(&GUID::NAMESPACE_DNS, b"www.example.com");
+    assert_eq!(DNS, GUID::from_u128(0x2ed6657d_e927_568b_95e1_2665a8aea6a2));
+    assert_eq!(DNS.version(), 5);
+
+    assert_eq!(
+        GUID::new_v5(&GUID::NAMESPACE_URL, b"https://www.rust-lang.org/"),
+        GUID::new_v5(&GUID::NAMESPACE_URL, b"https://www.rust-lang.org/")
+    );
+
+    // Names longer than a single SHA-1 block.
+    let name = [b'a'; 1500];
+    assert_eq!(
+        GUID::new_v5(&GUID::NAMESPACE_OID, &name),
+        GUID::from_u128(0x30ab8e66_6aa8_5860_a75b_8b8247f68c09)
+    );
+
+    let random = GUID::new_v4().unwrap();
+    assert_eq!(random.version(), 4);
+    assert_eq!(random.data4[0] & 0xc0, 0x80);
+}
@@ -0,0 +1,76 @@
+use windows::{core::*, Win32::Foundation::*};
+
+#[test]
+fn guid() {
+    let guid = GUID::from_u128(0x00000000_0000_0000_c000_000000000046);
+    let json = serde_json::to_string(&guid).unwrap();
+    assert_eq!(json, r#""00000000-0000-0000-C000-000000000046""#);
+    assert_eq!(serde_json::from_str::<GUID>(&json).unwrap(), guid);
+    assert_eq!(
+        serde_json::from_str::<GUID>(r#""00000000-0000-0000-c000-000000000046""#).unwrap(),
+        guid
+    );
+
+    let error = serde_json::from_str::<GUID>(r#""not a guid""#).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("invalid value: string \"not a guid\""));
+}
+
+#[test]
+fn hresult() {
+    let json = serde_json::to_string(&E_INVALIDARG).unwrap();
+    assert_eq!(json, "-2147024809");
+    assert_eq!(
+        serde_json::from_str::<HRESULT>(&json).unwrap(),
+        E_INVALIDARG
+    );
+}
+
+#[test]
+fn hstring() {
+    let value = h!("hello \"world\"");
+    let json = serde_json::to_string(value).unwrap();
+    assert_eq!(json, r#""hello \"world\"""#);
+    assert_eq!(&serde_json::from_str::<HSTRING>(&json).unwrap(), value);
+
+    // A lone surrogate can't be represented as a Rust string.
+    let invalid = HSTRING::from_wide(&[0xd800]).unwrap();
+    assert!(serde_json::to_string(&invalid).is_err());
+}
+
+#[test]
+fn filetime() {
+    let time = FILETIME::from_ticks(133_485_408_005_000_000);
+    let json = serde_json::to_string(&time).unwrap();
+    assert_eq!(json, "133485408005000000");
+    assert_eq!(serde_json::from_str::<FILETIME>(&json).unwrap(), time);
+}
+
+#[test]
+fn systemtime() {
+    let time = SYSTEMTIME {
+        wYear: 2024,
+        wMonth: 1,
+        wDayOfWeek: 1,
+        wDay: 1,
+        wHour: 2,
+        wMinute: 3,
+        wSecond: 4,
+        wMilliseconds: 500,
+    };
+    let json = serde_json::to_string(&time).unwrap();
+    assert_eq!(
+        json,
+        r#"{"wYear":2024,"wMonth":1,"wDayOfWeek":1,"wDay":1,"wHour":2,"wMinute":3,"wSecond":4,"wMilliseconds":500}"#
+    );
+    assert_eq!(serde_json::from_str::<SYSTEMTIME>(&json).unwrap(), time);
+    assert_eq!(
+        serde_json::from_str::<SYSTEMTIME>("[2024,1,1,1,2,3,4,500]").unwrap(),
+        time
+    );
+
+    assert!(serde_json::from_str::<SYSTEMTIME>(r#"{"wYear":2024}"#).is_err());
+    assert!(serde_json::from_str::<SYSTEMTIME>(r#"{"wYear":2024,"wYear":2025}"#).is_err());
+    assert!(serde_json::from_str::<SYSTEMTIME>(r#"{"year":2024}"#).is_err());
+}
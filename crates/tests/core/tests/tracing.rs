@@ -0,0 +1,77 @@
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use windows::{core::*, Foundation::Uri, Win32::Foundation::*};
+
+// A subscriber that records the fields of every event and span as text.
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+impl Recorder {
+    fn take(&self) -> Vec<String> {
+        std::mem::take(&mut self.0.lock().unwrap())
+    }
+}
+
+struct Fields(String);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push_str(&format!(" {}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == "windows"
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields(format!("span {}", span.metadata().name()));
+        span.record(&mut fields);
+        self.0.lock().unwrap().push(fields.0);
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields("event".to_string());
+        event.record(&mut fields);
+        self.0.lock().unwrap().push(fields.0);
+    }
+
+    fn enter(&self, _: &Id) {}
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn error() {
+    let recorder = Recorder::default();
+
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let _ = E_INVALIDARG.ok();
+        let _ = Error::new(E_ACCESSDENIED, "custom");
+        let line = line!() + 1;
+        let _ = Error::from_hresult(E_OUTOFMEMORY);
+
+        let events = recorder.take();
+        assert_eq!(events.len(), 3);
+        assert!(events[0].starts_with("event code=0x80070057 message="));
+        assert!(events[1].contains("code=0x80070005 message=custom"));
+        assert!(events[2].ends_with(&format!("tracing.rs:{line}:17")));
+    });
+}
+
+#[test]
+fn factory() {
+    let recorder = Recorder::default();
+
+    tracing::subscriber::with_default(recorder.clone(), || {
+        Uri::CreateUri(h!("http://kennykerr.ca")).unwrap();
+        let events = recorder.take();
+        assert!(events[0].starts_with("span factory class=\"Windows.Foundation.Uri\""));
+    });
+}
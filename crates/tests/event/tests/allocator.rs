@@ -0,0 +1,88 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::*;
+use windows::{core::*, Foundation::*, Win32::Foundation::E_ILLEGAL_METHOD_CALL};
+
+struct Counter {
+    allocations: AtomicUsize,
+    live: AtomicIsize,
+}
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+        self.live
+            .fetch_add(layout.size() as isize, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.live
+            .fetch_sub(layout.size() as isize, Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+static COUNTER: Counter = Counter {
+    allocations: AtomicUsize::new(0),
+    live: AtomicIsize::new(0),
+};
+
+#[implement(IStringable, TearOff = Closer)]
+struct Document;
+
+impl IStringable_Impl for Document_Impl {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok(HSTRING::new())
+    }
+}
+
+#[implement(IClosable, Owner = Document)]
+struct Closer;
+
+impl From<&Document> for Closer {
+    fn from(_: &Document) -> Self {
+        Self
+    }
+}
+
+impl IClosable_Impl for Closer_Impl {
+    fn Close(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+// The allocator is process-wide, so everything is tested in a single test.
+#[test]
+fn allocator() -> Result<()> {
+    set_allocator(&COUNTER)?;
+    assert_eq!(
+        set_allocator(&COUNTER).unwrap_err().code(),
+        E_ILLEGAL_METHOD_CALL
+    );
+
+    let event = Event::<EventHandler<i32>>::new();
+    assert_eq!(COUNTER.allocations.load(Ordering::Relaxed), 0);
+
+    let first = event.add(&EventHandler::<i32>::new(|_, _| Ok(())))?;
+    event.add(&EventHandler::<i32>::new(|_, _| Ok(())))?;
+    assert_eq!(COUNTER.allocations.load(Ordering::Relaxed), 2);
+    assert!(COUNTER.live.load(Ordering::Relaxed) > 0);
+
+    event.call(|delegate| delegate.Invoke(None, 1));
+    event.remove(first);
+    assert_eq!(COUNTER.allocations.load(Ordering::Relaxed), 3);
+
+    event.clear();
+    assert_eq!(COUNTER.live.load(Ordering::Relaxed), 0);
+
+    // Tear-offs are allocated with the allocator and freed along with their owner.
+    let stringable: IStringable = Document.into();
+    let closable: IClosable = stringable.cast()?;
+    assert_eq!(COUNTER.allocations.load(Ordering::Relaxed), 4);
+    assert!(COUNTER.live.load(Ordering::Relaxed) > 0);
+
+    drop((stringable, closable));
+    assert_eq!(COUNTER.live.load(Ordering::Relaxed), 0);
+
+    Ok(())
+}
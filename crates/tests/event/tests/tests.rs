@@ -144,3 +144,324 @@ fn is_send_sync() -> Result<()> {
     assert!(Arc::ptr_eq(&event, &returned_event));
     Ok(())
 }
+
+#[test]
+fn try_call() -> Result<()> {
+    use windows::Win32::Foundation::{E_ACCESSDENIED, E_FAIL, RPC_E_DISCONNECTED};
+
+    let event = Event::<EventHandler<i32>>::new();
+    assert_eq!(event.try_call(|delegate| delegate.Invoke(None, 10)), Ok(()));
+    assert!(event
+        .call_with_results(|delegate| delegate.Invoke(None, 10))
+        .is_empty());
+
+    let check = Arc::new(AtomicI32::new(0));
+    let check_sender = check.clone();
+
+    event.add(&EventHandler::<i32>::new(|_, _| Ok(())))?;
+    event.add(&EventHandler::<i32>::new(|_, _| Err(E_FAIL.into())))?;
+    event.add(&EventHandler::<i32>::new(|_, _| Err(E_ACCESSDENIED.into())))?;
+    event.add(&EventHandler::<i32>::new(move |_, args| {
+        check_sender.store(*args, Ordering::Relaxed);
+        Ok(())
+    }))?;
+
+    // The first error is returned, but every delegate is still invoked.
+    let error = event
+        .try_call(|delegate| delegate.Invoke(None, 20))
+        .unwrap_err();
+    assert_eq!(error.code(), E_FAIL);
+    assert_eq!(check.load(Ordering::Relaxed), 20);
+
+    let results = event.call_with_results(|delegate| delegate.Invoke(None, 30));
+    let codes: Vec<HRESULT> = results
+        .iter()
+        .map(|result| {
+            result
+                .as_ref()
+                .map_or_else(|error| error.code(), |_| HRESULT(0))
+        })
+        .collect();
+    assert_eq!(codes, [HRESULT(0), E_FAIL, E_ACCESSDENIED, HRESULT(0)]);
+    assert_eq!(check.load(Ordering::Relaxed), 30);
+
+    // Disconnected delegates are reported and then removed.
+    event.clear();
+    event.add(&EventHandler::<i32>::new(|_, _| {
+        Err(RPC_E_DISCONNECTED.into())
+    }))?;
+    let results = event.call_with_results(|delegate| delegate.Invoke(None, 40));
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].as_ref().unwrap_err().code(), RPC_E_DISCONNECTED);
+    assert!(event
+        .call_with_results(|delegate| delegate.Invoke(None, 50))
+        .is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn add_fn() -> Result<()> {
+    let event = Event::<TypedEventHandler<IStringable, i32>>::new();
+    let check = Arc::new(AtomicI32::new(0));
+    let check_sender = check.clone();
+
+    let token = event.add_fn(move |sender, args| {
+        assert!(sender.is_none());
+        check_sender.fetch_add(*args, Ordering::Relaxed);
+        Ok(())
+    })?;
+
+    event.call(|delegate| delegate.Invoke(None, 10));
+    assert_eq!(check.load(Ordering::Relaxed), 10);
+
+    event.remove(token);
+    event.call(|delegate| delegate.Invoke(None, 10));
+    assert_eq!(check.load(Ordering::Relaxed), 10);
+
+    let event = Event::<EventHandler<i32>>::new();
+    let check_sender = check.clone();
+
+    event.add_fn(move |sender, args| {
+        assert!(sender.is_none());
+        check_sender.store(*args, Ordering::Relaxed);
+        Ok(())
+    })?;
+
+    event.call(|delegate| delegate.Invoke(None, 20));
+    assert_eq!(check.load(Ordering::Relaxed), 20);
+
+    Ok(())
+}
+
+#[test]
+fn add_guarded() -> Result<()> {
+    let event = Event::<EventHandler<i32>>::new();
+    let check = Arc::new(AtomicI32::new(0));
+
+    let handler = {
+        let check = check.clone();
+        EventHandler::<i32>::new(move |_, args| {
+            check.fetch_add(*args, Ordering::Relaxed);
+            Ok(())
+        })
+    };
+
+    {
+        let guard = event.add_guarded(&handler)?;
+        assert_ne!(guard.token(), 0);
+        event.call(|delegate| delegate.Invoke(None, 1));
+        assert_eq!(check.load(Ordering::Relaxed), 1);
+    }
+
+    // The guard has revoked the registration.
+    event.call(|delegate| delegate.Invoke(None, 1));
+    assert_eq!(check.load(Ordering::Relaxed), 1);
+
+    let token = event.add_guarded(&handler)?.into_token();
+    event.call(|delegate| delegate.Invoke(None, 10));
+    assert_eq!(check.load(Ordering::Relaxed), 11);
+    event.remove(token);
+
+    event.add_guarded(&handler)?.forget();
+    event.call(|delegate| delegate.Invoke(None, 100));
+    assert_eq!(check.load(Ordering::Relaxed), 111);
+
+    Ok(())
+}
+
+#[test]
+fn introspection() -> Result<()> {
+    let event = Event::<EventHandler<i32>>::new();
+    assert!(event.is_empty());
+    assert_eq!(event.len(), 0);
+    assert!(event.tokens().is_empty());
+    assert_eq!(format!("{event:?}"), "Event { len: 0, agile: 0 }");
+
+    let first = event.add(&EventHandler::<i32>::new(|_, _| Ok(())))?;
+    let second = event.add(&EventHandler::<i32>::new(|_, _| Ok(())))?;
+    assert!(!event.is_empty());
+    assert_eq!(event.len(), 2);
+    assert_eq!(event.tokens(), [first, second]);
+    assert_eq!(format!("{event:?}"), "Event { len: 2, agile: 2 }");
+
+    event.remove(first);
+    assert_eq!(event.tokens(), [second]);
+
+    event.clear();
+    assert!(event.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn disconnect_policy() -> Result<()> {
+    use windows::Win32::Foundation::{E_FAIL, RPC_E_DISCONNECTED};
+
+    // By default, only disconnected delegates are removed.
+    let event = Event::<EventHandler<i32>>::new();
+    event.add(&EventHandler::<i32>::new(|_, _| Err(E_FAIL.into())))?;
+    event.add(&EventHandler::<i32>::new(|_, _| {
+        Err(RPC_E_DISCONNECTED.into())
+    }))?;
+    event.call(|delegate| delegate.Invoke(None, 0));
+    assert_eq!(event.len(), 1);
+
+    // A custom policy decides which errors evict a delegate.
+    let event = Event::<EventHandler<i32>>::with_disconnect_policy(|code| code == E_FAIL);
+    event.add(&EventHandler::<i32>::new(|_, _| Err(E_FAIL.into())))?;
+    event.add(&EventHandler::<i32>::new(|_, _| {
+        Err(RPC_E_DISCONNECTED.into())
+    }))?;
+    let results = event.call_with_results(|delegate| delegate.Invoke(None, 0));
+    assert_eq!(results[0].as_ref().unwrap_err().code(), E_FAIL);
+    assert_eq!(results[1].as_ref().unwrap_err().code(), RPC_E_DISCONNECTED);
+    assert_eq!(event.len(), 1);
+
+    // The remaining delegate keeps surfacing its error.
+    let error = event
+        .try_call(|delegate| delegate.Invoke(None, 0))
+        .unwrap_err();
+    assert_eq!(error.code(), RPC_E_DISCONNECTED);
+    assert_eq!(event.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn concurrent() -> Result<()> {
+    let event = Arc::new(Event::<EventHandler<i32>>::new());
+    let check = Arc::new(AtomicI32::new(0));
+
+    let handler = {
+        let check = check.clone();
+        EventHandler::<i32>::new(move |_, args| {
+            check.fetch_add(*args, Ordering::Relaxed);
+            Ok(())
+        })
+    };
+
+    let token = event.add(&handler)?;
+
+    // Raise the event on several threads while delegates are added and removed on several others,
+    // so that writers frequently replace the delegates back to back while raisers are loading them.
+    let raisers: Vec<_> = (0..4)
+        .map(|_| {
+            let event = event.clone();
+            std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    event.call(|delegate| delegate.Invoke(None, 1));
+                }
+            })
+        })
+        .collect();
+
+    let writers: Vec<_> = (0..4)
+        .map(|_| {
+            let event = event.clone();
+            std::thread::spawn(move || -> Result<()> {
+                for _ in 0..1000 {
+                    let token = event.add(&EventHandler::<i32>::new(|_, _| Ok(())))?;
+                    event.call(|delegate| delegate.Invoke(None, 0));
+                    event.remove(token);
+                }
+                Ok(())
+            })
+        })
+        .collect();
+
+    for raiser in raisers {
+        raiser.join().unwrap();
+    }
+
+    for writer in writers {
+        writer.join().unwrap()?;
+    }
+
+    // The first delegate stays registered throughout.
+    assert_eq!(check.load(Ordering::Relaxed), 4000);
+    assert_eq!(event.tokens(), [token]);
+
+    Ok(())
+}
+
+#[test]
+fn unique_tokens() -> Result<()> {
+    let event = Event::<EventHandler<i32>>::new();
+    let check = Arc::new(AtomicI32::new(0));
+
+    let handler = {
+        let check = check.clone();
+        EventHandler::<i32>::new(move |_, args| {
+            check.fetch_add(*args, Ordering::Relaxed);
+            Ok(())
+        })
+    };
+
+    // Adding the same delegate twice yields distinct registrations.
+    let first = event.add(&handler)?;
+    let second = event.add(&handler)?;
+    assert_ne!(first, second);
+    event.call(|delegate| delegate.Invoke(None, 1));
+    assert_eq!(check.load(Ordering::Relaxed), 2);
+
+    // Removing one registration leaves the other.
+    event.remove(second);
+    assert_eq!(event.tokens(), [first]);
+    event.remove(second);
+    assert_eq!(event.tokens(), [first]);
+    event.call(|delegate| delegate.Invoke(None, 1));
+    assert_eq!(check.load(Ordering::Relaxed), 3);
+
+    // Tokens are not reused once revoked.
+    event.remove(first);
+    let third = event.add(&handler)?;
+    assert!(third != first && third != second);
+
+    Ok(())
+}
+
+#[implement(IClosable)]
+struct Closable(Arc<AtomicI32>);
+
+impl IClosable_Impl for Closable_Impl {
+    fn Close(&self) -> Result<()> {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[test]
+fn weak_event() -> Result<()> {
+    let event = WeakEvent::<IClosable>::new();
+    let check = Arc::new(AtomicI32::new(0));
+
+    let closable: IClosable = Closable(check.clone()).into();
+    event.add(&closable)?;
+    event.call(|delegate| delegate.Close());
+    assert_eq!(check.load(Ordering::Relaxed), 1);
+
+    // The event doesn't keep the delegate alive, and prunes it once it is gone.
+    drop(closable);
+    assert_eq!(event.len(), 1);
+    assert!(event.try_call(|delegate| delegate.Close()).is_ok());
+    assert_eq!(check.load(Ordering::Relaxed), 1);
+    assert!(event.is_empty());
+
+    // Delegates without weak reference support are held strongly.
+    let event = WeakEvent::<EventHandler<i32>>::new();
+    let token = event.add(&EventHandler::<i32>::new({
+        let check = check.clone();
+        move |_, args| {
+            check.fetch_add(*args, Ordering::Relaxed);
+            Ok(())
+        }
+    }))?;
+
+    event.call(|delegate| delegate.Invoke(None, 10));
+    assert_eq!(check.load(Ordering::Relaxed), 11);
+    event.remove(token);
+    assert!(event.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use windows::core::GUID;
+use windows::Win32::Foundation::*;
+
+#[test]
+fn newtypes() {
+    let sys: windows_sys::Win32::Foundation::HWND = HWND(123 as _).into();
+    assert_eq!(sys, 123 as _);
+    assert_eq!(HWND::from(sys), HWND(123 as _));
+
+    let sys: windows_sys::Win32::Foundation::HANDLE = HANDLE(-1 as _).into();
+    assert_eq!(HANDLE::from(sys), INVALID_HANDLE_VALUE);
+
+    let sys: windows_sys::Win32::Foundation::BOOL = TRUE.into();
+    assert_eq!(sys, windows_sys::Win32::Foundation::TRUE);
+    assert_eq!(BOOL::from(windows_sys::Win32::Foundation::FALSE), FALSE);
+
+    let sys: windows_sys::Win32::Foundation::WIN32_ERROR = ERROR_ACCESS_DENIED.into();
+    assert_eq!(sys, windows_sys::Win32::Foundation::ERROR_ACCESS_DENIED);
+    assert_eq!(WIN32_ERROR::from(sys), ERROR_ACCESS_DENIED);
+
+    assert_eq!(LPARAM::from(-2), LPARAM(-2));
+    assert_eq!(windows_sys::Win32::Foundation::WPARAM::from(WPARAM(3)), 3);
+}
+
+#[test]
+fn structs() {
+    let rect = RECT {
+        left: 1,
+        top: 2,
+        right: 3,
+        bottom: 4,
+    };
+    let sys: windows_sys::Win32::Foundation::RECT = rect.into();
+    assert_eq!((sys.left, sys.top, sys.right, sys.bottom), (1, 2, 3, 4));
+    assert_eq!(RECT::from(sys), rect);
+
+    let time = windows_sys::Win32::Foundation::SYSTEMTIME {
+        wYear: 2024,
+        wMonth: 2,
+        wDayOfWeek: 4,
+        wDay: 29,
+        wHour: 1,
+        wMinute: 2,
+        wSecond: 3,
+        wMilliseconds: 4,
+    };
+    let time = SYSTEMTIME::from(time);
+    assert_eq!(
+        (
+            time.wYear,
+            time.wMonth,
+            time.wDayOfWeek,
+            time.wDay,
+            time.wMilliseconds
+        ),
+        (2024, 2, 4, 29, 4)
+    );
+
+    let luid = LUID {
+        LowPart: 5,
+        HighPart: -6,
+    };
+    let sys: windows_sys::Win32::Foundation::LUID = luid.into();
+    assert_eq!((sys.LowPart, sys.HighPart), (5, -6));
+    assert_eq!(LUID::from(sys), luid);
+}
+
+#[test]
+fn guid() {
+    let guid = GUID::from_u128(0x1a2b3c4d_5e6f_7a8b_9c0d_1e2f3a4b5c6d);
+    let sys: windows_sys::core::GUID = guid.into();
+    assert_eq!(
+        (sys.data1, sys.data2, sys.data3),
+        (0x1a2b3c4d, 0x5e6f, 0x7a8b)
+    );
+    assert_eq!(sys.data4, [0x9c, 0x0d, 0x1e, 0x2f, 0x3a, 0x4b, 0x5c, 0x6d]);
+    assert_eq!(GUID::from(sys), guid);
+}
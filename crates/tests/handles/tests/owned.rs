@@ -0,0 +1,70 @@
+use windows::{core::*, Win32::Foundation::*, Win32::Graphics::Gdi::*, Win32::System::Registry::*};
+
+#[test]
+fn receive() -> Result<()> {
+    unsafe {
+        let key = Owned::receive(|key: &mut HKEY| {
+            RegOpenKeyExW(HKEY_CURRENT_USER, w!("Software"), 0, KEY_READ, key).ok()
+        })?;
+        assert!(!key.is_invalid());
+
+        let error = Owned::receive(|key: &mut HKEY| {
+            RegOpenKeyExW(
+                HKEY_CURRENT_USER,
+                w!("Software\\windows-rs\\missing"),
+                0,
+                KEY_READ,
+                key,
+            )
+            .ok()
+        })
+        .unwrap_err();
+        assert_eq!(error.code(), ERROR_FILE_NOT_FOUND.to_hresult());
+
+        Ok(())
+    }
+}
+
+#[test]
+fn borrow() -> Result<()> {
+    unsafe {
+        let key = Owned::receive(|key: &mut HKEY| {
+            RegOpenKeyExW(HKEY_CURRENT_USER, w!("Software"), 0, KEY_READ, key).ok()
+        })?;
+
+        let borrowed: Borrowed<HKEY> = key.borrow();
+        assert_eq!(*borrowed, *key);
+        let handle = *borrowed;
+
+        let raw = key.into_raw();
+        assert_eq!(raw, handle);
+        RegCloseKey(raw).ok()?;
+
+        let borrowed = Borrowed::borrow_raw(HKEY_CURRENT_USER);
+        assert_eq!(*borrowed, HKEY_CURRENT_USER);
+
+        Ok(())
+    }
+}
+
+#[test]
+fn device_context() {
+    unsafe {
+        let dc = Owned::new(CreatedHDC(CreateCompatibleDC(None)));
+        assert!(!dc.is_invalid());
+
+        let bitmap = Owned::new(CreateCompatibleBitmap(dc.0, 1, 1));
+        assert!(!bitmap.is_invalid());
+
+        let previous = SelectObject(dc.0, *bitmap);
+        assert!(!previous.is_invalid());
+        SelectObject(*dc, previous);
+
+        let screen = Owned::new(WindowHDC {
+            hwnd: HWND::default(),
+            hdc: GetDC(None),
+        });
+        assert!(!screen.is_invalid());
+        assert_eq!(HDC::from(*screen), screen.hdc);
+    }
+}
@@ -0,0 +1,72 @@
+use std::io::{Read, Seek, Write};
+use std::os::windows::io::*;
+use windows::{core::*, Win32::Foundation::*};
+
+fn temp_file(name: &str) -> (std::path::PathBuf, std::fs::File) {
+    let path = std::env::temp_dir().join(format!("{name}_{}.txt", std::process::id()));
+    let file = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&path)
+        .unwrap();
+    (path, file)
+}
+
+#[test]
+fn owned() {
+    let (path, mut file) = temp_file("owned_handle");
+    file.write_all(b"hello").unwrap();
+    let raw = file.as_raw_handle();
+
+    let owned = Owned::<HANDLE>::from(OwnedHandle::from(file));
+    assert_eq!(owned.0, raw);
+    assert_eq!(owned.as_raw_handle(), raw);
+
+    let mut flags = 0;
+    unsafe { GetHandleInformation(*owned, &mut flags).unwrap() };
+
+    let mut file = std::fs::File::from(OwnedHandle::from(owned));
+    let mut text = String::new();
+    file.rewind().unwrap();
+    file.read_to_string(&mut text).unwrap();
+    assert_eq!(text, "hello");
+
+    let owned = unsafe { Owned::<HANDLE>::from_raw_handle(file.into_raw_handle()) };
+    let raw = owned.into_raw_handle();
+    unsafe { CloseHandle(HANDLE(raw)).unwrap() };
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn borrowed() {
+    let (path, file) = temp_file("borrowed_handle");
+
+    let borrowed = Borrowed::<HANDLE>::from(file.as_handle());
+    assert_eq!(borrowed.0, file.as_raw_handle());
+    assert_eq!(borrowed.as_handle().as_raw_handle(), file.as_raw_handle());
+
+    let mut flags = 0;
+    unsafe { GetHandleInformation(*borrowed, &mut flags).unwrap() };
+
+    let owned = Owned::<HANDLE>::from(OwnedHandle::from(file));
+    assert_eq!(owned.borrow().as_handle().as_raw_handle(), owned.0);
+    assert_eq!(owned.as_handle().as_raw_handle(), owned.0);
+    drop(owned);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn child() {
+    let child = std::process::Command::new("cmd.exe")
+        .args(["/c", "exit 7"])
+        .spawn()
+        .unwrap();
+
+    let process = Owned::<HANDLE>::from(OwnedHandle::from(child));
+    let mut flags = 0;
+    unsafe { GetHandleInformation(*process, &mut flags).unwrap() };
+}
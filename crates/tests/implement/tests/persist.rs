@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use windows::{core::*, Win32::Foundation::*, Win32::System::Com::*};
+
+const CLSID_NOTE: GUID = GUID::from_u128(0x6f1a2b3c_4d5e_4f60_8a9b_0c1d2e3f4a5b);
+
+#[derive(Serialize, Deserialize, Default, PartialEq, Debug)]
+struct Note {
+    title: String,
+    lines: Vec<String>,
+}
+
+#[implement(IPersistStream, IPersistStreamInit)]
+#[derive(Default)]
+struct Document(PersistState<Note>);
+
+impl SerdePersist for Document_Impl {
+    type State = Note;
+    const CLASS_ID: GUID = CLSID_NOTE;
+
+    fn persist_state(&self) -> &PersistState<Note> {
+        &self.0
+    }
+}
+
+#[test]
+fn round_trip() -> Result<()> {
+    let document = Document::default();
+    let stream: IPersistStream = document.into();
+
+    unsafe {
+        assert_eq!(stream.GetClassID()?, CLSID_NOTE);
+        assert_eq!(stream.IsDirty(), S_FALSE);
+    }
+
+    let state: &Document = unsafe { stream.as_impl() };
+    *state.0.write() = Note {
+        title: "hello".to_string(),
+        lines: vec!["one".to_string(), "two".to_string()],
+    };
+    assert_eq!(unsafe { stream.IsDirty() }, S_OK);
+
+    let bytes = save_to_bytes(&stream)?;
+    assert_eq!(unsafe { stream.IsDirty() }, S_FALSE);
+    assert_eq!(bytes.len() as u64, unsafe { stream.GetSizeMax()? });
+
+    let copy: IPersistStreamInit = Document::default().into();
+    load_from_bytes(&copy, &bytes)?;
+    let loaded: &Document = unsafe { copy.as_impl() };
+    assert_eq!(*loaded.0.read(), *state.0.read());
+    assert!(!loaded.0.is_dirty());
+
+    unsafe { copy.InitNew()? };
+    assert_eq!(*loaded.0.read(), Note::default());
+
+    Ok(())
+}
+
+#[test]
+fn invalid() {
+    let document: IPersistStream = Document::default().into();
+    assert!(load_from_bytes(&document, &[]).is_err());
+
+    let error = load_from_bytes(&document, &[2, 0, 0, 0, b'{', b'{']).unwrap_err();
+    assert_eq!(error.code(), ERROR_INVALID_DATA.to_hresult());
+}
+
+#[test]
+fn stream_io() -> Result<()> {
+    use std::io::{Read, Seek, SeekFrom, Write};
+
+    let stream = unsafe { StructuredStorage::CreateStreamOnHGlobal(HGLOBAL::default(), true)? };
+    let mut io = StreamIo::new(&stream);
+    io.write_all(b"hello world")?;
+    assert_eq!(io.seek(SeekFrom::Start(6))?, 6);
+
+    let mut text = String::new();
+    io.read_to_string(&mut text)?;
+    assert_eq!(text, "world");
+
+    Ok(())
+}
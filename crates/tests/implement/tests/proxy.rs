@@ -0,0 +1,68 @@
+use std::sync::{Arc, Mutex};
+use windows::{core::*, Foundation::*, Win32::Foundation::*, Win32::System::Com::*};
+
+const CLSID_OBJECT: GUID = GUID::from_u128(0x0b4e1f0e_53f7_4c8e_9d1c_6a0f2a3b4c5d);
+
+#[implement(IPersist, IStringable)]
+struct Object;
+
+impl IPersist_Impl for Object_Impl {
+    fn GetClassID(&self) -> Result<GUID> {
+        Ok(CLSID_OBJECT)
+    }
+}
+
+impl IStringable_Impl for Object_Impl {
+    fn ToString(&self) -> Result<HSTRING> {
+        Err(E_NOTIMPL.into())
+    }
+}
+
+type Calls = Arc<Mutex<Vec<(&'static str, &'static str, usize, Option<HRESULT>)>>>;
+
+fn callback(calls: &Calls) -> impl Fn(&ProxyCall) + Send + Sync + 'static {
+    let calls = calls.clone();
+    move |call| {
+        calls
+            .lock()
+            .unwrap()
+            .push((call.interface, call.method, call.slot, call.result))
+    }
+}
+
+#[test]
+fn com() -> Result<()> {
+    let inner: IPersist = Object.into();
+    let calls = Calls::default();
+    let persist = proxy(&inner, callback(&calls));
+    assert_ne!(persist.as_raw(), inner.as_raw());
+
+    assert_eq!(unsafe { persist.GetClassID()? }, CLSID_OBJECT);
+    assert_eq!(
+        *calls.lock().unwrap(),
+        [("IPersist", "GetClassID", 3, Some(S_OK))]
+    );
+
+    // The proxy keeps its identity and doesn't hand out the inner object's other interfaces.
+    let unknown: IUnknown = persist.cast()?;
+    assert_eq!(unknown.as_raw(), persist.as_raw());
+    assert_eq!(
+        persist.cast::<IStringable>().unwrap_err().code(),
+        E_NOINTERFACE
+    );
+    Ok(())
+}
+
+#[test]
+fn winrt() -> Result<()> {
+    let inner: IStringable = Object.into();
+    let calls = Calls::default();
+    let stringable = proxy(&inner, callback(&calls));
+
+    assert_eq!(stringable.ToString().unwrap_err().code(), E_NOTIMPL);
+    assert_eq!(
+        *calls.lock().unwrap(),
+        [("IStringable", "ToString", 6, Some(E_NOTIMPL))]
+    );
+    Ok(())
+}
@@ -0,0 +1,95 @@
+#![allow(non_snake_case)]
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use windows::{core::*, Foundation::*};
+
+#[implement(IStringable, TearOff = Closer)]
+struct Document {
+    name: String,
+    closed: AtomicBool,
+    tear_offs_created: AtomicUsize,
+}
+
+impl Document {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            closed: AtomicBool::new(false),
+            tear_offs_created: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl IStringable_Impl for Document_Impl {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok(self.name.as_str().into())
+    }
+}
+
+static CLOSERS: AtomicUsize = AtomicUsize::new(0);
+
+#[implement(IClosable, Owner = Document)]
+struct Closer(u32);
+
+impl From<&Document> for Closer {
+    fn from(document: &Document) -> Self {
+        document.tear_offs_created.fetch_add(1, Ordering::Relaxed);
+        CLOSERS.fetch_add(1, Ordering::Relaxed);
+        Self(123)
+    }
+}
+
+impl Drop for Closer {
+    fn drop(&mut self) {
+        CLOSERS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl IClosable_Impl for Closer_Impl {
+    fn Close(&self) -> Result<()> {
+        assert_eq!(self.0, 123);
+        self.owner().closed.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[test]
+fn tear_off() -> Result<()> {
+    let object = ComObject::new(Document::new("document"));
+    let stringable: IStringable = object.to_interface();
+    assert_eq!(object.tear_offs_created.load(Ordering::Relaxed), 0);
+
+    let closable: IClosable = stringable.cast()?;
+    assert_eq!(object.tear_offs_created.load(Ordering::Relaxed), 1);
+    closable.Close()?;
+    assert!(object.closed.load(Ordering::Relaxed));
+
+    // The tear-off is cached, so later queries return the same interface pointer.
+    let again: IClosable = stringable.cast()?;
+    assert_eq!(again.as_raw(), closable.as_raw());
+    assert_eq!(object.tear_offs_created.load(Ordering::Relaxed), 1);
+
+    // The tear-off shares the identity of its owner.
+    assert_eq!(closable.cast::<IUnknown>()?, stringable.cast::<IUnknown>()?);
+    assert_eq!(closable.cast::<IStringable>()?.ToString()?, "document");
+    assert!(closable.cast::<IMemoryBuffer>().is_err());
+
+    let closer: &Closer = unsafe { closable.as_impl() };
+    assert_eq!(closer.0, 123);
+
+    drop((object, stringable, closable, again));
+    assert_eq!(CLOSERS.load(Ordering::Relaxed), 0);
+
+    let closable: IClosable = {
+        let stringable: IStringable = Document::new("document").into();
+        stringable.cast()?
+    };
+
+    // The tear-off keeps its owner alive, and is only destroyed along with it.
+    assert_eq!(CLOSERS.load(Ordering::Relaxed), 1);
+    assert_eq!(closable.cast::<IStringable>()?.ToString()?, "document");
+    drop(closable);
+    assert_eq!(CLOSERS.load(Ordering::Relaxed), 0);
+
+    Ok(())
+}
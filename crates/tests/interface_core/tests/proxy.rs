@@ -0,0 +1,116 @@
+#![allow(non_snake_case)]
+
+use std::sync::{Arc, Mutex};
+use windows_core::*;
+
+pub const E_INVALIDARG: HRESULT = HRESULT(0x80070057_u32 as _);
+pub const E_NOINTERFACE: HRESULT = HRESULT(0x80004002_u32 as _);
+
+#[interface("4b0a1c55-3b19-4f7c-9d52-3c8e0d8a0b11")]
+unsafe trait IValue: IUnknown {
+    unsafe fn GetValue(&self, value: *mut i32) -> HRESULT;
+    unsafe fn SetValue(&self, value: i32) -> Result<()>;
+}
+
+#[interface("6f1b9f0e-8d34-4a53-b3a2-5a6f3f1c2d22")]
+unsafe trait IValue2: IValue {
+    unsafe fn Count(&self) -> u32;
+}
+
+#[interface("0c9bbf6c-2f8e-4b21-8f63-1d0b7a3e4c33")]
+unsafe trait IOther: IUnknown {
+    unsafe fn Other(&self) -> i32;
+}
+
+#[implement(IValue2, IOther)]
+struct Value(Mutex<(i32, u32)>);
+
+impl IValue_Impl for Value_Impl {
+    unsafe fn GetValue(&self, value: *mut i32) -> HRESULT {
+        let mut this = self.0.lock().unwrap();
+        this.1 += 1;
+        *value = this.0;
+        HRESULT(0)
+    }
+    unsafe fn SetValue(&self, value: i32) -> Result<()> {
+        let mut this = self.0.lock().unwrap();
+        this.1 += 1;
+
+        if value < 0 {
+            Err(E_INVALIDARG.into())
+        } else {
+            this.0 = value;
+            Ok(())
+        }
+    }
+}
+
+impl IValue2_Impl for Value_Impl {
+    unsafe fn Count(&self) -> u32 {
+        self.0.lock().unwrap().1
+    }
+}
+
+impl IOther_Impl for Value_Impl {
+    unsafe fn Other(&self) -> i32 {
+        123
+    }
+}
+
+#[test]
+fn test() -> Result<()> {
+    unsafe {
+        let calls = Arc::new(Mutex::new(vec![]));
+
+        let inner: IValue2 = Value(Mutex::new((0, 0))).into();
+        let value = {
+            let calls = calls.clone();
+            proxy(&inner, move |call| {
+                calls
+                    .lock()
+                    .unwrap()
+                    .push((call.interface, call.method, call.slot, call.result))
+            })
+        };
+
+        assert_ne!(value.as_raw(), inner.as_raw());
+
+        value.SetValue(123)?;
+        assert_eq!(value.SetValue(-1).unwrap_err().code(), E_INVALIDARG);
+        let mut out = 0;
+        assert_eq!(value.GetValue(&mut out), HRESULT(0));
+        assert_eq!(out, 123);
+        assert_eq!(value.Count(), 3);
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                ("IValue", "SetValue", 4, Some(HRESULT(0))),
+                ("IValue", "SetValue", 4, Some(E_INVALIDARG)),
+                ("IValue", "GetValue", 3, Some(HRESULT(0))),
+                ("IValue2", "Count", 5, None),
+            ]
+        );
+
+        // The proxy is returned for the proxied interface and the interfaces it derives from.
+        let base: IValue = value.cast()?;
+        assert_eq!(base.as_raw(), value.as_raw());
+        let unknown: IUnknown = value.cast()?;
+        assert_eq!(unknown.as_raw(), value.as_raw());
+        base.SetValue(1)?;
+        assert_eq!(calls.lock().unwrap().len(), 5);
+
+        // Any other interface is only available from the inner object, so that every interface returned by the
+        // proxy has the proxy's identity.
+        assert_eq!(value.cast::<IOther>().unwrap_err().code(), E_NOINTERFACE);
+        assert_eq!(inner.cast::<IOther>()?.Other(), 123);
+        assert_eq!(calls.lock().unwrap().len(), 5);
+
+        // The proxy holds a reference to the inner object until it's released.
+        drop(inner);
+        drop((base, unknown));
+        assert_eq!(value.Count(), 4);
+    }
+
+    Ok(())
+}
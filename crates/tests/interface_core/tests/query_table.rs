@@ -0,0 +1,99 @@
+#![allow(non_snake_case)]
+
+// This tests `QueryInterface` for types implementing enough interfaces that their IIDs are
+// found with a binary search, including base interfaces that are not in the sorted table.
+
+use windows_core::*;
+
+#[interface("f1c2a7d3-1f7c-4b57-9b7a-0c6a3e1d5b01")]
+unsafe trait IBase: IUnknown {
+    unsafe fn Base(&self) -> i32;
+}
+
+#[interface("0a93e7c2-52d4-4c3f-8e34-6c0a7d9b2e02")]
+unsafe trait IDerived: IBase {
+    unsafe fn Derived(&self) -> i32;
+}
+
+#[interface("c84b2e51-7d3a-4f0e-a1b6-3e5d9c7a4f03")]
+unsafe trait IOne: IUnknown {
+    unsafe fn One(&self) -> i32;
+}
+
+#[interface("5e7d1a9c-3b2f-4a86-9d4e-8f1c6b0a2d04")]
+unsafe trait ITwo: IUnknown {
+    unsafe fn Two(&self) -> i32;
+}
+
+#[interface("9b3f6c8e-0d1a-4e27-b5c9-2a7e4d1f8c05")]
+unsafe trait IThree: IUnknown {
+    unsafe fn Three(&self) -> i32;
+}
+
+#[interface("2d6a9f1e-8c4b-4d3a-a7e2-5b9c0f3d6e06")]
+unsafe trait IMissing: IUnknown {
+    unsafe fn Missing(&self) -> i32;
+}
+
+#[implement(IThree, IDerived, ITwo, IOne)]
+struct Class;
+
+impl IBase_Impl for Class_Impl {
+    unsafe fn Base(&self) -> i32 {
+        10
+    }
+}
+
+impl IDerived_Impl for Class_Impl {
+    unsafe fn Derived(&self) -> i32 {
+        20
+    }
+}
+
+impl IOne_Impl for Class_Impl {
+    unsafe fn One(&self) -> i32 {
+        1
+    }
+}
+
+impl ITwo_Impl for Class_Impl {
+    unsafe fn Two(&self) -> i32 {
+        2
+    }
+}
+
+impl IThree_Impl for Class_Impl {
+    unsafe fn Three(&self) -> i32 {
+        3
+    }
+}
+
+#[test]
+fn test() {
+    unsafe {
+        let three: IThree = Class.into();
+        assert_eq!(three.Three(), 3);
+
+        let one: IOne = three.cast().unwrap();
+        assert_eq!(one.One(), 1);
+
+        let two: ITwo = one.cast().unwrap();
+        assert_eq!(two.Two(), 2);
+
+        let derived: IDerived = two.cast().unwrap();
+        assert_eq!(derived.Derived(), 20);
+        assert_eq!(derived.Base(), 10);
+
+        // Base interfaces are not in the sorted table and are found by walking each interface hierarchy.
+        let base: IBase = three.cast().unwrap();
+        assert_eq!(base.Base(), 10);
+
+        let unknown: IUnknown = base.cast().unwrap();
+        assert_eq!(unknown, three.cast::<IUnknown>().unwrap());
+
+        assert_eq!(
+            three.cast::<IMissing>().unwrap_err().code(),
+            imp::E_NOINTERFACE
+        );
+    }
+}
@@ -0,0 +1,84 @@
+#[test]
+fn test() {
+    let manifest = "tests/manifest.manifest";
+    _ = std::fs::remove_file(manifest);
+
+    windows_bindgen::bindgen([
+        "--in",
+        "../../libs/bindgen/default/Windows.winmd",
+        "--out",
+        manifest,
+        "--filter",
+        "Windows.Foundation.Uri",
+        "--filter",
+        "Windows.Foundation.PropertyValue",
+        "--filter",
+        "Windows.Foundation.IUriRuntimeClass",
+        "--config",
+        "dll=Component.dll",
+    ])
+    .unwrap();
+
+    let contents = std::fs::read_to_string(manifest).unwrap();
+    std::fs::remove_file(manifest).unwrap();
+
+    assert_eq!(
+        contents,
+        r#"<?xml version="1.0" encoding="utf-8" standalone="yes"?>
+<assembly xmlns="urn:schemas-microsoft-com:asm.v1" manifestVersion="1.0">
+  <file name="Component.dll">
+    <activatableClass name="Windows.Foundation.PropertyValue" threadingModel="both" xmlns="urn:schemas-microsoft-com:winrt.v1" />
+    <activatableClass name="Windows.Foundation.Uri" threadingModel="both" xmlns="urn:schemas-microsoft-com:winrt.v1" />
+  </file>
+</assembly>
+"#
+    );
+}
+
+#[test]
+fn no_classes() {
+    let error = windows_bindgen::bindgen([
+        "--in",
+        "../../libs/bindgen/default/Windows.winmd",
+        "--out",
+        "tests/no_classes.manifest",
+        "--filter",
+        "Windows.Foundation.IStringable",
+    ])
+    .unwrap_err();
+
+    assert_eq!(error.to_string(), "error: no activatable classes\n");
+}
+
+#[test]
+fn com() {
+    let manifest = "tests/com.manifest";
+
+    windows_bindgen::bindgen([
+        "--in",
+        "../../libs/bindgen/default/Windows.Win32.winmd",
+        "--out",
+        manifest,
+        "--filter",
+        "Windows.Win32.UI.Shell.ShellLink",
+        "--config",
+        "threading=apartment",
+        "--config",
+        "progid=Test",
+    ])
+    .unwrap();
+
+    let contents = std::fs::read_to_string(manifest).unwrap();
+    std::fs::remove_file(manifest).unwrap();
+
+    assert_eq!(
+        contents,
+        r#"<?xml version="1.0" encoding="utf-8" standalone="yes"?>
+<assembly xmlns="urn:schemas-microsoft-com:asm.v1" manifestVersion="1.0">
+  <file name="com.dll">
+    <comClass clsid="{00021401-0000-0000-C000-000000000046}" threadingModel="Apartment" progid="Test.ShellLink" />
+  </file>
+</assembly>
+"#
+    );
+}
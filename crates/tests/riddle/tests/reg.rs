@@ -0,0 +1,109 @@
+fn read_utf16(path: &str) -> String {
+    let bytes = std::fs::read(path).unwrap();
+    std::fs::remove_file(path).unwrap();
+    let wide: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    assert_eq!(wide[0], 0xFEFF);
+    String::from_utf16(&wide[1..]).unwrap()
+}
+
+#[test]
+fn inproc() {
+    let reg = "tests/inproc.reg";
+
+    windows_bindgen::bindgen([
+        "--in",
+        "../../libs/bindgen/default/Windows.Win32.winmd",
+        "--out",
+        reg,
+        "--filter",
+        "Windows.Win32.UI.Shell.ShellLink",
+        "--config",
+        r"dll=C:\Server\Server.dll",
+        "--config",
+        "threading=apartment",
+    ])
+    .unwrap();
+
+    assert_eq!(
+        read_utf16(reg),
+        "Windows Registry Editor Version 5.00\r
+\r
+[HKEY_CURRENT_USER\\Software\\Classes\\CLSID\\{00021401-0000-0000-C000-000000000046}]\r
+@=\"ShellLink\"\r
+\r
+[HKEY_CURRENT_USER\\Software\\Classes\\CLSID\\{00021401-0000-0000-C000-000000000046}\\InprocServer32]\r
+@=\"C:\\\\Server\\\\Server.dll\"\r
+\"ThreadingModel\"=\"Apartment\"\r
+\r
+[HKEY_CURRENT_USER\\Software\\Classes\\CLSID\\{00021401-0000-0000-C000-000000000046}\\ProgID]\r
+@=\"inproc.ShellLink\"\r
+\r
+[HKEY_CURRENT_USER\\Software\\Classes\\inproc.ShellLink]\r
+@=\"ShellLink\"\r
+\r
+[HKEY_CURRENT_USER\\Software\\Classes\\inproc.ShellLink\\CLSID]\r
+@=\"{00021401-0000-0000-C000-000000000046}\"\r
+"
+    );
+}
+
+#[test]
+fn local() {
+    let reg = "tests/local.reg";
+
+    windows_bindgen::bindgen([
+        "--in",
+        "../../libs/bindgen/default/Windows.Win32.winmd",
+        "--out",
+        reg,
+        "--filter",
+        "Windows.Win32.UI.Shell.ShellLink",
+        "--config",
+        "exe=Server.exe",
+        "--config",
+        "progid=Test",
+        "--config",
+        "root=hklm",
+    ])
+    .unwrap();
+
+    let reg = read_utf16(reg);
+    assert!(reg.contains("[HKEY_LOCAL_MACHINE\\SOFTWARE\\Classes\\CLSID\\{00021401-0000-0000-C000-000000000046}\\LocalServer32]\r\n@=\"Server.exe\"\r\n\r\n"));
+    assert!(reg.contains("[HKEY_LOCAL_MACHINE\\SOFTWARE\\Classes\\Test.ShellLink\\CLSID]"));
+    assert!(!reg.contains("ThreadingModel"));
+}
+
+#[test]
+fn errors() {
+    let error = windows_bindgen::bindgen([
+        "--in",
+        "../../libs/bindgen/default/Windows.Win32.winmd",
+        "--out",
+        "tests/errors.reg",
+        "--filter",
+        "Windows.Win32.UI.Shell.ShellLink",
+        "--config",
+        "threading=single",
+    ])
+    .unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "error: invalid threading model `single`, expected one of both/apartment/free/neutral\n"
+    );
+
+    let error = windows_bindgen::bindgen([
+        "--in",
+        "../../libs/bindgen/default/Windows.Win32.winmd",
+        "--out",
+        "tests/errors.reg",
+        "--filter",
+        "Windows.Win32.UI.Shell.IShellLinkW",
+    ])
+    .unwrap_err();
+
+    assert_eq!(error.to_string(), "error: no COM classes\n");
+}
@@ -0,0 +1,52 @@
+#[test]
+fn component() {
+    let output = std::env::temp_dir().join(format!("riddle_scaffold_{}", std::process::id()));
+    let output = output.to_str().unwrap();
+    _ = std::fs::remove_dir_all(output);
+
+    windows_bindgen::scaffold(["--component", "Contoso.Widgets", "--out", output]).unwrap();
+
+    let read = |file: &str| std::fs::read_to_string(format!("{output}/{file}")).unwrap();
+
+    let cargo = read("Cargo.toml");
+    assert!(cargo.contains(r#"name = "contoso_widgets""#));
+    assert!(cargo.contains("[build-dependencies.windows-bindgen]"));
+    assert!(cargo.contains(r#"crate-type = ["cdylib", "rlib"]"#));
+
+    let build = read("build.rs");
+    assert!(build.contains(r#""Contoso.Widgets.winmd","#));
+    assert!(build.contains(r#""src/contoso_widgets.idl","#));
+    assert!(build.contains(r#""dll=contoso_widgets.dll","#));
+
+    assert!(read("src/contoso_widgets.idl").starts_with("namespace Contoso.Widgets\n"));
+    assert!(read("src/lib.rs").contains(r#"*name == "Contoso.Widgets.Class""#));
+    assert!(read("tests/smoke.rs").contains("contoso_widgets::activation_factory"));
+    assert!(!read("src/lib.rs").contains('$'));
+
+    // Existing crates aren't overwritten.
+    let error = windows_bindgen::scaffold(["--component", "Contoso.Widgets", "--out", output])
+        .unwrap_err()
+        .to_string();
+    assert!(error.starts_with("error: output directory is not empty"));
+
+    std::fs::remove_dir_all(output).unwrap();
+}
+
+#[test]
+fn errors() {
+    let error = |args: &[&str]| windows_bindgen::scaffold(args).unwrap_err().to_string();
+
+    assert_eq!(
+        error(&[]),
+        "error: `--component <namespace>` must be specified\n"
+    );
+    assert_eq!(
+        error(&["--component", "1Widgets"]),
+        "error: invalid namespace `1Widgets`\n"
+    );
+    assert_eq!(
+        error(&["--component", "Contoso..Widgets"]),
+        "error: invalid namespace `Contoso..Widgets`\n"
+    );
+    assert_eq!(error(&["--com"]), "error: invalid option `--com`\n");
+}
@@ -0,0 +1,29 @@
+#![allow(
+    non_snake_case,
+    non_upper_case_globals,
+    non_camel_case_types,
+    dead_code,
+    clippy::all
+)]
+#[repr(C)]
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct RECT {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+impl windows_core::TypeKind for RECT {
+    type TypeKind = windows_core::CopyType;
+}
+impl Default for RECT {
+    fn default() -> Self {
+        unsafe { core::mem::zeroed() }
+    }
+}
+#[repr(transparent)]
+#[derive(PartialEq, Eq, Copy, Clone, Default)]
+pub struct WIN32_ERROR(pub u32);
+impl windows_core::TypeKind for WIN32_ERROR {
+    type TypeKind = windows_core::CopyType;
+}
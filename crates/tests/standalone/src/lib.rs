@@ -14,6 +14,7 @@ mod b_hresult;
 mod b_hstring;
 mod b_inspectable;
 mod b_nested;
+mod b_no_debug;
 mod b_none;
 mod b_overloads;
 mod b_pcstr;
@@ -202,3 +203,21 @@ fn prepend() {
         ]
     );
 }
+
+#[test]
+fn no_debug() {
+    use b_no_debug::*;
+    let rect = RECT {
+        left: 1,
+        ..Default::default()
+    };
+    assert!(
+        rect == RECT {
+            left: 1,
+            top: 0,
+            right: 0,
+            bottom: 0
+        }
+    );
+    assert!(WIN32_ERROR(5) == WIN32_ERROR(5));
+}
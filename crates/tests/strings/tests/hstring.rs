@@ -54,3 +54,45 @@ fn hstring_builder() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn hstring_from_str() -> Result<()> {
+    // ASCII text is widened directly.
+    let h = HSTRING::from("hello");
+    assert_eq!(h.as_wide(), [0x68, 0x65, 0x6C, 0x6C, 0x6F]);
+    assert_eq!(unsafe { *h.as_ptr().add(5) }, 0);
+
+    // Other text is encoded as UTF-16, including surrogate pairs.
+    let h = HSTRING::from("héllo 🦀");
+    assert_eq!(h.len(), 8);
+    assert_eq!(h.to_string_lossy(), "héllo 🦀");
+    assert_eq!(unsafe { *h.as_ptr().add(8) }, 0);
+
+    assert!(HSTRING::from("").is_empty());
+
+    let h = HSTRING::from_wide(&[0x68, 0x69])?;
+    assert_eq!(h, "hi");
+    assert_eq!(unsafe { *h.as_ptr().add(2) }, 0);
+
+    Ok(())
+}
+
+#[test]
+fn hstring_eq() {
+    let h = HSTRING::from("hello");
+    assert_eq!(h, h.clone());
+    assert_eq!(h, HSTRING::from("hello"));
+    assert_ne!(h, HSTRING::from("hello!"));
+    assert_ne!(h, HSTRING::from("jello"));
+    assert_ne!(h, HSTRING::new());
+
+    assert_eq!(h, "hello");
+    assert_ne!(h, "hell");
+    assert_ne!(h, "hellö");
+    assert_ne!(h, "hello, world");
+
+    let h = HSTRING::from("héllo 🦀");
+    assert_eq!(h, "héllo 🦀");
+    assert_ne!(h, "hello 🦀");
+    assert_ne!(h, "héllo");
+}
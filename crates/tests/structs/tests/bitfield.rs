@@ -0,0 +1,27 @@
+use windows::Win32::Devices::Communication::*;
+
+#[test]
+fn accessors() {
+    let mut dcb = DCB::default();
+    assert_eq!(dcb._bitfield, 0);
+
+    dcb.set_fBinary(1);
+    dcb.set_fDtrControl(2);
+    dcb.set_fRtsControl(3);
+    assert_eq!(dcb.fBinary(), 1);
+    assert_eq!(dcb.fParity(), 0);
+    assert_eq!(dcb.fDtrControl(), 2);
+    assert_eq!(dcb.fRtsControl(), 3);
+    assert_eq!(dcb._bitfield, 0x3021);
+
+    // Values wider than the bitfield are truncated rather than spilling into neighbouring bits.
+    dcb.set_fParity(0xFF);
+    assert_eq!(dcb.fParity(), 1);
+    assert_eq!(dcb.fOutxCtsFlow(), 0);
+    assert_eq!(dcb._bitfield, 0x3023);
+
+    dcb.set_fDtrControl(0);
+    assert_eq!(dcb.fDtrControl(), 0);
+    assert_eq!(dcb.fRtsControl(), 3);
+    assert_eq!(dcb._bitfield, 0x3003);
+}
@@ -0,0 +1,108 @@
+use windows::Foundation::*;
+use windows_core::*;
+
+#[test]
+fn variant() -> Result<()> {
+    let boxed = VARIANT::from(123i32).box_value()?;
+    assert_eq!(i32::unbox_value(&boxed)?, 123);
+
+    let boxed = VARIANT::from(-5i8).box_value()?;
+    assert_eq!(i16::unbox_value(&boxed)?, -5);
+
+    let boxed = VARIANT::from(BSTR::from("hello")).box_value()?;
+    assert_eq!(HSTRING::unbox_value(&boxed)?, "hello");
+
+    let variant = VARIANT::unbox_value(&PropertyValue::CreateDouble(1.5)?)?;
+    assert_eq!(variant, VARIANT::from(1.5f64));
+
+    let variant = VARIANT::unbox_value(&PropertyValue::CreateString(h!("world"))?)?;
+    assert_eq!(variant, VARIANT::from(BSTR::from("world")));
+
+    let variant = VARIANT::unbox_value(&PropertyValue::CreateBoolean(true)?)?;
+    assert_eq!(variant, VARIANT::from(true));
+
+    let error = VARIANT::new().box_value().unwrap_err();
+    assert_eq!(error.code(), HRESULT(0x80028CA0_u32 as i32));
+
+    let error =
+        VARIANT::unbox_value(&PropertyValue::CreatePoint(Point { X: 1.0, Y: 2.0 })?).unwrap_err();
+    assert_eq!(error.code(), HRESULT(0x80028CA0_u32 as i32));
+
+    Ok(())
+}
+
+#[test]
+fn date() -> Result<()> {
+    // 2024-01-01 18:00:00.500 UTC
+    let time = DateTime {
+        UniversalTime: 133_486_056_005_000_000,
+    };
+
+    let variant = VARIANT::unbox_value(&PropertyValue::CreateDateTime(time)?)?;
+    assert_eq!(variant.vt(), 7);
+    assert_eq!(
+        unsafe { variant.as_raw().Anonymous.Anonymous.Anonymous.date },
+        45292.75 + 0.5 / 86_400.0
+    );
+    assert_eq!(DateTime::unbox_value(&variant.box_value()?)?, time);
+
+    // 1899-12-29 06:00:00 UTC
+    let time = DateTime {
+        UniversalTime: 94_352_472_000_000_000,
+    };
+    let variant = VARIANT::unbox_value(&PropertyValue::CreateDateTime(time)?)?;
+    assert_eq!(
+        unsafe { variant.as_raw().Anonymous.Anonymous.Anonymous.date },
+        -1.25
+    );
+    assert_eq!(DateTime::unbox_value(&variant.box_value()?)?, time);
+
+    let variant = PROPVARIANT::unbox_value(&PropertyValue::CreateDateTime(time)?)?;
+    assert_eq!(variant.vt(), 64);
+    assert_eq!(DateTime::unbox_value(&variant.box_value()?)?, time);
+
+    Ok(())
+}
+
+#[test]
+fn propvariant() -> Result<()> {
+    let boxed = PROPVARIANT::try_from(&[1i32, 2, 3][..])?.box_value()?;
+    assert_eq!(Vec::<i32>::unbox_value(&boxed)?, [1, 2, 3]);
+
+    let variant = PROPVARIANT::unbox_value(&boxed)?;
+    assert_eq!(Vec::<i32>::try_from(&variant)?, [1, 2, 3]);
+
+    let boxed = PROPVARIANT::try_from(&["one", "two"][..])?.box_value()?;
+    assert_eq!(
+        Vec::<HSTRING>::unbox_value(&boxed)?,
+        [h!("one").clone(), h!("two").clone()]
+    );
+    assert_eq!(
+        Vec::<String>::try_from(&PROPVARIANT::unbox_value(&boxed)?)?,
+        ["one", "two"]
+    );
+
+    let guid = GUID::from_u128(0x8a2b9d33_3c48_4a5b_9b2a_7d2c4e1f0a11);
+    let boxed = PROPVARIANT::from(guid).box_value()?;
+    assert_eq!(GUID::unbox_value(&boxed)?, guid);
+    assert_eq!(GUID::try_from(&PROPVARIANT::unbox_value(&boxed)?)?, guid);
+
+    let boxed = PROPVARIANT::from(7u64).box_value()?;
+    assert_eq!(u64::unbox_value(&boxed)?, 7);
+
+    Ok(())
+}
+
+#[test]
+fn object() -> Result<()> {
+    let uri = Uri::CreateUri(h!("https://example.com/"))?;
+
+    let variant = VARIANT::unbox_value(&uri.cast()?)?;
+    assert_eq!(IUnknown::try_from(&variant)?, uri.cast()?);
+    assert_eq!(variant.box_value()?.cast::<Uri>()?, uri);
+
+    let variant = PROPVARIANT::unbox_value(&uri.cast()?)?;
+    assert_eq!(variant.box_value()?.cast::<Uri>()?, uri);
+
+    Ok(())
+}
@@ -0,0 +1,189 @@
+use windows::Win32::Foundation::*;
+use windows::Win32::System::Com::CY;
+use windows::Win32::System::Variant::OleDate;
+use windows_core::*;
+
+#[test]
+fn decimal() -> Result<()> {
+    let value = DECIMAL::new(-12345, 2)?;
+    assert_eq!(value.mantissa(), -12345);
+    assert_eq!(value.scale(), 2);
+    assert!(value.is_negative());
+    assert_eq!(format!("{value:?}"), "DECIMAL(-123.45)");
+    assert_eq!(format!("{:?}", DECIMAL::new(5, 3)?), "DECIMAL(0.005)");
+    assert_eq!(format!("{:?}", DECIMAL::from(42)), "DECIMAL(42)");
+
+    // Banker's rounding, as with `VarDecRound`.
+    assert_eq!(i32::try_from(DECIMAL::new(25, 1)?)?, 2);
+    assert_eq!(i32::try_from(DECIMAL::new(35, 1)?)?, 4);
+    assert_eq!(i32::try_from(DECIMAL::new(-25, 1)?)?, -2);
+    assert_eq!(i32::try_from(DECIMAL::new(251, 2)?)?, 3);
+    assert_eq!(DECIMAL::new(12345, 3)?.round(2)?.mantissa(), 1234);
+    assert_eq!(DECIMAL::new(12, 0)?.round(2)?.mantissa(), 1200);
+
+    assert_eq!(DECIMAL::from(u64::MAX).mantissa(), u64::MAX as i128);
+    assert_eq!(
+        i128::try_from(DECIMAL::try_from(-(1i128 << 90))?)?,
+        -(1i128 << 90)
+    );
+
+    assert_eq!(
+        DECIMAL::new(1 << 96, 0).unwrap_err().code(),
+        DISP_E_OVERFLOW
+    );
+    assert_eq!(DECIMAL::new(1, 29).unwrap_err().code(), E_INVALIDARG);
+    assert_eq!(
+        u8::try_from(DECIMAL::from(256)).unwrap_err().code(),
+        DISP_E_OVERFLOW
+    );
+    assert_eq!(
+        u32::try_from(DECIMAL::from(-1)).unwrap_err().code(),
+        DISP_E_OVERFLOW
+    );
+
+    let value = DECIMAL::try_from(1.5f64)?;
+    assert_eq!((value.mantissa(), value.scale()), (15, 1));
+    assert_eq!(f64::try_from(DECIMAL::new(-125, 2)?)?, -1.25);
+
+    Ok(())
+}
+
+#[test]
+fn rust_decimal() -> Result<()> {
+    let value = DECIMAL::from(rust_decimal::Decimal::new(-31415, 4));
+    assert_eq!((value.mantissa(), value.scale()), (-31415, 4));
+
+    let value = rust_decimal::Decimal::try_from(DECIMAL::new(27182, 4)?)?;
+    assert_eq!(value, rust_decimal::Decimal::new(27182, 4));
+
+    let value = rust_decimal::Decimal::MAX;
+    assert_eq!(
+        rust_decimal::Decimal::try_from(DECIMAL::from(value))?,
+        value
+    );
+
+    Ok(())
+}
+
+#[test]
+fn currency() -> Result<()> {
+    let value = CY::from_scaled(12_345);
+    assert_eq!(value.scaled(), 12_345);
+    assert_eq!(format!("{value:?}"), "CY(1.2345)");
+    assert_eq!(format!("{:?}", CY::from_scaled(-5)), "CY(-0.0005)");
+
+    assert_eq!(CY::from(-3), CY::from_scaled(-30_000));
+    assert_eq!(
+        CY::try_from(922_337_203_685_477i64)?.scaled(),
+        9_223_372_036_854_770_000
+    );
+    assert_eq!(
+        CY::try_from(922_337_203_685_478i64).unwrap_err().code(),
+        DISP_E_OVERFLOW
+    );
+
+    assert_eq!(i64::from(CY::from_scaled(25_000)), 2);
+    assert_eq!(i64::from(CY::from_scaled(35_000)), 4);
+    assert_eq!(i64::from(CY::from_scaled(-15_001)), -2);
+    assert_eq!(f64::from(CY::from_scaled(-12_500)), -1.25);
+
+    assert_eq!(CY::try_from(1.23455f64)?, CY::from_scaled(12_346));
+    assert_eq!(CY::try_from(1e20f64).unwrap_err().code(), DISP_E_OVERFLOW);
+
+    let value = DECIMAL::from(CY::from_scaled(-12_345));
+    assert_eq!((value.mantissa(), value.scale()), (-12_345, 4));
+    assert_eq!(
+        CY::try_from(DECIMAL::new(1_234_565, 6)?)?,
+        CY::from_scaled(12_346)
+    );
+    assert_eq!(
+        CY::try_from(DECIMAL::new(123_455, 5)?)?,
+        CY::from_scaled(12_346)
+    );
+    assert_eq!(CY::try_from(DECIMAL::new(7, 0)?)?, CY::from(7));
+    assert_eq!(
+        CY::try_from(DECIMAL::from(u64::MAX)).unwrap_err().code(),
+        DISP_E_OVERFLOW
+    );
+
+    Ok(())
+}
+
+#[test]
+fn date() -> Result<()> {
+    let time = SYSTEMTIME {
+        wYear: 2024,
+        wMonth: 1,
+        wDay: 1,
+        wHour: 18,
+        wMilliseconds: 500,
+        ..Default::default()
+    };
+
+    let date = OleDate::from_system_time(&time)?;
+    assert_eq!(date.0, 45292.75 + 0.5 / 86_400.0);
+
+    let round_trip = date.to_system_time()?;
+    assert_eq!(
+        (round_trip.wYear, round_trip.wMonth, round_trip.wDay),
+        (2024, 1, 1)
+    );
+    assert_eq!(
+        (
+            round_trip.wHour,
+            round_trip.wMinute,
+            round_trip.wSecond,
+            round_trip.wMilliseconds
+        ),
+        (18, 0, 0, 500)
+    );
+    assert_eq!(round_trip.wDayOfWeek, 1);
+
+    let time = OleDate(-1.25).to_system_time()?;
+    assert_eq!(
+        (time.wYear, time.wMonth, time.wDay, time.wHour),
+        (1899, 12, 29, 6)
+    );
+
+    assert_eq!(OleDate::MAX.to_system_time()?.wMilliseconds, 999);
+    assert_eq!(
+        OleDate(3e6).to_system_time().unwrap_err().code(),
+        DISP_E_OVERFLOW
+    );
+
+    Ok(())
+}
+
+#[test]
+fn time() -> Result<()> {
+    use time::macros::datetime;
+
+    let value = time::PrimitiveDateTime::try_from(OleDate(45292.75))?;
+    assert_eq!(value, datetime!(2024-01-01 18:00));
+
+    let value = time::PrimitiveDateTime::try_from(OleDate(-1.25))?;
+    assert_eq!(value, datetime!(1899-12-29 06:00));
+
+    assert_eq!(
+        OleDate::try_from(datetime!(1899-12-29 06:00))?,
+        OleDate(-1.25)
+    );
+    assert_eq!(
+        OleDate::try_from(datetime!(1899-12-30 12:00))?,
+        OleDate(0.5)
+    );
+    assert_eq!(
+        OleDate::try_from(datetime!(0099-12-31 00:00))
+            .unwrap_err()
+            .code(),
+        DISP_E_OVERFLOW
+    );
+
+    let value = datetime!(2024-02-29 13:14:15.016);
+    assert_eq!(
+        time::PrimitiveDateTime::try_from(OleDate::try_from(value)?)?,
+        value
+    );
+
+    Ok(())
+}
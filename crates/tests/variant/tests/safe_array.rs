@@ -0,0 +1,95 @@
+use windows::Win32::Foundation::DISP_E_TYPEMISMATCH;
+use windows::Win32::System::Com::SAFEARRAYBOUND;
+use windows::Win32::System::Ole::*;
+use windows::Win32::System::Variant::*;
+use windows_core::*;
+
+#[test]
+fn vector() -> Result<()> {
+    let mut array = SafeArray::from_slice(&[1i32, 2, 3])?;
+    assert_eq!(array.dims(), 1);
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.bounds(1)?, (0, 2));
+    assert_eq!(array.get(&[1])?, 2);
+    assert!(array.get(&[3]).is_err());
+    assert!(array.get(&[0, 0]).is_err());
+
+    array.lock_mut()?[1] = 20;
+    assert_eq!(array.to_vec()?, [1, 20, 3]);
+    assert_eq!(array.lock()?.iter().sum::<i32>(), 24);
+    assert_eq!(format!("{array:?}"), "[1, 20, 3]");
+
+    let copy = array.clone();
+    array.lock_mut()?[0] = 10;
+    assert_eq!(Vec::try_from(&copy)?, [1, 20, 3]);
+
+    let empty = SafeArray::<f64>::new(0)?;
+    assert!(empty.is_empty());
+    assert!(empty.to_vec()?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn strings() -> Result<()> {
+    let array = SafeArray::from_slice(&[BSTR::from("one"), BSTR::from("two")])?;
+    let variant = VARIANT::from(array);
+    assert_eq!(variant.vt(), (VT_ARRAY | VT_BSTR).0);
+
+    let array = SafeArray::<BSTR>::try_from(&variant)?;
+    assert_eq!(array.get(&[0])?, "one");
+    assert_eq!(array.to_vec()?, ["one", "two"]);
+
+    let mut array = SafeArray::<BSTR>::new(2)?;
+    array.lock_mut()?[1] = BSTR::from("replaced");
+    assert_eq!(array.to_vec()?, ["", "replaced"]);
+
+    let error = SafeArray::<i32>::try_from(&variant).unwrap_err();
+    assert_eq!(error.code(), DISP_E_TYPEMISMATCH);
+    assert!(SafeArray::<i32>::try_from(&VARIANT::from(1i32)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn variants() -> Result<()> {
+    let array =
+        SafeArray::from_slice(&[VARIANT::from(1i32), VARIANT::from("two"), VARIANT::null()])?;
+    let values = array.to_vec()?;
+    assert_eq!(i32::try_from(&values[0])?, 1);
+    assert_eq!(BSTR::try_from(&values[1])?, "two");
+    assert!(values[2].is_null());
+
+    Ok(())
+}
+
+#[test]
+fn dimensions() -> Result<()> {
+    let mut array = SafeArray::<i32>::with_bounds(&[
+        SAFEARRAYBOUND {
+            cElements: 2,
+            lLbound: 0,
+        },
+        SAFEARRAYBOUND {
+            cElements: 3,
+            lLbound: 1,
+        },
+    ])?;
+
+    assert_eq!(array.dims(), 2);
+    assert_eq!(array.len(), 6);
+    assert_eq!(array.bounds(1)?, (0, 1));
+    assert_eq!(array.bounds(2)?, (1, 3));
+
+    // The leftmost index changes fastest.
+    for (index, value) in array.lock_mut()?.iter_mut().enumerate() {
+        *value = index as i32;
+    }
+
+    assert_eq!(array.get(&[0, 1])?, 0);
+    assert_eq!(array.get(&[1, 1])?, 1);
+    assert_eq!(array.get(&[0, 2])?, 2);
+    assert_eq!(array.get(&[1, 3])?, 5);
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use windows::Foundation::Uri;
-use windows::Win32::Foundation::{E_INVALIDARG, TYPE_E_TYPEMISMATCH};
-use windows::Win32::System::Com;
+use windows::Win32::Foundation::{
+    DISP_E_OVERFLOW, DISP_E_TYPEMISMATCH, E_INVALIDARG, FILETIME, HGLOBAL, TYPE_E_TYPEMISMATCH,
+};
+use windows::Win32::System::Com::{self, IStream, StructuredStorage::*};
+use windows::Win32::System::Variant::*;
 use windows_core::*;
 
 #[test]
@@ -260,3 +263,110 @@ fn test_propvariant() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_variant_change_type() -> Result<()> {
+    let v = VARIANT::null();
+    assert!(v.is_null());
+    assert!(!v.is_empty());
+    assert_eq!(v.vt(), VT_NULL.0);
+    assert!(!VARIANT::new().is_null());
+    assert_eq!(VARIANT::null(), VARIANT::null());
+    assert_ne!(VARIANT::null(), VARIANT::new());
+
+    let v = VARIANT::from("123");
+    assert_eq!(v.vt(), VT_BSTR.0);
+    let i = v.change_type(VT_I4.0)?;
+    assert_eq!(i.vt(), VT_I4.0);
+    assert_eq!(i32::try_from(&i)?, 123);
+    assert_eq!(u8::try_from(&v)?, 123u8);
+    assert_eq!(i8::try_from(&v)?, 123i8);
+    assert_eq!(f32::try_from(&v)?, 123.0f32);
+    assert_eq!(String::try_from(&VARIANT::from(3.5f64))?, "3.5");
+
+    assert_eq!(
+        u8::try_from(&VARIANT::from(1000i32)).unwrap_err().code(),
+        DISP_E_OVERFLOW
+    );
+    assert_eq!(
+        i8::try_from(&VARIANT::from("abc")).unwrap_err().code(),
+        DISP_E_TYPEMISMATCH
+    );
+
+    let v = PROPVARIANT::null();
+    assert!(v.is_null());
+    assert_eq!(v.vt(), VT_NULL.0);
+
+    let v = PROPVARIANT::from(-45i32);
+    assert_eq!(v.change_type(VT_BSTR.0)?.vt(), VT_BSTR.0);
+    assert_eq!(i8::try_from(&v)?, -45i8);
+    assert_eq!(f32::try_from(&v)?, -45.0f32);
+    assert_eq!(String::try_from(&v)?, "-45");
+    assert!(u8::try_from(&v).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_propvariant_vectors() -> Result<()> {
+    let v = PROPVARIANT::try_from(&[1i32, -2, 3][..])?;
+    assert_eq!(v.vt(), (VT_VECTOR | VT_I4).0);
+    assert_eq!(Vec::<i32>::try_from(&v)?, [1, -2, 3]);
+    assert_eq!(v, PROPVARIANT::try_from(&[1i32, -2, 3][..])?);
+    assert_ne!(v, PROPVARIANT::try_from(&[1i32, 2, 3][..])?);
+
+    let v = PROPVARIANT::try_from(&[][..] as &[u64])?;
+    assert_eq!(v.vt(), (VT_VECTOR | VT_UI8).0);
+    assert!(Vec::<u64>::try_from(&v)?.is_empty());
+
+    let v = PROPVARIANT::try_from(&[1.5f64, 2.5][..])?;
+    assert_eq!(Vec::<f64>::try_from(&v)?, [1.5, 2.5]);
+
+    let v = PROPVARIANT::try_from(&["hello", "", "world"][..])?;
+    assert_eq!(v.vt(), (VT_VECTOR | VT_LPWSTR).0);
+    assert_eq!(Vec::<String>::try_from(&v)?, ["hello", "", "world"]);
+
+    // A single value converts to a vector with one element.
+    assert_eq!(
+        Vec::<String>::try_from(&PROPVARIANT::from("single"))?,
+        ["single"]
+    );
+    assert!(Vec::<i32>::try_from(&PROPVARIANT::from("text")).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_propvariant_types() -> Result<()> {
+    let guid = GUID::from_u128(0x0c6b5c8b_5b69_4b4b_9d2e_7d3b9b2c1a11);
+    let v = PROPVARIANT::from(guid);
+    assert_eq!(v.vt(), VT_CLSID.0);
+    assert_eq!(GUID::try_from(&v)?, guid);
+    assert_eq!(v.clone(), v);
+
+    let time = FILETIME {
+        dwLowDateTime: 0x1234_5678,
+        dwHighDateTime: 0x01d9_0000,
+    };
+    let v = PROPVARIANT::from(time);
+    assert_eq!(v.vt(), VT_FILETIME.0);
+    assert_eq!(FILETIME::try_from(&v)?, time);
+    assert_eq!(
+        FILETIME::try_from(&PROPVARIANT::from(1i32))
+            .unwrap_err()
+            .code(),
+        TYPE_E_TYPEMISMATCH
+    );
+
+    let stream = unsafe { CreateStreamOnHGlobal(HGLOBAL::default(), true)? };
+    unsafe { stream.Write(b"data".as_ptr() as _, 4, None).ok()? };
+    let v = PROPVARIANT::from(stream.clone());
+    assert_eq!(v.vt(), VT_STREAM.0);
+    assert_eq!(IStream::try_from(&v)?, stream);
+    assert_eq!(
+        IStream::try_from(&PROPVARIANT::new()).unwrap_err().code(),
+        TYPE_E_TYPEMISMATCH
+    );
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use std::sync::mpsc::{channel, Receiver};
+use std::time::Duration;
+use windows::{
+    core::*, Win32::Foundation::*, Win32::System::Com::*, Win32::UI::Accessibility::*,
+    Win32::UI::WindowsAndMessaging::*,
+};
+
+// Creates a window with a button, a check box, and an edit control on a thread that pumps messages,
+// returning the window handle and a receiver for the button's notifications.
+fn create() -> (HWND, Receiver<u32>) {
+    let (sender, receiver) = channel();
+    let (window_sender, window_receiver) = channel();
+
+    std::thread::spawn(move || {
+        let window = WindowBuilder::new("windows-rs test automation")
+            .title("Automation")
+            .size(400, 300)
+            .create(move |_, message, wparam, _| {
+                match message {
+                    WM_COMMAND => _ = sender.send((wparam.0 >> 16) as u32),
+                    WM_DESTROY => unsafe { PostQuitMessage(0) },
+                    _ => {}
+                }
+
+                None
+            })
+            .unwrap();
+
+        let child = |class: PCWSTR, title: PCWSTR, style: i32, y: i32| unsafe {
+            CreateWindowExW(
+                WINDOW_EX_STYLE(0),
+                class,
+                title,
+                WS_CHILD | WS_VISIBLE | WS_TABSTOP | WINDOW_STYLE(style as u32),
+                10,
+                y,
+                200,
+                24,
+                window.handle(),
+                None,
+                None,
+                None,
+            )
+            .unwrap()
+        };
+
+        child(w!("BUTTON"), w!("Press"), BS_PUSHBUTTON, 10);
+        child(w!("BUTTON"), w!("Check"), BS_AUTOCHECKBOX, 40);
+        child(w!("EDIT"), w!(""), ES_LEFT, 70);
+
+        window_sender.send(window.handle().0 as isize).unwrap();
+        MessageLoop::new().run().unwrap();
+    });
+
+    (HWND(window_receiver.recv().unwrap() as _), receiver)
+}
+
+#[test]
+fn automation() -> Result<()> {
+    unsafe { CoInitializeEx(None, COINIT_MULTITHREADED).ok()? };
+    let (hwnd, notifications) = create();
+
+    let automation = Automation::new()?;
+    let window = automation.element_from_handle(hwnd)?;
+    assert_eq!(window.name()?, "Automation");
+    assert_eq!(window.native_window()?, hwnd);
+
+    let buttons = window.find_all(
+        TreeScope_Children,
+        &Condition::control_type(UIA_ButtonControlTypeId),
+    )?;
+    assert_eq!(buttons.len(), 1);
+
+    let button = window
+        .find_first(
+            TreeScope_Descendants,
+            &Condition::name("press")
+                .ignore_case()
+                .and(Condition::control_type(UIA_ButtonControlTypeId)),
+        )?
+        .unwrap();
+    assert_eq!(button.name()?, "Press");
+    assert!(button.pattern::<IUIAutomationInvokePattern>()?.is_some());
+    assert!(button.pattern::<IUIAutomationTogglePattern>()?.is_none());
+
+    // The button raises an event and notifies its parent when invoked.
+    let (sender, invoked) = channel();
+    let handler = automation.on_event(
+        UIA_Invoke_InvokedEventId,
+        &button,
+        TreeScope_Element,
+        move |element, id| _ = sender.send((element.name().unwrap(), id)),
+    )?;
+
+    button.invoke()?;
+    assert_eq!(
+        notifications.recv_timeout(Duration::from_secs(5)).unwrap(),
+        BN_CLICKED
+    );
+    assert_eq!(
+        invoked.recv_timeout(Duration::from_secs(5)).unwrap(),
+        ("Press".to_string(), UIA_Invoke_InvokedEventId)
+    );
+    drop(handler);
+
+    let check = window
+        .find_first(TreeScope_Children, &Condition::name("Check"))?
+        .unwrap();
+    assert_eq!(check.toggle_state()?, ToggleState_Off);
+    check.toggle()?;
+    assert_eq!(check.toggle_state()?, ToggleState_On);
+    assert!(button.toggle().is_err());
+
+    let edit = window
+        .find_first(
+            TreeScope_Children,
+            &Condition::control_type(UIA_EditControlTypeId),
+        )?
+        .unwrap();
+    edit.set_value("hello")?;
+    assert_eq!(edit.value()?, "hello");
+
+    let others = window.find_all(
+        TreeScope_Children,
+        &!Condition::control_type(UIA_ButtonControlTypeId)
+            .or(Condition::control_type(UIA_CheckBoxControlTypeId)),
+    )?;
+    assert!(others
+        .iter()
+        .all(|element| element.name().unwrap() != "Press"));
+
+    assert!(window
+        .find_first(TreeScope_Descendants, &Condition::automation_id("missing"))?
+        .is_none());
+
+    unsafe { PostMessageW(hwnd, WM_CLOSE, WPARAM(0), LPARAM(0))? };
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use std::path::PathBuf;
+use std::sync::{mpsc::channel, Mutex};
+use std::time::Duration;
+use windows::{core::*, Win32::System::DataExchange::*};
+
+// The clipboard is shared by every test, so they take turns using it.
+static CLIPBOARD: Mutex<()> = Mutex::new(());
+
+#[test]
+fn text() -> Result<()> {
+    let _lock = CLIPBOARD.lock().unwrap();
+    let clipboard = Clipboard::open()?;
+    clipboard.clear()?;
+    assert_eq!(clipboard.text()?, None);
+
+    let sequence = Clipboard::sequence_number();
+    clipboard.set_text("hello 🌍")?;
+    assert_ne!(Clipboard::sequence_number(), sequence);
+    assert_eq!(clipboard.text()?.as_deref(), Some("hello 🌍"));
+    Ok(())
+}
+
+#[test]
+fn formats() -> Result<()> {
+    let _lock = CLIPBOARD.lock().unwrap();
+    let clipboard = Clipboard::open()?;
+    clipboard.clear()?;
+
+    let format = Clipboard::register_format("windows-rs test format")?;
+    assert_eq!(
+        Clipboard::register_format("windows-rs test format")?,
+        format
+    );
+    assert!(!clipboard.contains(format));
+
+    clipboard.set(format, &[1, 2, 3])?;
+    clipboard.set_html("<b>bold</b> & <i>italic</i>")?;
+    clipboard.set_png(b"\x89PNG")?;
+    clipboard.set_files(&[r"C:\Windows\notepad.exe", r"C:\Users\Public\ünïcode.txt"])?;
+
+    assert!(clipboard.contains(format));
+    assert_eq!(clipboard.get(format)?.unwrap()[..3], [1, 2, 3]);
+    assert_eq!(
+        clipboard.html()?.as_deref(),
+        Some("<b>bold</b> & <i>italic</i>")
+    );
+    assert_eq!(clipboard.png()?.unwrap()[..4], *b"\x89PNG");
+    assert_eq!(
+        clipboard.files()?.unwrap(),
+        [
+            PathBuf::from(r"C:\Windows\notepad.exe"),
+            PathBuf::from(r"C:\Users\Public\ünïcode.txt")
+        ]
+    );
+
+    // Formats that weren't set are reported as missing rather than as errors.
+    assert_eq!(clipboard.text()?, None);
+    assert_eq!(clipboard.dib()?, None);
+    Ok(())
+}
+
+#[test]
+fn dib() -> Result<()> {
+    let _lock = CLIPBOARD.lock().unwrap();
+    let clipboard = Clipboard::open()?;
+    clipboard.clear()?;
+
+    // A 1x1 32-bit BITMAPINFOHEADER followed by a single pixel.
+    let mut dib = Vec::new();
+    dib.extend_from_slice(&40u32.to_le_bytes());
+    dib.extend_from_slice(&1i32.to_le_bytes());
+    dib.extend_from_slice(&1i32.to_le_bytes());
+    dib.extend_from_slice(&1u16.to_le_bytes());
+    dib.extend_from_slice(&32u16.to_le_bytes());
+    dib.extend_from_slice(&[0; 20]);
+    dib.extend_from_slice(&[0x11, 0x22, 0x33, 0xff]);
+
+    clipboard.set_dib(&dib)?;
+    assert_eq!(clipboard.dib()?.unwrap()[..dib.len()], dib);
+    Ok(())
+}
+
+#[test]
+fn busy() -> Result<()> {
+    let _lock = CLIPBOARD.lock().unwrap();
+    let clipboard = Clipboard::open()?;
+
+    // Another thread can open the clipboard once it has been closed.
+    let other = std::thread::spawn(|| Clipboard::open().map(drop));
+    std::thread::sleep(Duration::from_millis(20));
+    drop(clipboard);
+    other.join().unwrap()
+}
+
+#[test]
+fn listener() -> Result<()> {
+    let _lock = CLIPBOARD.lock().unwrap();
+    let (sender, receiver) = channel();
+
+    let listener = ClipboardListener::new(move || _ = sender.send(()))?;
+
+    let clipboard = Clipboard::open()?;
+    clipboard.clear()?;
+    clipboard.set_text("listener")?;
+    drop(clipboard);
+
+    receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+    drop(listener);
+
+    // The callback is dropped along with the listener.
+    while receiver.try_recv().is_ok() {}
+    assert!(receiver.recv().is_err());
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use windows::core::*;
+use windows::Win32::Foundation::*;
+use windows::Win32::Security::*;
+use windows::Win32::System::Com::*;
+
+#[test]
+fn initialize() -> Result<()> {
+    unsafe { CoInitializeEx(None, COINIT_MULTITHREADED).ok()? };
+
+    let error = ComSecurity::new()
+        .capabilities(EOAC_APPID)
+        .initialize()
+        .unwrap_err();
+    assert_eq!(error.code(), E_INVALIDARG);
+
+    let permissions = SecurityDescriptor::from_sddl("O:BAG:BAD:(A;;0x3;;;PS)(A;;0x3;;;SY)")?;
+
+    ComSecurity::new()
+        .authentication_level(RPC_C_AUTHN_LEVEL_PKT_PRIVACY)
+        .impersonation_level(RPC_C_IMP_LEVEL_IDENTIFY)
+        .access_permissions(&permissions)
+        .capabilities(EOAC_DISABLE_AAA)
+        .capabilities(EOAC_NO_CUSTOM_MARSHAL)
+        .initialize()?;
+
+    // Security can only be initialized once per process.
+    let error = ComSecurity::new().initialize().unwrap_err();
+    assert_eq!(error.code(), RPC_E_TOO_LATE);
+    assert_eq!(
+        error.message(),
+        "COM security has already been initialized for this process"
+    );
+
+    Ok(())
+}
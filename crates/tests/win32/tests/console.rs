@@ -0,0 +1,93 @@
+use std::io::{Read, Write};
+use windows::{
+    core::*, Win32::Foundation::*, Win32::Storage::FileSystem::*, Win32::System::Console::*,
+    Win32::System::Threading::*,
+};
+
+#[test]
+fn pseudo_console() -> Result<()> {
+    let console = PseudoConsole::new(COORD { X: 80, Y: 25 })?;
+    let process = ProcessBuilder::new("cmd.exe")
+        .args(["/c", "echo", "pseudo console"])
+        .pseudo_console(console.handle())
+        .spawn()?;
+
+    let mut output = console.output().try_clone()?;
+    let reader = std::thread::spawn(move || {
+        let mut text = Vec::new();
+        let mut buffer = [0; 1024];
+
+        // The pipe stays open until the pseudo console is closed, so read until the text appears.
+        while !String::from_utf8_lossy(&text).contains("pseudo console") {
+            let read = output.read(&mut buffer).unwrap();
+            text.extend_from_slice(&buffer[..read]);
+        }
+    });
+
+    assert_eq!(process.wait(None)?, Some(0));
+    reader.join().unwrap();
+
+    console.resize(COORD { X: 120, Y: 40 })?;
+    console.input().write_all(b"\r\n")?;
+    Ok(())
+}
+
+#[test]
+fn input() -> Result<()> {
+    // The test may not have a console, in which case one is created.
+    _ = unsafe { AllocConsole() };
+
+    let handle = unsafe {
+        CreateFileW(
+            w!("CONIN$"),
+            (GENERIC_READ | GENERIC_WRITE).0,
+            FILE_SHARE_READ | FILE_SHARE_WRITE,
+            None,
+            OPEN_EXISTING,
+            FILE_FLAGS_AND_ATTRIBUTES(0),
+            None,
+        )?
+    };
+
+    let handle = unsafe { Owned::new(handle) };
+    let input = unsafe { ConsoleInput::from_handle(*handle) };
+
+    let guard = input.set_mode_scoped(ENABLE_WINDOW_INPUT)?;
+    assert_eq!(input.mode()?, ENABLE_WINDOW_INPUT);
+
+    let mut key = INPUT_RECORD {
+        EventType: KEY_EVENT as u16,
+        ..Default::default()
+    };
+    key.Event.KeyEvent.bKeyDown = true.into();
+    key.Event.KeyEvent.wRepeatCount = 1;
+    key.Event.KeyEvent.uChar.UnicodeChar = 'q' as u16;
+
+    let mut resize = INPUT_RECORD {
+        EventType: WINDOW_BUFFER_SIZE_EVENT as u16,
+        ..Default::default()
+    };
+    resize.Event.WindowBufferSizeEvent.dwSize = COORD { X: 100, Y: 50 };
+
+    unsafe { FlushConsoleInputBuffer(input.handle())? };
+    unsafe { WriteConsoleInputW(input.handle(), &[key, resize], &mut 0)? };
+    assert_eq!(input.pending()?, 2);
+
+    let mut events = input.events();
+    let ConsoleEvent::Key(key) = events.next().unwrap()? else {
+        panic!()
+    };
+    assert!(key.down);
+    assert_eq!(key.repeat_count, 1);
+    assert_eq!(key.char(), Some('q'));
+    assert_eq!(
+        events.next().unwrap()?,
+        ConsoleEvent::Resize(COORD { X: 100, Y: 50 })
+    );
+    assert_eq!(input.pending()?, 0);
+
+    let previous = guard.previous();
+    drop(guard);
+    assert_eq!(input.mode()?, previous);
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use windows::Win32::Security::Cryptography::*;
+
+#[test]
+fn protect_unprotect() -> windows::core::Result<()> {
+    let blob = protect(b"hunter2")?;
+    assert_ne!(blob, b"hunter2");
+    assert_eq!(unprotect(&blob)?, b"hunter2");
+
+    let options = DataProtection::new()
+        .entropy(b"entropy")
+        .description("database password");
+    let blob = options.protect(b"hunter2")?;
+    assert_eq!(
+        options.unprotect_with_description(&blob)?,
+        (b"hunter2".to_vec(), "database password".to_string())
+    );
+
+    // The entropy is required to unprotect the data.
+    assert!(unprotect(&blob).is_err());
+    assert!(DataProtection::new()
+        .entropy(b"wrong")
+        .unprotect(&blob)
+        .is_err());
+
+    let machine = DataProtection::new().scope(ProtectionScope::Machine);
+    assert_eq!(machine.unprotect(&machine.protect(&[])?)?, b"");
+    Ok(())
+}
+
+#[test]
+fn protect_secret_unprotect() -> windows::core::Result<()> {
+    let blob = protect_secret("LOCAL=user", b"hunter2")?;
+    assert_eq!(unprotect_secret(&blob)?, b"hunter2");
+
+    assert!(protect_secret("NOT A DESCRIPTOR", b"hunter2").is_err());
+    assert!(unprotect_secret(b"garbage").is_err());
+    Ok(())
+}
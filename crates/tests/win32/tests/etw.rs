@@ -0,0 +1,83 @@
+use std::sync::{Arc, Mutex};
+use windows::{core::*, Win32::System::Diagnostics::Etw::*};
+
+const PROVIDER: GUID = GUID::from_u128(0x6c3c4a1e_5d0b_4c1f_9a33_2f1e7b8d4c01);
+
+#[test]
+fn write() -> Result<()> {
+    let provider = TraceProvider::register("Test.Etw.Write", PROVIDER)?;
+
+    // No trace session listens to this provider, so events are discarded without being written.
+    assert!(!provider.is_enabled(TraceLevel::LogAlways, 0));
+    assert!(!provider.is_event_enabled(&TraceEvent::new("Started", TraceLevel::Critical)));
+
+    provider.write(&TraceEvent::new("Started", TraceLevel::Information))?;
+
+    let event = TraceEvent::new("Fields", TraceLevel::Verbose)
+        .keywords(0x10)
+        .opcode(1)
+        .activity_id(new_activity_id())
+        .field("int", &-1i32)
+        .field("unsigned", &u64::MAX)
+        .field("float", &1.5f64)
+        .field("flag", &true)
+        .field("id", &PROVIDER)
+        .field("text", "hello")
+        .field("owned", &String::from("world"))
+        .field("wide", &HSTRING::from("wide"))
+        .field("bytes", [1u8, 2, 3].as_slice());
+
+    provider.write(&event)
+}
+
+#[test]
+fn macros() -> Result<()> {
+    let provider = TraceProvider::register("Test.Etw.Macros", PROVIDER)?;
+    let activity = new_activity_id();
+
+    windows::trace_event!(provider, "Started", TraceLevel::Information)?;
+    windows::trace_event!(
+        provider,
+        "Read",
+        TraceLevel::Verbose,
+        "path" = "file.txt",
+        "bytes" = 42u32
+    )?;
+    windows::trace_event!(provider, "Failed", TraceLevel::Error, keywords = 0x2, activity_id = activity; "code" = 5i32)?;
+
+    // Fields are not evaluated unless the provider is enabled.
+    let evaluated = std::cell::Cell::new(false);
+    let value = || {
+        evaluated.set(true);
+        1u32
+    };
+    windows::trace_event!(provider, "Lazy", TraceLevel::Warning, "value" = value())?;
+    assert!(!evaluated.get());
+    Ok(())
+}
+
+#[test]
+fn callback() -> Result<()> {
+    let controls = Arc::new(Mutex::new(Vec::new()));
+    let received = controls.clone();
+
+    let provider =
+        TraceProvider::register_with_callback("Test.Etw.Callback", PROVIDER, move |control| {
+            received.lock().unwrap().push(control);
+        })?;
+
+    // The callback is only invoked when a trace session changes the provider's state.
+    assert!(!provider.is_enabled(TraceLevel::LogAlways, 0));
+    drop(provider);
+    assert!(controls.lock().unwrap().is_empty());
+    Ok(())
+}
+
+#[test]
+fn activity_ids() {
+    let first = new_activity_id();
+    let second = new_activity_id();
+
+    assert_ne!(first, GUID::zeroed());
+    assert_ne!(first, second);
+}
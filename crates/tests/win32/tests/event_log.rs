@@ -0,0 +1,115 @@
+use std::sync::mpsc::channel;
+use std::time::Duration;
+use windows::{core::*, Win32::System::EventLog::*};
+
+const SOURCE: &str = "test_win32_event_log";
+const FILTER: &str = "*[System[Provider[@Name='test_win32_event_log']]]";
+
+// Logs an event to the Application channel with a unique marker string, which is returned.
+fn report(name: &str) -> String {
+    let marker = format!(
+        "{name} {} {:?}",
+        std::process::id(),
+        std::time::SystemTime::now()
+    );
+
+    unsafe {
+        let source = RegisterEventSourceW(None, &HSTRING::from(SOURCE)).unwrap();
+        let wide = HSTRING::from(&marker);
+        ReportEventW(
+            source,
+            EVENTLOG_INFORMATION_TYPE,
+            0,
+            1000,
+            None,
+            0,
+            Some(&[PCWSTR(wide.as_ptr())]),
+            None,
+        )
+        .unwrap();
+        DeregisterEventSource(source).unwrap();
+    }
+
+    marker
+}
+
+#[test]
+fn query() -> Result<()> {
+    let marker = report("query");
+
+    let event = EventQuery::channel("Application")
+        .filter(FILTER)
+        .reverse()
+        .run()?
+        .find(|event| {
+            event.as_ref().is_ok_and(|event| {
+                event.user_values().unwrap() == [EventValue::String(marker.clone())]
+            })
+        })
+        .expect("event not found")?;
+
+    let system = event.system()?;
+    assert_eq!(system.provider_name, SOURCE);
+    assert_eq!(system.provider_guid, None);
+    assert_eq!(system.event_id, 1000);
+    assert_eq!(system.level, 4);
+    assert_eq!(system.channel, "Application");
+    assert!(system.record_id > 0);
+    assert!(system.time_created > 0);
+
+    let xml = event.xml()?;
+    assert!(xml.starts_with("<Event "));
+    assert!(xml.contains(&marker));
+
+    let values = event.values(&[
+        "Event/System/Provider/@Name",
+        "Event/System/EventID",
+        "Event/System/Missing",
+    ])?;
+    assert_eq!(values[0], EventValue::String(SOURCE.to_string()));
+    assert_eq!(values[1].as_u64(), Some(1000));
+    assert_eq!(values[2], EventValue::Null);
+    Ok(())
+}
+
+#[test]
+fn empty() -> Result<()> {
+    let mut events = EventQuery::channel("Application")
+        .filter("*[System[Provider[@Name='test_win32_event_log_missing']]]")
+        .run()?;
+
+    assert!(events.next().is_none());
+    assert!(events.next().is_none());
+
+    // Unknown channels are reported when the query is run.
+    assert!(EventQuery::channel("test_win32_event_log_missing")
+        .run()
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn subscribe() -> Result<()> {
+    let (sender, receiver) = channel();
+
+    let subscription = EventQuery::channel("Application")
+        .filter(FILTER)
+        .subscribe(SubscriptionStart::FutureEvents, move |event| {
+            let values = event.and_then(|event| event.user_values());
+            _ = sender.send(values);
+        })?;
+
+    let marker = report("subscribe");
+
+    // Events logged by other tests may be delivered first.
+    loop {
+        let values = receiver.recv_timeout(Duration::from_secs(30)).unwrap()?;
+
+        if values == [EventValue::String(marker.clone())] {
+            break;
+        }
+    }
+
+    drop(subscription);
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use windows::{
+    core::*, Win32::Foundation::*, Win32::Storage::FileSystem::*, Win32::System::Memory::*,
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[repr(C)]
+struct Counter {
+    value: AtomicU32,
+}
+
+#[test]
+fn unnamed() -> Result<()> {
+    let mapping = FileMapping::new(4096)?;
+    let mut view = mapping.map(0, 16)?;
+    assert_eq!(view.len(), 16);
+    assert!(view.is_writable());
+
+    unsafe {
+        view.as_mut_slice().copy_from_slice(b"0123456789abcdef");
+        assert_eq!(view.as_slice(), b"0123456789abcdef");
+    }
+
+    // A second view of the same mapping sees the same memory, and a length of zero maps the rest of the mapping.
+    let whole = mapping.map_read(0, 0)?;
+    assert_eq!(whole.len(), 4096);
+    assert!(!whole.is_writable());
+    assert_eq!(unsafe { &whole.as_slice()[..4] }, b"0123");
+    Ok(())
+}
+
+#[test]
+fn named() -> Result<()> {
+    let name = "Local\\test_file_mapping_named";
+    let first = FileMapping::create(name, std::mem::size_of::<Counter>() as u64)?;
+    let second = FileMapping::open(name, true)?;
+
+    let a = first.map(0, 0)?.cast::<Counter>()?;
+    let b = second.map(0, 0)?.cast::<Counter>()?;
+
+    unsafe {
+        a.get().value.fetch_add(2, Ordering::SeqCst);
+        b.get().value.fetch_add(3, Ordering::SeqCst);
+        assert_eq!(a.get().value.load(Ordering::SeqCst), 5);
+    }
+
+    drop(first);
+    drop(second);
+
+    // Views keep the mapping alive after its handles are closed.
+    assert_eq!(unsafe { b.get() }.value.load(Ordering::SeqCst), 5);
+
+    let error = FileMapping::open("Local\\test_file_mapping_missing", false).unwrap_err();
+    assert_eq!(error.code(), ERROR_FILE_NOT_FOUND.to_hresult());
+    Ok(())
+}
+
+#[test]
+fn typed() -> Result<()> {
+    let mapping = FileMapping::new(4096)?;
+    let view = mapping.map(0, 0)?.cast::<Point>()?;
+    view.write(Point { x: 1, y: 2 })?;
+    assert_eq!(unsafe { view.read() }, Point { x: 1, y: 2 });
+
+    let read = mapping.map_read(0, 0)?.cast::<Point>()?;
+    assert_eq!(unsafe { read.read() }, Point { x: 1, y: 2 });
+    assert_eq!(
+        read.write(Point { x: 0, y: 0 }).unwrap_err().code(),
+        E_ACCESSDENIED
+    );
+
+    let error = mapping.map(0, 4).unwrap().cast::<Point>().unwrap_err();
+    assert_eq!(error.code(), E_INVALIDARG);
+    Ok(())
+}
+
+#[test]
+fn file() -> Result<()> {
+    let path = std::env::temp_dir().join("test_file_mapping.txt");
+    std::fs::write(&path, b"hello world").unwrap();
+
+    unsafe {
+        let file = Owned::new(CreateFileW(
+            &HSTRING::from(path.as_path()),
+            (FILE_GENERIC_READ | FILE_GENERIC_WRITE).0,
+            FILE_SHARE_READ,
+            None,
+            OPEN_EXISTING,
+            FILE_ATTRIBUTE_NORMAL,
+            None,
+        )?);
+
+        let mapping = FileMapping::from_file(*file, 0, true)?;
+        drop(file);
+        let mut view = mapping.map(0, 5)?;
+        view.as_mut_slice().copy_from_slice(b"HELLO");
+        view.flush()?;
+    }
+
+    assert_eq!(std::fs::read(&path).unwrap(), b"HELLO world");
+    std::fs::remove_file(&path).unwrap();
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use windows::core::*;
+use windows::Win32::Foundation::*;
+use windows::Win32::Graphics::{Direct3D::*, Direct3D11::*, Dxgi::*};
+
+// Returns the current reference count of the object behind `raw`.
+unsafe fn ref_count(raw: *mut core::ffi::c_void) -> u32 {
+    let vtable = (*(raw as *mut *mut IUnknown_Vtbl)).as_ref().unwrap();
+    (vtable.AddRef)(raw);
+    (vtable.Release)(raw)
+}
+
+fn create_device() -> Result<ID3D11Device> {
+    let mut device = None;
+    unsafe {
+        D3D11CreateDevice(
+            None,
+            D3D_DRIVER_TYPE_WARP,
+            HMODULE::default(),
+            D3D11_CREATE_DEVICE_FLAG(0),
+            None,
+            D3D11_SDK_VERSION,
+            Some(&mut device),
+            None,
+            None,
+        )?
+    };
+    Ok(device.unwrap())
+}
+
+#[test]
+fn borrowed() -> Result<()> {
+    let device = create_device()?;
+    let raw = device.as_raw();
+
+    unsafe {
+        let before = ref_count(raw);
+        let borrowed = ID3D11Device::from_raw_borrowed(&raw).unwrap();
+        assert_eq!(borrowed.GetFeatureLevel(), device.GetFeatureLevel());
+        assert_eq!(ref_count(raw), before);
+
+        let cloned = ID3D11Device::from_raw_cloned(raw).unwrap();
+        assert_eq!(ref_count(raw), before + 1);
+        drop(cloned);
+        assert_eq!(ref_count(raw), before);
+
+        assert!(ID3D11Device::from_raw_cloned(core::ptr::null_mut()).is_none());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn queried() -> Result<()> {
+    let device = create_device()?;
+
+    unsafe {
+        // The raw pointer may come from another library that only knows it as some COM interface.
+        let dxgi = IDXGIDevice::cast_from_raw(device.as_raw())?;
+        let adapter: IDXGIAdapter = dxgi.GetAdapter()?;
+        assert!(
+            adapter.GetDesc()?.DedicatedSystemMemory > 0
+                || adapter.GetDesc()?.SharedSystemMemory > 0
+        );
+
+        let device2 = ID3D11Device::cast_from_raw(dxgi.as_raw())?;
+        assert_eq!(device2, device);
+
+        assert_eq!(
+            IDXGIFactory::cast_from_raw(device.as_raw())
+                .unwrap_err()
+                .code(),
+            E_NOINTERFACE
+        );
+        assert_eq!(
+            ID3D11Device::cast_from_raw(core::ptr::null_mut())
+                .unwrap_err()
+                .code(),
+            E_POINTER
+        );
+    }
+
+    // Handing ownership to another library and taking it back.
+    let raw = device.clone().into_raw();
+    let device2 = unsafe { ID3D11Device::from_raw(raw) };
+    assert_eq!(device2, device);
+    Ok(())
+}
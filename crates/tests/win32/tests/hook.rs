@@ -0,0 +1,84 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use windows::{core::*, Win32::Foundation::*, Win32::UI::WindowsAndMessaging::*};
+
+fn send(window: &Window, message: u32) {
+    unsafe { SendMessageW(window.handle(), message, WPARAM(0), LPARAM(0)) };
+}
+
+#[test]
+fn thread_hook() -> Result<()> {
+    let window = WindowBuilder::new("windows-rs test hook")
+        .message_only()
+        .create(|_, _, _, _| None)?;
+
+    let first = Rc::new(Cell::new(0));
+    let second = Rc::new(Cell::new(0));
+
+    let counter = |count: &Rc<Cell<u32>>| {
+        let count = count.clone();
+
+        move |_, _, lparam: LPARAM| {
+            let info = unsafe { &*(lparam.0 as *const CWPSTRUCT) };
+
+            if info.message == WM_USER {
+                count.set(count.get() + 1);
+            }
+
+            None
+        }
+    };
+
+    let first_hook = Hook::new(WH_CALLWNDPROC, counter(&first))?;
+    let second_hook = Hook::new(WH_CALLWNDPROC, counter(&second))?;
+    send(&window, WM_USER);
+    assert_eq!((first.get(), second.get()), (1, 1));
+
+    drop(first_hook);
+    send(&window, WM_USER);
+    assert_eq!((first.get(), second.get()), (1, 2));
+
+    // Callbacks are dropped along with their hooks.
+    drop(second_hook);
+    send(&window, WM_USER);
+    assert_eq!((first.get(), second.get()), (1, 2));
+    assert_eq!(Rc::strong_count(&second), 1);
+    Ok(())
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(
+        Hook::new(WINDOWS_HOOK_ID(100), |_, _, _| None)
+            .err()
+            .unwrap()
+            .code(),
+        E_INVALIDARG
+    );
+}
+
+#[test]
+fn hook_thread() -> Result<()> {
+    // A thread hook only observes the hook thread, so the rest of the test process is unaffected.
+    let thread = HookThread::new(|| Ok(vec![Hook::new(WH_GETMESSAGE, |_, _, _| None)?]))?;
+    drop(thread);
+
+    let error = HookThread::new(|| Err(E_FAIL.into())).err().unwrap();
+    assert_eq!(error.code(), E_FAIL);
+    Ok(())
+}
+
+// Low-level hooks observe input across the desktop, so this doesn't run by default.
+#[test]
+#[ignore]
+fn low_level_hooks() -> Result<()> {
+    let thread = HookThread::new(|| {
+        Ok(vec![
+            Hook::keyboard_ll(|_, _| false)?,
+            Hook::mouse_ll(|_, _| false)?,
+        ])
+    })?;
+
+    drop(thread);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::os::windows::io::AsRawHandle;
+use std::process::{Child, Command};
+use std::time::{Duration, Instant};
+use windows::{Win32::Foundation::*, Win32::System::JobObjects::*};
+
+// Spawns a process that runs for about 30 seconds unless it is terminated.
+fn spawn() -> Child {
+    Command::new("cmd.exe")
+        .args(["/c", "ping -n 30 127.0.0.1 >nul"])
+        .spawn()
+        .unwrap()
+}
+
+fn handle(child: &Child) -> HANDLE {
+    HANDLE(child.as_raw_handle())
+}
+
+#[test]
+fn assign() {
+    let job = Job::new().unwrap();
+    let notifications = job.notifications().unwrap();
+    let mut child = spawn();
+
+    assert!(!job.contains_process(handle(&child)).unwrap());
+    job.assign_process(handle(&child)).unwrap();
+    assert!(job.contains_process(handle(&child)).unwrap());
+    assert!(job.process_ids().unwrap().contains(&(child.id() as usize)));
+
+    assert_eq!(
+        notifications.next(Some(Duration::from_secs(5))).unwrap(),
+        Some(JobNotification::NewProcess(child.id() as usize))
+    );
+
+    job.terminate(123).unwrap();
+    assert_eq!(child.wait().unwrap().code(), Some(123));
+
+    // Terminating the job's last process reports that no processes remain active.
+    let mut active_zero = false;
+    while let Some(notification) = notifications.next(Some(Duration::from_secs(5))).unwrap() {
+        if notification == JobNotification::ActiveProcessZero {
+            active_zero = true;
+            break;
+        }
+    }
+    assert!(active_zero);
+    assert_eq!(
+        notifications.next(Some(Duration::from_millis(10))).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn kill_on_close() {
+    let job = Job::new().unwrap();
+    job.set_limits(
+        &JobLimits::new()
+            .kill_on_close()
+            .active_processes(8)
+            .job_memory(1 << 30),
+    )
+    .unwrap();
+    job.set_ui_restrictions(JOB_OBJECT_UILIMIT_READCLIPBOARD | JOB_OBJECT_UILIMIT_WRITECLIPBOARD)
+        .unwrap();
+    job.set_cpu_rate(Some(5_000)).unwrap();
+    job.set_cpu_rate(None).unwrap();
+
+    let start = Instant::now();
+    let mut child = spawn();
+    job.assign_process(handle(&child)).unwrap();
+    drop(job);
+
+    child.wait().unwrap();
+    assert!(start.elapsed() < Duration::from_secs(20));
+}
+
+#[test]
+fn named() {
+    let job = Job::create("test_job_named").unwrap();
+    let opened = Job::open("test_job_named").unwrap();
+
+    let mut child = spawn();
+    job.assign_process(handle(&child)).unwrap();
+    assert!(opened.contains_process(handle(&child)).unwrap());
+    opened.terminate(1).unwrap();
+    assert_eq!(child.wait().unwrap().code(), Some(1));
+
+    let error = Job::open("test_job_missing").unwrap_err();
+    assert_eq!(error.code(), ERROR_FILE_NOT_FOUND.to_hresult());
+}
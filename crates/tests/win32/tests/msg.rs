@@ -0,0 +1,123 @@
+use windows::{
+    Win32::Foundation::*, Win32::System::DataExchange::*, Win32::UI::WindowsAndMessaging::*,
+};
+
+#[test]
+fn decode() {
+    let msg = unsafe {
+        Msg::decode(
+            WM_SIZE,
+            WPARAM(SIZE_MAXIMIZED as usize),
+            LPARAM(0x0300_0400),
+        )
+    };
+    assert_eq!(
+        msg,
+        Msg::Size(SizeMsg {
+            kind: SIZE_MAXIMIZED,
+            width: 0x400,
+            height: 0x300
+        })
+    );
+
+    // Coordinates are signed since they may be negative on multiple monitor systems.
+    let msg = unsafe { Msg::decode(WM_MOUSEMOVE, WPARAM(1), LPARAM(0xFFFE_000A)) };
+    assert_eq!(
+        msg,
+        Msg::MouseMove(MouseMoveMsg {
+            x: 10,
+            y: -2,
+            keys: 1
+        })
+    );
+
+    let msg = unsafe { Msg::decode(WM_KEYDOWN, WPARAM(0x41), LPARAM(0x4104_0003)) };
+    assert_eq!(
+        msg,
+        Msg::KeyDown(KeyDownMsg {
+            key: 0x41,
+            repeat_count: 3,
+            scan_code: 4,
+            extended: true,
+            previous_down: true,
+        })
+    );
+
+    let msg = unsafe { Msg::decode(WM_COMMAND, WPARAM(0x0001_0064), LPARAM(0)) };
+    assert_eq!(
+        msg,
+        Msg::Command(CommandMsg {
+            id: 100,
+            code: 1,
+            control: HWND::default()
+        })
+    );
+
+    let msg = unsafe { Msg::decode(WM_USER, WPARAM(1), LPARAM(2)) };
+    assert_eq!(msg, Msg::Other(WM_USER, WPARAM(1), LPARAM(2)));
+    assert_eq!(msg.handled(), LRESULT(0));
+}
+
+#[test]
+fn round_trip() {
+    let rect = RECT {
+        left: 1,
+        top: 2,
+        right: 3,
+        bottom: 4,
+    };
+
+    let bytes = b"hello";
+    let data = COPYDATASTRUCT {
+        dwData: 7,
+        cbData: bytes.len() as u32,
+        lpData: bytes.as_ptr() as *mut _,
+    };
+
+    let messages = [
+        Msg::Size(SizeMsg {
+            kind: SIZE_RESTORED,
+            width: 640,
+            height: 480,
+        }),
+        Msg::KeyDown(KeyDownMsg {
+            key: 0x0D,
+            repeat_count: 1,
+            scan_code: 0x1C,
+            extended: false,
+            previous_down: false,
+        }),
+        Msg::MouseMove(MouseMoveMsg {
+            x: -5,
+            y: 300,
+            keys: 0,
+        }),
+        Msg::Command(CommandMsg {
+            id: 2,
+            code: 0,
+            control: HWND(8 as _),
+        }),
+        Msg::DpiChanged(DpiChangedMsg {
+            dpi_x: 144,
+            dpi_y: 144,
+            suggested: &rect,
+        }),
+        Msg::CopyData(CopyDataMsg {
+            sender: HWND(16 as _),
+            data: &data,
+        }),
+    ];
+
+    for msg in messages {
+        let (message, wparam, lparam) = msg.encode();
+        assert_eq!(unsafe { Msg::decode(message, wparam, lparam) }, msg);
+    }
+
+    let (message, wparam, lparam) = messages[5].encode();
+    let Msg::CopyData(copy) = (unsafe { Msg::decode(message, wparam, lparam) }) else {
+        panic!();
+    };
+    assert_eq!(copy.data.dwData, 7);
+    assert_eq!(copy.bytes(), b"hello");
+    assert_eq!(messages[5].handled(), LRESULT(1));
+}
@@ -0,0 +1,53 @@
+use std::time::Duration;
+use windows::Win32::System::Performance::*;
+
+#[test]
+fn counter() -> windows::core::Result<()> {
+    let query = CounterQuery::new()?;
+    let cpu = query.add_english(r"\Processor(_Total)\% Processor Time")?;
+    let processes = query.add_english(r"\System\Processes")?;
+    let processors = query.add_english(r"\Processor(*)\% Processor Time")?;
+
+    query.collect()?;
+    assert!(processes.large_value()? > 0);
+
+    // Rate counters need a second sample.
+    assert!(cpu.value().is_err());
+    std::thread::sleep(Duration::from_millis(100));
+    query.collect()?;
+
+    let value = cpu.value()?;
+    assert!((0.0..=100.0).contains(&value));
+
+    let values = processors.values()?;
+    assert!(values.iter().any(|(name, _)| name == "_Total"));
+    assert!(values.len() >= 2);
+    Ok(())
+}
+
+#[test]
+fn collect_every() -> windows::core::Result<()> {
+    let query = CounterQuery::new()?;
+    let processes = query.add_english(r"\System\Processes")?;
+
+    let timer = query.collect_every(Duration::from_secs(1))?;
+    timer.wait()?;
+    assert!(processes.large_value()? > 0);
+    Ok(())
+}
+
+#[test]
+fn browse() -> windows::core::Result<()> {
+    assert!(CounterQuery::new()?.add(r"\Invalid\Counter").is_err());
+
+    let paths = expand_counter_path(r"\Processor(_Total)\*")?;
+    assert!(!paths.is_empty());
+    assert!(paths.iter().all(|path| path.contains("Processor(_Total)")));
+
+    assert!(counter_objects()?.len() > 1);
+
+    let (counters, instances) = counter_object_items("System")?;
+    assert!(!counters.is_empty());
+    assert!(instances.is_empty());
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use std::sync::mpsc::channel;
+use std::time::Duration;
+use windows::Win32::System::Power::*;
+use windows::Win32::System::RemoteDesktop::*;
+use windows::Win32::System::Services::*;
+use windows::Win32::System::SystemServices::*;
+use windows::Win32::UI::WindowsAndMessaging::*;
+
+#[test]
+fn setting() -> windows::core::Result<()> {
+    let (sender, receiver) = channel();
+
+    // The current value of a setting is reported as soon as it is registered.
+    let listener = PowerNotifications::new()
+        .setting(GUID_ACDC_POWER_SOURCE)
+        .listen(move |event| _ = sender.send(event))?;
+
+    match receiver.recv_timeout(Duration::from_secs(5)).unwrap() {
+        PowerEvent::Setting(setting, value) => {
+            assert_eq!(setting, GUID_ACDC_POWER_SOURCE);
+            assert_eq!(value.len(), 4);
+        }
+        event => panic!("{event:?}"),
+    }
+
+    drop(listener);
+    Ok(())
+}
+
+#[test]
+fn sessions() -> windows::core::Result<()> {
+    let listener = PowerNotifications::new().sessions().listen(|_| {})?;
+    drop(listener);
+    Ok(())
+}
+
+#[test]
+fn service_control() {
+    unsafe {
+        assert_eq!(
+            PowerEvent::from_service_control(
+                SERVICE_CONTROL_POWEREVENT,
+                PBT_APMSUSPEND,
+                std::ptr::null()
+            ),
+            Some(PowerEvent::Suspend)
+        );
+
+        assert_eq!(
+            PowerEvent::from_service_control(
+                SERVICE_CONTROL_POWEREVENT,
+                PBT_APMRESUMEAUTOMATIC,
+                std::ptr::null()
+            ),
+            Some(PowerEvent::ResumeAutomatic)
+        );
+
+        let notification = WTSSESSION_NOTIFICATION {
+            cbSize: std::mem::size_of::<WTSSESSION_NOTIFICATION>() as u32,
+            dwSessionId: 3,
+        };
+
+        assert_eq!(
+            PowerEvent::from_service_control(
+                SERVICE_CONTROL_SESSIONCHANGE,
+                WTS_SESSION_LOCK,
+                &notification as *const _ as _
+            ),
+            Some(PowerEvent::Session {
+                reason: WTS_SESSION_LOCK,
+                session_id: 3
+            })
+        );
+
+        assert_eq!(
+            PowerEvent::from_service_control(SERVICE_CONTROL_STOP, 0, std::ptr::null()),
+            None
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use std::time::Duration;
+use windows::{Win32::Foundation::*, Win32::System::JobObjects::*, Win32::System::Threading::*};
+
+#[test]
+fn exit_code() {
+    let process = ProcessBuilder::new("cmd.exe")
+        .args(["/c", "exit 7"])
+        .spawn()
+        .unwrap();
+
+    assert_eq!(process.wait(None).unwrap(), Some(7));
+    assert_eq!(process.exit_code().unwrap(), Some(7));
+}
+
+#[test]
+fn environment() {
+    let run = |builder: &mut ProcessBuilder| {
+        builder
+            .args(["/c", "if %TEST_PROCESS_MODE%==worker exit 3"])
+            .spawn()
+            .unwrap()
+            .wait(None)
+            .unwrap()
+    };
+
+    assert_eq!(
+        run(ProcessBuilder::new("cmd.exe").env("test_process_mode", "worker")),
+        Some(3)
+    );
+    assert_eq!(run(&mut ProcessBuilder::new("cmd.exe")), Some(0));
+    assert_eq!(
+        run(ProcessBuilder::new("cmd.exe")
+            .env("TEST_PROCESS_MODE", "worker")
+            .env_remove("TEST_PROCESS_MODE")),
+        Some(0)
+    );
+
+    // Inherited variables, such as `SystemRoot`, are replaced by variables with the same name, regardless of case.
+    assert_eq!(
+        ProcessBuilder::new("cmd.exe")
+            .args(["/c", "if %SYSTEMROOT%==other exit 4"])
+            .env("systemroot", "other")
+            .spawn()
+            .unwrap()
+            .wait(None)
+            .unwrap(),
+        Some(4)
+    );
+}
+
+#[test]
+fn current_dir() {
+    let dir = std::env::temp_dir().join("test_process_current_dir");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("marker.txt"), b"").unwrap();
+
+    let process = ProcessBuilder::new("cmd.exe")
+        .args(["/c", "if exist marker.txt exit 5"])
+        .current_dir(&dir)
+        .spawn()
+        .unwrap();
+
+    assert_eq!(process.wait(None).unwrap(), Some(5));
+}
+
+#[test]
+fn suspended_job() {
+    let job = Job::new().unwrap();
+    let process = ProcessBuilder::new("cmd.exe")
+        .args(["/c", "exit 0"])
+        .creation_flags(CREATE_SUSPENDED)
+        .job(job.handle())
+        .spawn()
+        .unwrap();
+
+    assert!(job.contains_process(*process.process).unwrap());
+    assert_eq!(process.wait(Some(Duration::from_millis(10))).unwrap(), None);
+    assert_eq!(process.exit_code().unwrap(), None);
+
+    process.resume().unwrap();
+    assert_eq!(process.wait(None).unwrap(), Some(0));
+
+    let process = ProcessBuilder::new("cmd.exe")
+        .creation_flags(CREATE_SUSPENDED)
+        .spawn()
+        .unwrap();
+    process.kill(9).unwrap();
+    assert_eq!(process.wait(None).unwrap(), Some(9));
+}
+
+#[test]
+fn inherit_handle() {
+    let event = unsafe { CreateEventW(None, true, false, None).unwrap() };
+    let process = ProcessBuilder::new("cmd.exe")
+        .args(["/c", "exit 0"])
+        .inherit_handle(event)
+        .spawn()
+        .unwrap();
+    assert_eq!(process.wait(None).unwrap(), Some(0));
+
+    // The handle is only inheritable while the process is created.
+    let mut flags = 0;
+    unsafe { GetHandleInformation(event, &mut flags).unwrap() };
+    assert_eq!(flags & HANDLE_FLAG_INHERIT.0, 0);
+    unsafe { CloseHandle(event).unwrap() };
+}
+
+#[test]
+fn missing() {
+    assert!(ProcessBuilder::new("test_process_missing.exe")
+        .spawn()
+        .is_err());
+}
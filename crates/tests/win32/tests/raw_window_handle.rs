@@ -0,0 +1,56 @@
+use raw_window_handle::*;
+use windows::{core::*, Win32::Foundation::*, Win32::UI::WindowsAndMessaging::*};
+
+#[test]
+fn conversions() -> Result<()> {
+    let window = WindowBuilder::new("windows-rs test raw_window_handle")
+        .message_only()
+        .create(|_, _, _, _| None)?;
+
+    let handle = Win32WindowHandle::try_from(window.handle())?;
+    assert_eq!(handle.hwnd.get(), window.handle().0 as isize);
+    assert!(handle.hinstance.is_some());
+    assert_eq!(HWND::from(handle), window.handle());
+
+    let raw = RawWindowHandle::try_from(window.handle())?;
+    assert_eq!(HWND::try_from(raw)?, window.handle());
+
+    let error = Win32WindowHandle::try_from(HWND::default()).unwrap_err();
+    assert_eq!(error.code(), E_INVALIDARG);
+
+    let other = RawWindowHandle::Web(WebWindowHandle::new(1));
+    assert_eq!(HWND::try_from(other).unwrap_err().code(), E_INVALIDARG);
+
+    Ok(())
+}
+
+#[test]
+fn has_handle() -> Result<()> {
+    let window = WindowBuilder::new("windows-rs test has_handle")
+        .message_only()
+        .create(|_, _, _, _| None)?;
+
+    let raw = unsafe { RawWindow::new(window.handle()) };
+    assert_eq!(raw.handle(), window.handle());
+    assert_eq!(
+        raw.window_handle().unwrap().as_raw(),
+        RawWindowHandle::try_from(window.handle())?
+    );
+    assert_eq!(
+        raw.display_handle().unwrap().as_raw(),
+        RawDisplayHandle::Windows(WindowsDisplayHandle::new())
+    );
+
+    assert_eq!(
+        window.window_handle().unwrap().as_raw(),
+        RawWindowHandle::try_from(window.handle())?
+    );
+
+    let null = unsafe { RawWindow::new(HWND::default()) };
+    assert!(matches!(
+        null.window_handle(),
+        Err(HandleError::Unavailable)
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use windows::{core::*, Win32::System::RestartManager::*};
+
+#[test]
+fn locking() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("restart_manager_{}.txt", std::process::id()));
+    let file = std::fs::File::create(&path).unwrap();
+
+    let session = RestartSession::new()?;
+    session.register_files([&path])?;
+
+    let processes = session.processes()?;
+    let current = processes
+        .iter()
+        .find(|process| process.id() == std::process::id())
+        .expect("current process");
+    assert!(!current.app_name.is_empty());
+    assert!(current.service_name.is_empty());
+    assert_eq!(current.status, RmStatusRunning);
+
+    assert_eq!(locking_processes([&path])?, processes);
+
+    drop(file);
+    assert!(locking_processes([&path])?.is_empty());
+    std::fs::remove_file(&path).unwrap();
+
+    Ok(())
+}
+
+#[test]
+fn unused() -> Result<()> {
+    let session = RestartSession::new()?;
+    session.register_files([std::env::temp_dir().join("restart_manager_missing.txt")])?;
+    assert!(session.processes()?.is_empty());
+    assert_eq!(session.reboot_reasons()?, RmRebootReasonNone);
+
+    Ok(())
+}
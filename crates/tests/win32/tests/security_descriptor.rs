@@ -0,0 +1,170 @@
+use windows::{
+    Win32::Foundation::*, Win32::Security::Authorization::*, Win32::Security::*,
+    Win32::Storage::FileSystem::*, Win32::System::SystemServices::*, Win32::System::Threading::*,
+};
+
+#[test]
+fn sid() {
+    let admins: Sid = "BA".parse().unwrap();
+    assert_eq!(admins.to_string(), "S-1-5-32-544");
+    assert_eq!(admins, "S-1-5-32-544".parse().unwrap());
+
+    let error = "NotASid".parse::<Sid>().unwrap_err();
+    assert_eq!(error.code(), ERROR_INVALID_SID.to_hresult());
+}
+
+#[test]
+fn sddl() {
+    let descriptor =
+        SecurityDescriptor::from_sddl("O:BAG:SYD:P(A;OICI;GA;;;SY)(D;;GW;;;WD)").unwrap();
+    assert_eq!(
+        descriptor.owner().unwrap().unwrap().to_string(),
+        "S-1-5-32-544"
+    );
+    assert_eq!(descriptor.group().unwrap().unwrap().to_string(), "S-1-5-18");
+    assert!(descriptor.is_dacl_protected().unwrap());
+    assert_eq!(descriptor.sacl().unwrap(), None);
+
+    let dacl = descriptor.dacl().unwrap().unwrap();
+    assert_eq!(dacl.len(), 2);
+    assert_eq!(
+        dacl[0],
+        Ace::allow("SY".parse().unwrap(), GENERIC_ALL.0)
+            .inherit(OBJECT_INHERIT_ACE | CONTAINER_INHERIT_ACE)
+    );
+    assert_eq!(dacl[1], Ace::deny("WD".parse().unwrap(), GENERIC_WRITE.0));
+
+    assert_eq!(
+        descriptor
+            .to_sddl(OWNER_SECURITY_INFORMATION | DACL_SECURITY_INFORMATION)
+            .unwrap(),
+        "O:BAD:P(A;OICI;GA;;;SY)(D;;GW;;;WD)"
+    );
+
+    let descriptor: SecurityDescriptor = "D:".parse().unwrap();
+    assert_eq!(descriptor.owner().unwrap(), None);
+    assert_eq!(descriptor.dacl().unwrap(), Some(vec![]));
+
+    let error = SecurityDescriptor::from_sddl("D:(X;;;)").unwrap_err();
+    assert_eq!(error.code(), ERROR_INVALID_PARAMETER.to_hresult());
+}
+
+#[test]
+fn builder() {
+    let builder = SecurityDescriptorBuilder::new()
+        .owner("BA".parse().unwrap())
+        .ace(Ace::deny("WD".parse().unwrap(), FILE_GENERIC_WRITE.0))
+        .ace(Ace::allow("SY".parse().unwrap(), GENERIC_ALL.0).inherit(CONTAINER_INHERIT_ACE))
+        .ace(Ace::audit(
+            "WD".parse().unwrap(),
+            GENERIC_ALL.0,
+            false,
+            true,
+        ))
+        .ace(Ace::mandatory_label(
+            "LW".parse().unwrap(),
+            SYSTEM_MANDATORY_LABEL_NO_WRITE_UP,
+        ))
+        .protected();
+
+    assert_eq!(
+        builder.sddl().unwrap(),
+        "O:S-1-5-32-544D:P(D;;0x120116;;;S-1-1-0)(A;CI;0x10000000;;;S-1-5-18)S:P(AU;FA;0x10000000;;;S-1-1-0)(ML;;0x1;;;S-1-16-4096)"
+    );
+
+    let descriptor = builder.build().unwrap();
+    let dacl = descriptor.dacl().unwrap().unwrap();
+    assert_eq!(dacl[0].kind, AceKind::Deny);
+    assert_eq!(dacl[1].flags, CONTAINER_INHERIT_ACE);
+
+    let sacl = descriptor.sacl().unwrap().unwrap();
+    assert_eq!(sacl[0].kind, AceKind::Audit);
+    assert_eq!(sacl[0].flags, FAILED_ACCESS_ACE_FLAG);
+    assert_eq!(sacl[1].kind, AceKind::MandatoryLabel);
+    assert_eq!(sacl[1].sid.to_string(), "S-1-16-4096");
+
+    let descriptor = SecurityDescriptorBuilder::new().build().unwrap();
+    assert_eq!(descriptor.dacl().unwrap(), None);
+
+    let descriptor = SecurityDescriptorBuilder::new()
+        .empty_dacl()
+        .build()
+        .unwrap();
+    assert_eq!(descriptor.dacl().unwrap(), Some(vec![]));
+
+    let error = SecurityDescriptorBuilder::new()
+        .ace(Ace::allow("SY".parse().unwrap(), GENERIC_ALL.0).inherit(ACE_FLAGS(0x20)))
+        .sddl()
+        .unwrap_err();
+    assert_eq!(error.code(), E_INVALIDARG);
+}
+
+#[test]
+fn file() {
+    let path = std::env::temp_dir().join(format!("security_descriptor_{}.txt", std::process::id()));
+    std::fs::write(&path, "test").unwrap();
+    let name = path.to_str().unwrap();
+
+    let user = Token::current_process(TOKEN_QUERY).unwrap().user().unwrap();
+
+    SecurityDescriptorBuilder::new()
+        .ace(Ace::allow(user.clone(), FILE_ALL_ACCESS.0))
+        .ace(Ace::allow("BA".parse().unwrap(), FILE_GENERIC_READ.0))
+        .protected()
+        .build()
+        .unwrap()
+        .apply_to_named(name, SE_FILE_OBJECT, DACL_SECURITY_INFORMATION)
+        .unwrap();
+
+    let descriptor =
+        SecurityDescriptor::from_named(name, SE_FILE_OBJECT, DACL_SECURITY_INFORMATION).unwrap();
+    assert!(descriptor.is_dacl_protected().unwrap());
+
+    let dacl = descriptor.dacl().unwrap().unwrap();
+    assert_eq!(dacl.len(), 2);
+    assert_eq!(dacl[0], Ace::allow(user, FILE_ALL_ACCESS.0));
+    assert_eq!(dacl[1].mask, FILE_GENERIC_READ.0);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn kernel_object() {
+    let user = Token::current_process(TOKEN_QUERY).unwrap().user().unwrap();
+
+    let descriptor = SecurityDescriptorBuilder::new()
+        .ace(Ace::allow(user.clone(), EVENT_ALL_ACCESS.0))
+        .build()
+        .unwrap();
+
+    let attributes = descriptor.security_attributes(false);
+
+    unsafe {
+        let event = CreateEventW(Some(&attributes), true, false, None).unwrap();
+
+        let read =
+            SecurityDescriptor::from_handle(event, SE_KERNEL_OBJECT, DACL_SECURITY_INFORMATION)
+                .unwrap();
+        assert_eq!(
+            read.dacl().unwrap().unwrap(),
+            vec![Ace::allow(user.clone(), EVENT_ALL_ACCESS.0)]
+        );
+
+        SecurityDescriptorBuilder::new()
+            .ace(Ace::allow(user.clone(), EVENT_MODIFY_STATE.0))
+            .build()
+            .unwrap()
+            .apply_to_handle(event, SE_KERNEL_OBJECT, DACL_SECURITY_INFORMATION)
+            .unwrap();
+
+        let read =
+            SecurityDescriptor::from_handle(event, SE_KERNEL_OBJECT, DACL_SECURITY_INFORMATION)
+                .unwrap();
+        assert_eq!(
+            read.dacl().unwrap().unwrap(),
+            vec![Ace::allow(user, EVENT_MODIFY_STATE.0)]
+        );
+
+        CloseHandle(event).unwrap();
+    }
+}
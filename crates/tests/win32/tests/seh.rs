@@ -0,0 +1,29 @@
+use windows::Win32::Foundation::*;
+use windows::Win32::System::Diagnostics::Debug::*;
+
+#[test]
+fn guard() {
+    assert_eq!(seh::guard(|| 123).unwrap(), 123);
+
+    let local = String::from("borrowed");
+    assert_eq!(seh::guard(|| local.len()).unwrap(), 8);
+
+    let error = seh::guard(|| unsafe {
+        RaiseException(STATUS_ACCESS_VIOLATION.0 as u32, 0, None);
+    })
+    .unwrap_err();
+
+    assert_eq!(error.code(), STATUS_ACCESS_VIOLATION.to_hresult());
+    assert!(error
+        .message()
+        .starts_with("Exception 0xC0000005 at address 0x"));
+
+    // The process survives to guard again.
+    assert_eq!(seh::guard(|| 456).unwrap(), 456);
+}
+
+#[test]
+#[should_panic(expected = "guarded panic")]
+fn guard_panic() {
+    _ = seh::guard(|| panic!("guarded panic"));
+}
@@ -0,0 +1,42 @@
+use windows::{core::*, Win32::System::RemoteDesktop::*};
+
+#[test]
+fn current() -> Result<()> {
+    let current = Session::current()?;
+    let mut id = 0;
+    unsafe { ProcessIdToSessionId(std::process::id(), &mut id)? };
+    assert_eq!(current.id(), id);
+
+    let sessions = Session::enumerate()?;
+    let info = sessions
+        .iter()
+        .find(|info| info.session == current)
+        .expect("current session");
+
+    assert_eq!(info.station_name, current.station_name()?);
+    assert_eq!(info.state, current.state()?);
+    assert_eq!(
+        current.is_remote()?,
+        current.protocol()? != SessionProtocol::Console
+    );
+    current.user_name()?;
+    current.domain_name()?;
+
+    Ok(())
+}
+
+#[test]
+fn services() -> Result<()> {
+    // Session zero hosts services and is never interactive.
+    let services = Session::from_id(0);
+    assert!(Session::enumerate()?
+        .iter()
+        .any(|info| info.session == services));
+    assert!(services.user_name()?.is_empty());
+
+    if let Some(console) = Session::console() {
+        assert_ne!(console, services);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,242 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use windows::Win32::Foundation::*;
+use windows::Win32::System::Time::*;
+
+const NEW_YEAR: u64 = 133_485_408_005_000_000;
+
+#[test]
+fn ticks() {
+    let time = FILETIME::from_ticks(NEW_YEAR);
+    assert_eq!(time.ticks(), NEW_YEAR);
+    assert_eq!(time.dwLowDateTime, NEW_YEAR as u32);
+    assert_eq!(time.dwHighDateTime, (NEW_YEAR >> 32) as u32);
+    assert!(FILETIME::from_ticks(1) > FILETIME::from_ticks(0));
+}
+
+#[test]
+fn duration() -> windows::core::Result<()> {
+    assert_eq!(
+        Duration::from(FILETIME::from_ticks(12_345_678)),
+        Duration::from_nanos(1_234_567_800)
+    );
+    assert_eq!(
+        FILETIME::try_from(Duration::from_nanos(1_234_567_899))?.ticks(),
+        12_345_678
+    );
+    assert_eq!(
+        FILETIME::try_from(Duration::MAX).unwrap_err().code(),
+        E_INVALIDARG
+    );
+    Ok(())
+}
+
+#[test]
+fn system_time() -> windows::core::Result<()> {
+    let time = SystemTime::from(FILETIME::from_ticks(NEW_YEAR));
+    assert_eq!(
+        time.duration_since(UNIX_EPOCH).unwrap(),
+        Duration::from_millis(1_704_067_200_500)
+    );
+    assert_eq!(FILETIME::try_from(time)?.ticks(), NEW_YEAR);
+
+    assert_eq!(
+        FILETIME::try_from(UNIX_EPOCH)?.ticks(),
+        116_444_736_000_000_000
+    );
+    assert_eq!(
+        SystemTime::from(FILETIME::from_ticks(0)),
+        UNIX_EPOCH - Duration::from_secs(11_644_473_600)
+    );
+
+    // Times before the Unix epoch round down to the previous tick, just as times after it do.
+    let before = UNIX_EPOCH - Duration::from_nanos(150);
+    assert_eq!(
+        FILETIME::try_from(before)?.ticks(),
+        116_444_736_000_000_000 - 2
+    );
+    assert_eq!(
+        FILETIME::try_from(UNIX_EPOCH - Duration::from_secs(11_644_473_601))
+            .unwrap_err()
+            .code(),
+        E_INVALIDARG
+    );
+
+    let now = SystemTime::now();
+    let round_trip = SystemTime::from(FILETIME::try_from(now)?);
+    assert!(now.duration_since(round_trip).unwrap() < Duration::from_nanos(100));
+    Ok(())
+}
+
+#[test]
+fn system_time_fields() -> windows::core::Result<()> {
+    let fields = SYSTEMTIME {
+        wYear: 2024,
+        wMonth: 1,
+        wDayOfWeek: 1,
+        wDay: 1,
+        wHour: 0,
+        wMinute: 0,
+        wSecond: 0,
+        wMilliseconds: 500,
+    };
+    assert_eq!(FILETIME::try_from(fields)?.ticks(), NEW_YEAR);
+    assert_eq!(
+        SYSTEMTIME::try_from(FILETIME::from_ticks(NEW_YEAR))?,
+        fields
+    );
+    assert_eq!(
+        SystemTime::try_from(fields)?
+            .duration_since(UNIX_EPOCH)
+            .unwrap(),
+        Duration::from_millis(1_704_067_200_500)
+    );
+    assert_eq!(SYSTEMTIME::try_from(SystemTime::try_from(fields)?)?, fields);
+
+    let leap = SYSTEMTIME {
+        wYear: 2000,
+        wMonth: 2,
+        wDay: 29,
+        ..Default::default()
+    };
+    assert!(FILETIME::try_from(leap).is_ok());
+    assert!(FILETIME::try_from(SYSTEMTIME {
+        wYear: 1900,
+        ..leap
+    })
+    .is_err());
+    assert!(FILETIME::try_from(SYSTEMTIME {
+        wYear: 1600,
+        wMonth: 12,
+        wDay: 31,
+        ..Default::default()
+    })
+    .is_err());
+    assert!(SYSTEMTIME::try_from(FILETIME::from_ticks(u64::MAX)).is_err());
+    Ok(())
+}
+
+#[test]
+fn matches_kernel32() -> windows::core::Result<()> {
+    for ticks in [
+        0,
+        1,
+        NEW_YEAR,
+        125_911_584_000_000_000,
+        159_000_000_123_456_789,
+        i64::MAX as u64,
+    ] {
+        let file_time = FILETIME::from_ticks(ticks);
+        let mut expected = SYSTEMTIME::default();
+        unsafe { FileTimeToSystemTime(&file_time, &mut expected)? };
+        let actual = SYSTEMTIME::try_from(file_time)?;
+        assert_eq!(actual, expected);
+
+        let mut expected = FILETIME::default();
+        unsafe { SystemTimeToFileTime(&actual, &mut expected)? };
+        assert_eq!(FILETIME::try_from(actual)?, expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn chrono() -> windows::core::Result<()> {
+    use chrono::{FixedOffset, TimeZone, Utc};
+
+    let expected =
+        Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + chrono::TimeDelta::milliseconds(500);
+    let time = FILETIME::from_ticks(NEW_YEAR);
+    assert_eq!(chrono::DateTime::<Utc>::from(time), expected);
+    assert_eq!(FILETIME::try_from(expected)?, time);
+
+    let offset = expected.with_timezone(&FixedOffset::east_opt(3600).unwrap());
+    assert_eq!(FILETIME::try_from(offset)?, time);
+
+    let fields = SYSTEMTIME::try_from(expected)?;
+    assert_eq!(
+        (
+            fields.wYear,
+            fields.wMonth,
+            fields.wDay,
+            fields.wMilliseconds
+        ),
+        (2024, 1, 1, 500)
+    );
+    assert_eq!(chrono::DateTime::<Utc>::try_from(fields)?, expected);
+
+    let early = Utc.with_ymd_and_hms(1600, 12, 31, 23, 59, 59).unwrap();
+    assert_eq!(FILETIME::try_from(early).unwrap_err().code(), E_INVALIDARG);
+    Ok(())
+}
+
+#[test]
+fn time() -> windows::core::Result<()> {
+    use time::macros::datetime;
+
+    let expected = datetime!(2024-01-01 00:00:00.5 UTC);
+    let time = FILETIME::from_ticks(NEW_YEAR);
+    assert_eq!(time::OffsetDateTime::try_from(time)?, expected);
+    assert_eq!(FILETIME::try_from(expected)?, time);
+    assert_eq!(
+        FILETIME::try_from(datetime!(2024-01-01 01:00:00.5 +1))?,
+        time
+    );
+
+    let fields = SYSTEMTIME::try_from(expected)?;
+    assert_eq!(time::OffsetDateTime::try_from(fields)?, expected);
+
+    // Years beyond 9999 need the `large-dates` feature of the `time` crate.
+    assert!(time::OffsetDateTime::try_from(FILETIME::from_ticks(u64::MAX)).is_err());
+    Ok(())
+}
+
+#[test]
+fn date_time() -> windows::core::Result<()> {
+    use windows::Foundation::{DateTime, TimeSpan};
+
+    let date = DateTime {
+        UniversalTime: NEW_YEAR as i64,
+    };
+    assert_eq!(DateTime::try_from(FILETIME::from_ticks(NEW_YEAR))?, date);
+    assert_eq!(FILETIME::try_from(date)?.ticks(), NEW_YEAR);
+    assert!(FILETIME::try_from(DateTime { UniversalTime: -1 }).is_err());
+
+    let system = SystemTime::try_from(date)?;
+    assert_eq!(
+        system.duration_since(UNIX_EPOCH).unwrap(),
+        Duration::from_millis(1_704_067_200_500)
+    );
+    assert_eq!(DateTime::try_from(system)?, date);
+
+    // Unlike `FILETIME`, a `DateTime` may be before 1601.
+    let early = DateTime { UniversalTime: -15 };
+    assert_eq!(DateTime::try_from(SystemTime::try_from(early)?)?, early);
+    assert_eq!(
+        DateTime::try_from(chrono::DateTime::<chrono::Utc>::from(early))?,
+        early
+    );
+
+    assert_eq!(
+        time::OffsetDateTime::try_from(date)?,
+        time::macros::datetime!(2024-01-01 00:00:00.5 UTC)
+    );
+    assert_eq!(
+        DateTime::try_from(time::macros::datetime!(2024-01-01 00:00:00.5 UTC))?,
+        date
+    );
+
+    let span = TimeSpan {
+        Duration: -12_345_678,
+    };
+    assert_eq!(
+        chrono::TimeDelta::from(span),
+        chrono::TimeDelta::nanoseconds(-1_234_567_800)
+    );
+    assert_eq!(TimeSpan::try_from(chrono::TimeDelta::from(span))?, span);
+    assert_eq!(
+        time::Duration::from(span),
+        time::Duration::nanoseconds(-1_234_567_800)
+    );
+    assert_eq!(TimeSpan::try_from(time::Duration::from(span))?, span);
+    assert!(TimeSpan::try_from(time::Duration::MAX).is_err());
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use windows::{Win32::Foundation::*, Win32::Security::*};
+
+#[test]
+fn query() {
+    let token = Token::current_process(TOKEN_QUERY).unwrap();
+
+    let user = token.user().unwrap();
+    assert!(user.to_string().starts_with("S-1-5-"));
+    assert_eq!(user.authority(), 5);
+    assert_eq!(format!("{user:?}"), format!("Sid({user})"));
+    assert_eq!(user, token.user().unwrap());
+    assert_eq!(unsafe { Sid::from_psid(user.as_psid()) }.unwrap(), user);
+
+    // Processes run at medium integrity or above unless they are sandboxed.
+    assert!(token.integrity_level().unwrap() >= 0x2000);
+
+    let elevated = token.is_elevated().unwrap();
+    let elevation_type = token.elevation_type().unwrap();
+    assert!(!elevated || elevation_type != TokenElevationTypeLimited);
+}
+
+#[test]
+fn privileges() {
+    // Adjusts a copy of the process token so that other tests are unaffected.
+    let process = Token::current_process(TOKEN_DUPLICATE).unwrap();
+    let mut handle = HANDLE::default();
+    unsafe {
+        DuplicateTokenEx(
+            process.handle(),
+            TOKEN_QUERY | TOKEN_ADJUST_PRIVILEGES,
+            None,
+            SecurityImpersonation,
+            TokenPrimary,
+            &mut handle,
+        )
+        .unwrap()
+    };
+    let token = Token::from_handle(unsafe { windows::core::Owned::new(handle) });
+
+    // Every token holds the change notify privilege, which is enabled by default.
+    assert!(token.privilege_enabled(SE_CHANGE_NOTIFY_NAME).unwrap());
+    token.disable_privilege(SE_CHANGE_NOTIFY_NAME).unwrap();
+    assert!(!token.privilege_enabled(SE_CHANGE_NOTIFY_NAME).unwrap());
+    token.enable_privilege(SE_CHANGE_NOTIFY_NAME).unwrap();
+    assert!(token.privilege_enabled(SE_CHANGE_NOTIFY_NAME).unwrap());
+
+    // Only the operating system holds the trusted computing base privilege.
+    let error = token.enable_privilege(SE_TCB_NAME).unwrap_err();
+    assert_eq!(error.code(), ERROR_NOT_ALL_ASSIGNED.to_hresult());
+
+    let error = token
+        .enable_privilege(windows::core::w!("NotAPrivilege"))
+        .unwrap_err();
+    assert_eq!(error.code(), ERROR_NO_SUCH_PRIVILEGE.to_hresult());
+}
+
+#[test]
+fn impersonate() {
+    assert!(Token::current_thread(TOKEN_QUERY).unwrap().is_none());
+
+    let token = Token::current_process(TOKEN_QUERY | TOKEN_DUPLICATE).unwrap();
+    let guard = token.impersonate().unwrap();
+
+    let thread = Token::current_thread(TOKEN_QUERY).unwrap().unwrap();
+    assert_eq!(thread.user().unwrap(), token.user().unwrap());
+
+    drop(guard);
+    assert!(Token::current_thread(TOKEN_QUERY).unwrap().is_none());
+}
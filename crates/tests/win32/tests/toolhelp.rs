@@ -0,0 +1,67 @@
+use windows::Win32::System::Diagnostics::ToolHelp::*;
+use windows::Win32::System::Threading::*;
+
+#[test]
+fn processes() -> windows::core::Result<()> {
+    let id = unsafe { GetCurrentProcessId() };
+    let snapshot = Snapshot::new(TH32CS_SNAPPROCESS | TH32CS_SNAPTHREAD, 0)?;
+
+    let process = snapshot
+        .processes()
+        .find(|process| process.id == id)
+        .unwrap();
+    assert!(process.name.to_lowercase().starts_with("toolhelp"));
+    assert!(process.threads >= 1);
+
+    let thread_id = unsafe { GetCurrentThreadId() };
+    let thread = snapshot
+        .threads()
+        .find(|thread| thread.id == thread_id)
+        .unwrap();
+    assert_eq!(thread.process_id, id);
+
+    // Each iterator walks the snapshot from the beginning.
+    assert_eq!(snapshot.processes().count(), snapshot.processes().count());
+    Ok(())
+}
+
+#[test]
+fn modules() -> windows::core::Result<()> {
+    let snapshot = Snapshot::new(TH32CS_SNAPMODULE | TH32CS_SNAPHEAPLIST, 0)?;
+
+    let kernel32 = snapshot
+        .modules()
+        .find(|module| module.name.eq_ignore_ascii_case("kernel32.dll"))
+        .unwrap();
+    assert!(kernel32.path.is_absolute());
+    assert!(kernel32.size > 0);
+    assert_ne!(kernel32.base_address, 0);
+
+    let heap = snapshot.heaps().find(|heap| heap.default).unwrap();
+    assert!(heap.blocks().take(10).all(|block| block.address != 0));
+    Ok(())
+}
+
+#[test]
+fn info() -> windows::core::Result<()> {
+    let info = ProcessInfo::current()?;
+
+    let path = info.image_name()?;
+    assert!(path.is_absolute());
+    assert!(path
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .starts_with("toolhelp"));
+
+    let times = info.times()?;
+    assert!(times.creation <= std::time::SystemTime::now());
+    assert_eq!(times.exit, None);
+
+    let memory = info.memory()?;
+    assert!(memory.working_set > 0);
+    assert!(memory.peak_working_set >= memory.working_set);
+
+    assert!(ProcessInfo::open(u32::MAX - 2).is_err());
+    Ok(())
+}
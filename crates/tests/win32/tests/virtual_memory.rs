@@ -0,0 +1,72 @@
+use windows::{core::*, Win32::Foundation::*, Win32::System::Memory::*};
+
+#[test]
+fn reserve_commit() -> Result<()> {
+    let mut region = VirtualRegion::reserve(1 << 20)?;
+    assert_eq!(region.len(), 1 << 20);
+    assert_eq!(region.query(0)?.State, MEM_RESERVE);
+
+    region.commit(0, 4096, PAGE_READWRITE)?;
+    let info = region.query(0)?;
+    assert_eq!(info.State, MEM_COMMIT);
+    assert_eq!(info.Protect, PAGE_READWRITE);
+    assert_eq!(info.RegionSize, 4096);
+    assert_eq!(region.query(4096)?.State, MEM_RESERVE);
+
+    unsafe {
+        assert!(region.as_mut_slice()[..4096].iter().all(|byte| *byte == 0));
+        region.as_mut_slice()[..4].copy_from_slice(b"test");
+        assert_eq!(&region.as_slice()[..4], b"test");
+
+        region.decommit(0, 4096)?;
+    }
+
+    assert_eq!(region.query(0)?.State, MEM_RESERVE);
+
+    let error = region.commit(1 << 20, 1, PAGE_READWRITE).unwrap_err();
+    assert_eq!(error.code(), E_INVALIDARG);
+
+    Ok(())
+}
+
+#[test]
+fn protect() -> Result<()> {
+    let region = VirtualRegion::new(8192, PAGE_READWRITE)?;
+    assert_eq!(region.query(0)?.Protect, PAGE_READWRITE);
+
+    {
+        let guard = region.protect_scoped(0, 4096, PAGE_READONLY)?;
+        assert_eq!(guard.previous(), PAGE_READWRITE);
+        assert_eq!(region.query(0)?.Protect, PAGE_READONLY);
+        assert_eq!(region.query(4096)?.Protect, PAGE_READWRITE);
+    }
+
+    assert_eq!(region.query(0)?.Protect, PAGE_READWRITE);
+
+    assert_eq!(region.protect(0, 8192, PAGE_EXECUTE_READ)?, PAGE_READWRITE);
+    assert_eq!(region.query(0)?.Protect, PAGE_EXECUTE_READ);
+    assert_eq!(region.query(0)?.RegionSize, 8192);
+
+    Ok(())
+}
+
+#[test]
+fn large_pages() {
+    let minimum = unsafe { GetLargePageMinimum() };
+    assert_eq!(large_page_size(), minimum);
+
+    if minimum == 0 {
+        let error = VirtualRegion::large_pages(1, PAGE_READWRITE).unwrap_err();
+        assert_eq!(error.code(), ERROR_NOT_SUPPORTED.to_hresult());
+        return;
+    }
+
+    // Large pages are only allocated if the privilege is held, which isn't the case for most test environments.
+    match VirtualRegion::large_pages(1, PAGE_READWRITE) {
+        Ok(region) => {
+            assert_eq!(region.len(), minimum);
+            assert_eq!(region.as_ptr() as usize % minimum, 0);
+        }
+        Err(error) => assert_eq!(error.code(), ERROR_PRIVILEGE_NOT_HELD.to_hresult()),
+    }
+}
@@ -0,0 +1,98 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use windows::{core::*, Win32::Foundation::*, Win32::UI::WindowsAndMessaging::*};
+
+#[test]
+fn handler() -> Result<()> {
+    let messages = Rc::new(Cell::new(0));
+    let created = Rc::new(Cell::new(false));
+
+    let window = {
+        let messages = messages.clone();
+        let created = created.clone();
+
+        WindowBuilder::new("windows-rs test handler")
+            .message_only()
+            .create(move |_, message, wparam, _| {
+                messages.set(messages.get() + 1);
+
+                match message {
+                    WM_CREATE => created.set(true),
+                    WM_USER => return Some(LRESULT(wparam.0 as isize * 2)),
+                    _ => {}
+                }
+
+                None
+            })?
+    };
+
+    assert!(created.get());
+    assert!(window.is_alive());
+    assert_eq!(
+        unsafe { SendMessageW(window.handle(), WM_USER, WPARAM(21), LPARAM(0)) },
+        LRESULT(42)
+    );
+
+    // Messages the handler doesn't handle go to DefWindowProcW.
+    assert_eq!(
+        unsafe { SendMessageW(window.handle(), WM_USER + 1, WPARAM(21), LPARAM(0)) },
+        LRESULT(0)
+    );
+
+    // The handler is dropped along with the window.
+    let count = messages.get();
+    drop(window);
+    assert!(messages.get() > count);
+    assert_eq!(Rc::strong_count(&messages), 1);
+    Ok(())
+}
+
+#[test]
+fn destroyed() -> Result<()> {
+    let window = WindowBuilder::new("windows-rs test destroyed")
+        .message_only()
+        .create(|_, _, _, _| None)?;
+
+    unsafe { DestroyWindow(window.handle())? };
+    assert!(!window.is_alive());
+    Ok(())
+}
+
+#[test]
+fn message_loop() -> Result<()> {
+    let window = WindowBuilder::new("windows-rs test message loop")
+        .message_only()
+        .create(|_, message, wparam, _| {
+            if message == WM_USER {
+                unsafe { PostQuitMessage(wparam.0 as i32) };
+                return Some(LRESULT(0));
+            }
+
+            None
+        })?;
+
+    unsafe { PostMessageW(window.handle(), WM_USER, WPARAM(7), LPARAM(0))? };
+    assert_eq!(MessageLoop::new().run()?, 7);
+    Ok(())
+}
+
+#[test]
+fn idle() -> Result<()> {
+    let mut calls = 0;
+
+    let exit_code = MessageLoop::new()
+        .on_idle(|| {
+            calls += 1;
+
+            if calls == 3 {
+                unsafe { PostQuitMessage(3) };
+            }
+
+            true
+        })
+        .run()?;
+
+    assert_eq!(exit_code, 3);
+    assert_eq!(calls, 3);
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use windows::{core::*, Win32::Foundation::*, Win32::UI::WindowsAndMessaging::*};
+
+#[test]
+fn methods() -> Result<()> {
+    let window = WindowBuilder::new("windows-rs test window_ext")
+        .size(200, 100)
+        .create(|_, message, wparam, _| match message {
+            WM_USER => Some(LRESULT(wparam.0 as isize + 1)),
+            _ => None,
+        })?;
+
+    let hwnd = window.handle();
+    assert!(hwnd.is_window());
+    assert_eq!(hwnd.get_class_name()?, "windows-rs test window_ext");
+    assert_eq!(
+        unsafe { hwnd.send_message(WM_USER, WPARAM(41), LPARAM(0)) },
+        LRESULT(42)
+    );
+    unsafe { hwnd.post_message(WM_USER, WPARAM(0), LPARAM(0))? };
+
+    let rect = hwnd.client_rect()?;
+    assert_eq!((rect.left, rect.top), (0, 0));
+    assert!(rect.right <= 200 && rect.bottom <= 100);
+    assert_ne!(hwnd.dpi(), 0);
+
+    drop(window);
+    assert!(!hwnd.is_window());
+    assert_eq!(hwnd.dpi(), 0);
+    assert!(hwnd.get_class_name().is_err());
+    assert!(hwnd.client_rect().is_err());
+    assert!(unsafe { hwnd.post_message(WM_USER, WPARAM(0), LPARAM(0)) }.is_err());
+
+    Ok(())
+}
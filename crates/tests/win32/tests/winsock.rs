@@ -62,6 +62,9 @@ fn sockaddr_in() {
         unsafe { sockaddr_in.sin_addr.S_un.S_addr },
         u32::from(IPV4_ADDR).to_be()
     );
+
+    // Compare the round-trip
+    assert_eq!(std::net::SocketAddrV4::from(sockaddr_in), socket_addr_v4);
 }
 
 #[test]
@@ -87,6 +90,9 @@ fn sockaddr_in6() {
 
     // This bitfield has no endianness
     assert_eq!(unsafe { sockaddr_in6.Anonymous.sin6_scope_id }, SCOPE_ID);
+
+    // Compare the round-trip
+    assert_eq!(std::net::SocketAddrV6::from(sockaddr_in6), socket_addr_v6);
 }
 
 #[test]
@@ -107,6 +113,12 @@ fn sockaddr_inet4() {
         unsafe { sockaddr_inet.Ipv4.sin_addr.S_un.S_addr },
         u32::from(IPV4_ADDR).to_be()
     );
+
+    // Compare the round-trip
+    assert_eq!(
+        std::net::SocketAddr::try_from(sockaddr_inet).unwrap(),
+        socket_addr_v4.into()
+    );
 }
 
 #[test]
@@ -142,4 +154,64 @@ fn sockaddr_inet6() {
         unsafe { sockaddr_inet.Ipv6.Anonymous.sin6_scope_id },
         SCOPE_ID
     );
+    // Compare the round-trip
+    assert_eq!(
+        std::net::SocketAddr::try_from(sockaddr_inet).unwrap(),
+        socket_addr_v6.into()
+    );
+
+    // An unspecified family can't be converted
+    let error = std::net::SocketAddr::try_from(SOCKADDR_INET::default()).unwrap_err();
+    assert_eq!(
+        error.code(),
+        windows::core::HRESULT::from_win32(WSAEAFNOSUPPORT.0 as u32)
+    );
+}
+
+#[test]
+fn socket() {
+    use std::io::{Read, Write};
+    use std::os::windows::io::*;
+    use windows::core::{Borrowed, Owned};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    // Apply a Winsock option to a std socket through a borrowed socket.
+    let client = std::net::TcpStream::connect(addr).unwrap();
+    let borrowed = Borrowed::<SOCKET>::from(client.as_socket());
+    assert_eq!(borrowed.0 as u64, client.as_raw_socket());
+    let enable: u32 = 1;
+    let result = unsafe {
+        setsockopt(
+            *borrowed,
+            IPPROTO_TCP.0,
+            TCP_NODELAY,
+            Some(&enable.to_ne_bytes()),
+        )
+    };
+    assert_eq!(result, 0);
+    assert!(client.nodelay().unwrap());
+
+    // Move the accepted socket into an owned windows-rs socket and back.
+    let (server, _) = listener.accept().unwrap();
+    let owned = Owned::<SOCKET>::from(OwnedSocket::from(server));
+    assert_eq!(owned.as_socket().as_raw_socket(), owned.0 as u64);
+    let mut server = std::net::TcpStream::from(OwnedSocket::from(owned));
+
+    let info = WSAPROTOCOL_INFOW::from_socket(&server).unwrap();
+    assert_eq!(info.iAddressFamily, AF_INET.0 as i32);
+    assert_eq!(info.iSocketType, SOCK_STREAM.0);
+
+    // Duplicate the client socket as another process would.
+    let info = WSAPROTOCOL_INFOW::duplicate(&client, std::process::id()).unwrap();
+    let mut duplicate = std::net::TcpStream::try_from(&info).unwrap();
+    drop(client);
+
+    duplicate.write_all(b"hello").unwrap();
+    drop(duplicate);
+
+    let mut text = String::new();
+    server.read_to_string(&mut text).unwrap();
+    assert_eq!(text, "hello");
 }
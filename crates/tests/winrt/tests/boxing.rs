@@ -0,0 +1,56 @@
+use windows::{
+    core::*, Foundation::Numerics::*, Foundation::*, Win32::Foundation::TYPE_E_TYPEMISMATCH,
+};
+
+#[test]
+fn reference() -> Result<()> {
+    let vector = Vector2 { X: 1.0, Y: 2.0 };
+    let reference = IReference::from(vector);
+    assert_eq!(reference.Value()?, vector);
+
+    let boxed: IInspectable = reference.cast()?;
+    assert_eq!(boxed.cast::<IReference<Vector2>>()?.Value()?, vector);
+
+    let property = boxed.cast::<IPropertyValue>()?;
+    assert_eq!(property.Type()?, PropertyType::OtherType);
+    assert!(!property.IsNumericScalar()?);
+    assert_eq!(
+        property.GetUInt32().unwrap_err().code(),
+        TYPE_E_TYPEMISMATCH
+    );
+
+    assert!(boxed.cast::<IReference<Vector3>>().is_err());
+    Ok(())
+}
+
+#[test]
+fn reference_scalar() -> Result<()> {
+    let property = IReference::from(5i32).cast::<IPropertyValue>()?;
+    assert_eq!(property.Type()?, PropertyType::Int32);
+    assert!(property.IsNumericScalar()?);
+    assert_eq!(property.GetInt32()?, 5);
+    assert_eq!(
+        property.GetUInt32().unwrap_err().code(),
+        TYPE_E_TYPEMISMATCH
+    );
+
+    let property = IReference::from(HSTRING::from("hello")).cast::<IPropertyValue>()?;
+    assert_eq!(property.Type()?, PropertyType::String);
+    assert!(!property.IsNumericScalar()?);
+    assert_eq!(property.GetString()?, "hello");
+    Ok(())
+}
+
+#[test]
+fn arrays() -> Result<()> {
+    let boxed = vec![1u8, 2, 3].box_value()?;
+    assert_eq!(Vec::<u8>::unbox_value(&boxed)?, [1, 2, 3]);
+    assert!(Vec::<i32>::unbox_value(&boxed).is_err());
+
+    let strings = vec![HSTRING::from("a"), HSTRING::from("b")];
+    assert_eq!(Vec::<HSTRING>::unbox_value(&strings.box_value()?)?, strings);
+
+    let points = vec![Point { X: 1.0, Y: 2.0 }];
+    assert_eq!(Vec::<Point>::unbox_value(&points.box_value()?)?, points);
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use windows::{core::*, Storage::Streams::*, Win32::Foundation::E_INVALIDARG};
+
+#[test]
+fn from_slice() -> Result<()> {
+    let buffer = IBuffer::from_slice(&[1, 2, 3])?;
+    assert_eq!(buffer.Capacity()?, 3);
+    assert_eq!(buffer.Length()?, 3);
+    assert_eq!(unsafe { buffer.as_slice()? }, [1, 2, 3]);
+    assert_eq!(buffer.to_vec()?, [1, 2, 3]);
+
+    buffer.SetLength(1)?;
+    assert_eq!(unsafe { buffer.as_slice()? }, [1]);
+
+    let buffer = IBuffer::from_slice(&[])?;
+    assert_eq!(buffer.Length()?, 0);
+    assert!(unsafe { buffer.as_slice()?.is_empty() });
+    Ok(())
+}
+
+#[test]
+fn stock() -> Result<()> {
+    let buffer = IBuffer::try_from(vec![4, 5, 6])?;
+    assert_eq!(buffer.Capacity()?, 3);
+    assert_eq!(unsafe { buffer.as_slice()? }, [4, 5, 6]);
+
+    buffer.SetLength(2)?;
+    assert_eq!(buffer.to_vec()?, [4, 5]);
+    assert_eq!(buffer.SetLength(4).unwrap_err().code(), E_INVALIDARG);
+
+    // Stock buffers work with WinRT APIs that read from buffers.
+    let reader = DataReader::FromBuffer(&buffer)?;
+    assert_eq!(reader.UnconsumedBufferLength()?, 2);
+    assert_eq!(reader.ReadByte()?, 4);
+    assert_eq!(reader.ReadByte()?, 5);
+    Ok(())
+}
+
+#[test]
+fn stream() -> Result<()> {
+    let writer = DataWriter::new()?;
+    writer.WriteBytes(&[7, 8, 9])?;
+    let buffer = writer.DetachBuffer()?;
+    assert_eq!(unsafe { buffer.as_slice()? }, [7, 8, 9]);
+    Ok(())
+}
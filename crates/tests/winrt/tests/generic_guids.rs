@@ -252,3 +252,25 @@ fn generic_class_guid() {
         GUID::try_from("E170688F-3495-5BF6-AAB5-9CAC17E0F10F").unwrap()
     );
 }
+
+#[test]
+fn const_generic_guids() {
+    const VECTOR: GUID = IVector::<HSTRING>::IID;
+    const MAP: GUID = IMap::<HSTRING, IInspectable>::IID;
+    const HANDLER: GUID = TypedEventHandler::<IStringable, IInspectable>::IID;
+
+    assert_eq!(
+        VECTOR,
+        GUID::try_from("98B9ACC1-4B56-532E-AC73-03D5291CCA90").unwrap()
+    );
+
+    assert_eq!(
+        MAP,
+        GUID::try_from("1B0D3570-0877-5EC2-8A2C-3B9539506ACA").unwrap()
+    );
+
+    assert_eq!(
+        HANDLER,
+        GUID::try_from("BAF26C49-D415-50B0-A5A7-3984972B61C2").unwrap()
+    );
+}
@@ -0,0 +1,69 @@
+use windows::{core::*, Win32::System::Registry::*, UI::Notifications::*};
+
+#[test]
+fn xml() {
+    assert_eq!(
+        Toast::new().xml(),
+        r#"<toast><visual><binding template="ToastGeneric"></binding></visual></toast>"#
+    );
+
+    let toast = Toast::new()
+        .launch("action=open&id=1")
+        .title("Backup <complete>")
+        .body("12 files")
+        .body("were \"copied\"")
+        .logo("file:///C:/logo.png")
+        .image("https://example.com/image.png")
+        .input("reply", "Type a reply")
+        .button("Open", "open")
+        .button("Dismiss", "dismiss")
+        .silent();
+
+    assert_eq!(
+        toast.xml(),
+        concat!(
+            r#"<toast launch="action=open&amp;id=1"><visual><binding template="ToastGeneric">"#,
+            r#"<text>Backup &lt;complete&gt;</text><text>12 files</text><text>were &quot;copied&quot;</text>"#,
+            r#"<image placement="appLogoOverride" src="file:///C:/logo.png"/>"#,
+            r#"<image src="https://example.com/image.png"/></binding></visual>"#,
+            r#"<actions><input id="reply" type="text" placeHolderContent="Type a reply"/>"#,
+            r#"<action content="Open" arguments="open" activationType="foreground"/>"#,
+            r#"<action content="Dismiss" arguments="dismiss" activationType="foreground"/></actions>"#,
+            r#"<audio silent="true"/></toast>"#
+        )
+    );
+
+    assert_eq!(format!("{toast:?}"), format!("Toast({:?})", toast.xml()));
+}
+
+#[test]
+fn notification() -> Result<()> {
+    let notification = Toast::new()
+        .title("title")
+        .body("body")
+        .on_activated(|_| {})
+        .notification()?;
+
+    let content = notification.Content()?.GetXml()?;
+    assert!(content.to_string().contains("<text>title</text>"));
+    Ok(())
+}
+
+#[test]
+fn register() -> Result<()> {
+    let app_id = "Windows.Test.Toast";
+    register_app_id(app_id, "Test", Some("file:///C:/icon.png"))?;
+
+    let mut key = HKEY::default();
+    let path = h!(r"Software\Classes\AppUserModelId\Windows.Test.Toast");
+    unsafe { RegOpenKeyExW(HKEY_CURRENT_USER, path, 0, KEY_READ, &mut key).ok()? };
+    unsafe { RegCloseKey(key).ok()? };
+
+    // Registering again without an icon removes the previous icon.
+    register_app_id(app_id, "Test", None)?;
+    unregister_app_id(app_id)?;
+
+    let error = unsafe { RegOpenKeyExW(HKEY_CURRENT_USER, path, 0, KEY_READ, &mut key) };
+    assert!(error.is_err());
+    Ok(())
+}
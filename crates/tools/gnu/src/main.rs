@@ -11,13 +11,26 @@ fn main() {
         "i686_gnullvm",
     ];
     let mut platforms = BTreeSet::new();
-    for platform in std::env::args().skip(1) {
-        if ALL_PLATFORMS.contains(&&*platform) {
-            platforms.insert(platform);
-        } else if platform == "all" {
+    let mut imports = None;
+    let mut out = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--imports" || arg == "--out" {
+            let Some(value) = args.next() else {
+                eprintln!("Missing value for {arg}");
+                return;
+            };
+            if arg == "--imports" {
+                imports = Some(value);
+            } else {
+                out = Some(std::path::PathBuf::from(value));
+            }
+        } else if ALL_PLATFORMS.contains(&&*arg) {
+            platforms.insert(arg);
+        } else if arg == "all" {
             platforms.extend(ALL_PLATFORMS.iter().map(|s| s.to_string()));
         } else {
-            eprintln!("Unknown platform: {platform}");
+            eprintln!("Unknown platform: {arg}");
             return;
         }
     }
@@ -25,6 +38,16 @@ fn main() {
         eprintln!("Please specify at least one platform or use 'all' argument");
         return;
     };
+    if imports.is_some() != out.is_some() {
+        eprintln!("Please specify both --imports and --out to build trimmed libs");
+        return;
+    }
+
+    let mut libraries = lib::libraries();
+
+    if let Some(imports) = &imports {
+        libraries = lib::select_libraries(libraries, &std::fs::read_to_string(imports).unwrap());
+    }
 
     for platform in platforms {
         let tools = if platform.ends_with("_gnu") {
@@ -44,23 +67,32 @@ fn main() {
             }
         }
 
-        build_platform(&platform, tools[0], tools[1]);
+        let output = if let Some(out) = &out {
+            out.join(&platform)
+        } else {
+            std::path::PathBuf::from(format!("crates/targets/{platform}/lib"))
+        };
+
+        build_platform(&platform, tools[0], tools[1], &libraries, &output);
     }
 }
 
-fn build_platform(platform: &str, dlltool: &str, ar: &str) {
+fn build_platform(
+    platform: &str,
+    dlltool: &str,
+    ar: &str,
+    libraries: &BTreeMap<String, BTreeMap<String, lib::CallingConvention>>,
+    output: &std::path::Path,
+) {
     println!("Platform: {platform}");
 
-    let libraries = lib::libraries();
-    let output = std::path::PathBuf::from(format!("crates/targets/{platform}/lib"));
-    std::fs::create_dir_all(&output).unwrap();
-    std::fs::create_dir_all(&output).unwrap();
+    std::fs::create_dir_all(output).unwrap();
 
-    for (library, functions) in &libraries {
-        build_library(&output, dlltool, library, functions, platform);
+    for (library, functions) in libraries {
+        build_library(output, dlltool, library, functions, platform);
     }
 
-    build_mri(&output, ar, &libraries);
+    build_mri(output, ar, libraries);
 
     for library in libraries.keys() {
         std::fs::remove_file(output.join(format!("lib{library}.a"))).unwrap();
@@ -34,6 +34,66 @@ pub fn libraries() -> BTreeMap<String, BTreeMap<String, CallingConvention>> {
     libraries
 }
 
+/// Returns only those libraries and functions named by `imports`, so that trimmed import libs can be built for
+/// projects that only link against a handful of APIs.
+///
+/// Each non-empty line of `imports` names a library followed by the function to import from it, for example
+/// `kernel32.dll GetLastError`. A line naming only a library selects all of its functions. Text following `#`
+/// is ignored.
+pub fn select_libraries(
+    mut libraries: BTreeMap<String, BTreeMap<String, CallingConvention>>,
+    imports: &str,
+) -> BTreeMap<String, BTreeMap<String, CallingConvention>> {
+    let mut selected = BTreeMap::<String, BTreeMap<String, CallingConvention>>::new();
+
+    for line in imports.lines() {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut words = line.split_whitespace();
+
+        let Some(library) = words.next() else {
+            continue;
+        };
+
+        // Windows libs are always produced with lower case module names.
+        let library = library.to_lowercase();
+
+        let Some(functions) = libraries.get_mut(&library) else {
+            panic!("library `{library}` not found");
+        };
+
+        let mut functions_selected = false;
+
+        for function in words {
+            functions_selected = true;
+
+            if selected
+                .get(&library)
+                .is_some_and(|selected| selected.contains_key(function))
+            {
+                continue;
+            }
+
+            let Some(calling_convention) = functions.remove(function) else {
+                panic!("function `{function}` not found in `{library}`");
+            };
+
+            selected
+                .entry(library.clone())
+                .or_default()
+                .insert(function.to_string(), calling_convention);
+        }
+
+        if !functions_selected {
+            selected
+                .entry(library.clone())
+                .or_default()
+                .append(functions);
+        }
+    }
+
+    selected
+}
+
 fn combine_libraries(
     reader: &metadata::Reader,
     libraries: &mut BTreeMap<String, BTreeMap<String, CallingConvention>>,
@@ -99,3 +159,29 @@ fn find<P: AsRef<Path>>(path: P, regex: &Regex) -> Vec<(String, String)> {
 
     names
 }
+
+#[test]
+fn test_select_libraries() {
+    let imports = r#"
+# Comments and blank lines are ignored.
+
+KERNEL32.dll GetLastError SetLastError
+kernel32.dll GetLastError
+ole32.dll CoIncrementMTAUsage # trailing comment
+"#;
+
+    let selected = select_libraries(libraries(), imports);
+    assert_eq!(selected.len(), 2);
+
+    let kernel32: Vec<_> = selected["kernel32.dll"].keys().collect();
+    assert_eq!(kernel32, ["GetLastError", "SetLastError"]);
+
+    let ole32: Vec<_> = selected["ole32.dll"].keys().collect();
+    assert_eq!(ole32, ["CoIncrementMTAUsage"]);
+
+    let selected = select_libraries(libraries(), "advapi32.dll");
+    assert_eq!(
+        selected["advapi32.dll"].len(),
+        libraries()["advapi32.dll"].len()
+    );
+}
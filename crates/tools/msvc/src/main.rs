@@ -19,7 +19,38 @@ fn main() {
         return;
     };
 
+    let mut args = std::env::args().skip(1);
+    let mut imports = None;
+    let mut out = None;
+    while let Some(arg) = args.next() {
+        let value = args.next();
+        match (arg.as_str(), value) {
+            ("--imports", Some(value)) => imports = Some(value),
+            ("--out", Some(value)) => out = Some(std::path::PathBuf::from(value)),
+            _ => {
+                println!("Usage: tool_msvc [--imports <file> --out <dir>]");
+                return;
+            }
+        }
+    }
+
     let libraries = lib::libraries();
+
+    match (imports, out) {
+        (Some(imports), Some(out)) => {
+            let libraries =
+                lib::select_libraries(libraries, &std::fs::read_to_string(imports).unwrap());
+
+            build_trimmed(&out.join(platform), &libraries);
+            return;
+        }
+        (None, None) => {}
+        _ => {
+            println!("Please specify both --imports and --out to build trimmed libs");
+            return;
+        }
+    }
+
     let output = std::path::PathBuf::from("crates/targets/baseline");
     _ = std::fs::remove_dir_all(&output);
     std::fs::create_dir_all(&output).unwrap();
@@ -34,12 +65,52 @@ changes can sneak in undetected.
     )
     .unwrap();
 
-    for (library, functions) in &libraries {
-        build_library(&output, library, functions);
+    build_umbrella_library(&output, &libraries);
+
+    std::fs::remove_dir_all(format!("crates/targets/{platform}/lib")).unwrap();
+    std::fs::create_dir_all(format!("crates/targets/{platform}/lib")).unwrap();
+    std::fs::rename(
+        output.join("windows.lib"),
+        format!(
+            "crates/targets/{platform}/lib/windows.{}.lib",
+            std::env!("CARGO_PKG_VERSION")
+        ),
+    )
+    .unwrap();
+}
+
+/// Builds an umbrella lib containing only the selected imports, named so that it can stand in for the
+/// lib provided by the corresponding target crate.
+fn build_trimmed(
+    output: &std::path::Path,
+    libraries: &BTreeMap<String, BTreeMap<String, lib::CallingConvention>>,
+) {
+    _ = std::fs::remove_dir_all(output);
+    std::fs::create_dir_all(output).unwrap();
+
+    build_umbrella_library(output, libraries);
+
+    for library in libraries.keys() {
+        std::fs::remove_file(output.join(format!("{library}.c"))).unwrap();
+    }
+
+    std::fs::rename(
+        output.join("windows.lib"),
+        output.join(format!("windows.{}.lib", std::env!("CARGO_PKG_VERSION"))),
+    )
+    .unwrap();
+}
+
+fn build_umbrella_library(
+    output: &std::path::Path,
+    libraries: &BTreeMap<String, BTreeMap<String, lib::CallingConvention>>,
+) {
+    for (library, functions) in libraries {
+        build_library(output, library, functions);
     }
 
     let mut cmd = std::process::Command::new("lib");
-    cmd.current_dir(&output);
+    cmd.current_dir(output);
     cmd.arg("/nologo");
     cmd.arg("/Brepro");
     cmd.arg("/out:windows.lib");
@@ -55,17 +126,6 @@ changes can sneak in undetected.
     }
 
     make_reproducible(&output.join("windows.lib"));
-
-    std::fs::remove_dir_all(format!("crates/targets/{platform}/lib")).unwrap();
-    std::fs::create_dir_all(format!("crates/targets/{platform}/lib")).unwrap();
-    std::fs::rename(
-        output.join("windows.lib"),
-        format!(
-            "crates/targets/{platform}/lib/windows.{}.lib",
-            std::env!("CARGO_PKG_VERSION")
-        ),
-    )
-    .unwrap();
 }
 
 fn build_library(
@@ -4,10 +4,11 @@ fn main() {
     if args.is_empty() {
         println!(
             r#"Usage: riddle.exe [options...]
+       riddle.exe new --component <namespace> [--out <path>]
 
 Options:
   --in  <path>          Path to files and directories containing .winmd and .rdl files
-  --out <path>          Path to .winmd, .rdl, or .rs file to generate
+  --out <path>          Path to .winmd, .rdl, .rs, .manifest, or .reg file to generate
   --filter <namespace>  Namespaces to include or !exclude in output
   --config <key=value>  Override a configuration value
   --format              Format .rdl files only
@@ -15,7 +16,13 @@ Options:
 "#
         );
     } else {
-        match windows_bindgen::bindgen(args) {
+        let result = if args[0] == "new" {
+            windows_bindgen::scaffold(&args[1..])
+        } else {
+            windows_bindgen::bindgen(args)
+        };
+
+        match result {
             Ok(ok) => println!("{ok}"),
             Err(error) => {
                 eprintln!("{error}");
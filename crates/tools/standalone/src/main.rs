@@ -213,6 +213,15 @@ fn main() {
             "prepend:Windows.Foundation.DateTime=#[derive(std::cmp::PartialOrd,std::cmp::Ord)]",
         ],
     );
+
+    riddle(
+        &src.join("b_no_debug.rs"),
+        &[
+            "Windows.Win32.Foundation.RECT",
+            "Windows.Win32.Foundation.WIN32_ERROR",
+        ],
+        &["flatten", "minimal", "no-debug"],
+    );
 }
 
 fn write_sys(output: &Path, filter: &[&str]) {